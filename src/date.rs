@@ -1,15 +1,25 @@
+#[cfg(all(feature = "formatting", feature = "alloc"))]
+use alloc::string::String;
+#[cfg(feature = "parsing")]
+use core::convert::TryInto;
 use core::fmt;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 use core::time::Duration as StdDuration;
-#[cfg(feature = "formatting")]
-use std::io;
+#[cfg(all(feature = "parsing", feature = "std"))]
+use std::io::{self, BufRead};
 
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+use crate::format_description::locale::Locale;
 #[cfg(feature = "formatting")]
 use crate::format_description::{modifier, Component, FormatItem};
 #[cfg(feature = "formatting")]
 use crate::formatting::formattable::sealed::Formattable;
+#[cfg(feature = "formatting")]
+use crate::formatting::{self, Output, SliceWriter};
 #[cfg(feature = "parsing")]
 use crate::parsing::parsable::sealed::Parsable;
+#[cfg(all(feature = "parsing", feature = "std"))]
+use crate::parsing::Parsed;
 use crate::util::{days_in_year, days_in_year_month, is_leap_year, weeks_in_year};
 use crate::{error, Duration, PrimitiveDateTime, Time, Weekday};
 
@@ -648,7 +658,7 @@ impl Date {
     /// [`format_description::parse`](crate::format_description::parse()).
     pub fn format_into<F: Formattable>(
         self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         format: &F,
     ) -> Result<usize, F::Error> {
         format.format_into(output, Some(self), None, None)
@@ -664,9 +674,34 @@ impl Date {
     /// assert_eq!(date!("2020-01-02").format(&format)?, "2020-01-02");
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
     pub fn format<F: Formattable>(self, format: &F) -> Result<String, F::Error> {
         format.format(Some(self), None, None)
     }
+
+    /// Format the `Date` using the provided format description and the names provided by the
+    /// locale. The formatted value will be output to the provided writer.
+    pub fn format_into_with_locale<F: Formattable>(
+        self,
+        output: &mut impl Output,
+        format: &F,
+        locale: &dyn Locale,
+    ) -> Result<usize, F::Error> {
+        format.format_into_with_locale(output, Some(self), None, None, locale)
+    }
+
+    /// Format the `Date` using the provided format description and the names provided by the
+    /// locale.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    pub fn format_with_locale<F: Formattable>(
+        self,
+        format: &F,
+        locale: &dyn Locale,
+    ) -> Result<String, F::Error> {
+        format.format_with_locale(Some(self), None, None, locale)
+    }
 }
 
 #[cfg(feature = "parsing")]
@@ -685,12 +720,65 @@ impl Date {
     pub fn parse(input: &str, description: &impl Parsable) -> Result<Self, error::Parse> {
         description.parse_date(input.as_bytes())
     }
+
+    /// Parse a `Date` from the input using the provided format description and the names
+    /// provided by the locale.
+    pub fn parse_with_locale(
+        input: &str,
+        description: &impl Parsable,
+        locale: &dyn Locale,
+    ) -> Result<Self, error::Parse> {
+        Ok(description
+            .parse_with_locale(input.as_bytes(), locale)?
+            .try_into()?)
+    }
+
+    /// Parse a `Date` from the start of the input using the provided format description. The
+    /// remaining input is returned alongside the value.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::date, Date};
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// assert_eq!(
+    ///     Date::parse_prefix("2020-01-02 rest", &format)?,
+    ///     (date!("2020-01-02"), " rest")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &impl Parsable,
+    ) -> Result<(Self, &'a str), error::Parse> {
+        let (parsed, remaining) = description.parse_prefix(input)?;
+        Ok((parsed.try_into()?, remaining))
+    }
+
+    /// Parse a `Date` from the start of the reader using the provided format description. Only the
+    /// bytes that were parsed are consumed, so the remainder can be read afterwards. Unless the
+    /// value is at the end of the reader, it must be followed by at least 16 bytes in the reader's
+    /// buffer, as it could otherwise continue past the end of the buffer.
+    ///
+    /// Errors that occur while parsing are returned with [`io::ErrorKind::InvalidData`],
+    /// wrapping an [`error::Parse`]. If an error is returned, it is unspecified how many bytes
+    /// were consumed from the reader.
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+    pub fn parse_from_reader(
+        reader: &mut impl BufRead,
+        description: &impl Parsable,
+    ) -> io::Result<Self> {
+        let mut parsed = Parsed::new();
+        description.parse_into_from_reader(reader, &mut parsed)?;
+        parsed.try_into().map_err(|err| {
+            io::Error::new(io::ErrorKind::InvalidData, error::Parse::TryFromParsed(err))
+        })
+    }
 }
 
 #[cfg(feature = "formatting")]
-#[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
-impl fmt::Display for Date {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Date {
+    /// Format the `Date` into the output as done by its [`Display`](fmt::Display) implementation.
+    pub(crate) fn format_display_into(self, output: &mut impl Output) -> fmt::Result {
         /// [year]-[month]-[day]
         const FORMAT: &[FormatItem<'_>] = &[
             FormatItem::Component(Component::Year(modifier::Year {
@@ -698,29 +786,43 @@ impl fmt::Display for Date {
                 repr: modifier::YearRepr::Full,
                 iso_week_based: false,
                 sign_is_mandatory: false,
+                digits: 4,
             })),
             FormatItem::Literal(b"-"),
             FormatItem::Component(Component::Month(modifier::Month {
                 padding: modifier::Padding::Zero,
                 repr: modifier::MonthRepr::Numerical,
+                case_sensitive: true,
             })),
             FormatItem::Literal(b"-"),
             FormatItem::Component(Component::Day(modifier::Day {
                 padding: modifier::Padding::Zero,
+                suffix: modifier::Suffix::None,
             })),
         ];
-        match self.format(&FORMAT) {
-            Ok(ref s) => f.write_str(s),
+        match self.format_into(output, &FORMAT) {
+            Ok(_) => Ok(()),
             Err(error::Format::InvalidComponent(_)) => {
                 unreachable!("A well-known format is not used")
             }
             Err(error::Format::InsufficientTypeInformation) => {
                 unreachable!("All components used only require a `Date`")
             }
-            Err(error::Format::StdIo(_)) => Err(fmt::Error),
+            Err(_) => Err(fmt::Error),
         }
     }
 }
+
+#[cfg(feature = "formatting")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; 13];
+        let mut output = SliceWriter::new(&mut buf);
+        self.format_display_into(&mut output)?;
+        formatting::pad(f, output.written())
+    }
+}
 // endregion formatting & parsing
 
 // region: trait impls
@@ -1,11 +1,27 @@
+#[cfg(all(feature = "formatting", feature = "alloc"))]
+use alloc::string::String;
 use core::cmp::Ordering;
 use core::convert::{TryFrom, TryInto};
+#[cfg(feature = "formatting")]
+use core::fmt;
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+#[cfg(feature = "parsing")]
+use core::str::FromStr;
 use core::time::Duration as StdDuration;
 
 use const_fn::const_fn;
 
 use crate::error;
+#[cfg(feature = "formatting")]
+use crate::format_description::{modifier, Component};
+#[cfg(feature = "formatting")]
+use crate::formatting::formattable::sealed::Formattable;
+#[cfg(feature = "formatting")]
+use crate::formatting::{self, format_duration_component, Output, SliceWriter};
+#[cfg(feature = "parsing")]
+use crate::parsing::parsable::sealed::Parsable;
+#[cfg(feature = "parsing")]
+use crate::parsing::{duration::parse_duration, Parsed};
 #[cfg(feature = "std")]
 use crate::Instant;
 
@@ -735,6 +751,205 @@ impl Duration {
     }
 }
 
+// region: formatting & parsing
+#[cfg(feature = "formatting")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
+impl Duration {
+    /// Format the `Duration` using the provided format description. The formatted value will be
+    /// output to the provided writer. The format description will typically be parsed by using
+    /// [`format_description::parse`](crate::format_description::parse()).
+    pub fn format_into<F: Formattable>(
+        self,
+        output: &mut impl Output,
+        format: &F,
+    ) -> Result<usize, F::Error> {
+        format.format_duration_into(output, self)
+    }
+
+    /// Format the `Duration` using the provided format description. The format description will
+    /// typically be parsed by using
+    /// [`format_description::parse`](crate::format_description::parse()).
+    ///
+    /// ```rust
+    /// # use time::{format_description, ext::NumericalDuration};
+    /// let format = format_description::parse(
+    ///     "[duration_hour repr:total]:[duration_minute]:[duration_second]",
+    /// )?;
+    /// assert_eq!(90.minutes().format(&format)?, "01:30:00");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    pub fn format<F: Formattable>(self, format: &F) -> Result<String, F::Error> {
+        format.format_duration(self)
+    }
+}
+
+#[cfg(feature = "parsing")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+impl Duration {
+    /// Parse a `Duration` from the input using the provided format description. The format
+    /// description will typically be parsed by using
+    /// [`format_description::parse`](crate::format_description::parse()).
+    ///
+    /// ```rust
+    /// # use time::{format_description, ext::NumericalDuration, Duration};
+    /// let format = format_description::parse(
+    ///     "[duration_hour repr:total]:[duration_minute]:[duration_second]",
+    /// )?;
+    /// assert_eq!(Duration::parse("01:30:00", &format)?, 90.minutes());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse(input: &str, description: &impl Parsable) -> Result<Self, error::Parse> {
+        description.parse_duration(input.as_bytes())
+    }
+}
+
+#[cfg(feature = "formatting")]
+impl Duration {
+    /// Format the `Duration` into the output as done by its [`Display`](fmt::Display)
+    /// implementation. The precision is the number of digits after the decimal point of the
+    /// seconds, which are omitted entirely if it is zero. Without a precision, as many digits as
+    /// necessary are used.
+    pub(crate) fn format_display_into(
+        self,
+        output: &mut impl Output,
+        precision: Option<usize>,
+    ) -> fmt::Result {
+        match self.format_units_into(output, precision) {
+            Ok(_) => Ok(()),
+            Err(error::Format::InvalidComponent(_)) => {
+                unreachable!("A well-known format is not used")
+            }
+            Err(error::Format::InsufficientTypeInformation) => {
+                unreachable!("All components used only require a `Duration`")
+            }
+            Err(_) => Err(fmt::Error),
+        }
+    }
+
+    /// Format the sign followed by each unit with a non-zero value, separated by spaces.
+    fn format_units_into(
+        self,
+        output: &mut impl Output,
+        precision: Option<usize>,
+    ) -> Result<usize, error::Format> {
+        let seconds = self.whole_seconds().wrapping_abs() as u64;
+        let nanoseconds = self.subsec_nanoseconds();
+        let units = [
+            (
+                seconds >= 86_400,
+                Component::DurationDay(modifier::DurationDay),
+                b"d",
+            ),
+            (
+                seconds / 3_600 % 24 != 0,
+                Component::DurationHour(modifier::DurationHour {
+                    padding: modifier::Padding::None,
+                    repr: modifier::DurationRepr::Remainder,
+                }),
+                b"h",
+            ),
+            (
+                seconds / 60 % 60 != 0,
+                Component::DurationMinute(modifier::DurationMinute {
+                    padding: modifier::Padding::None,
+                    repr: modifier::DurationRepr::Remainder,
+                }),
+                b"m",
+            ),
+            (
+                seconds % 60 != 0 || nanoseconds != 0 || seconds == 0,
+                Component::DurationSecond(modifier::DurationSecond {
+                    padding: modifier::Padding::None,
+                    repr: modifier::DurationRepr::Remainder,
+                }),
+                b"s",
+            ),
+        ];
+
+        let mut bytes = format_duration_component(
+            output,
+            Component::DurationSign(modifier::DurationSign {
+                sign_is_mandatory: false,
+            }),
+            self,
+        )?;
+        let mut is_first = true;
+        for &(is_present, component, suffix) in units.iter() {
+            if !is_present {
+                continue;
+            }
+            if !is_first {
+                bytes += formatting::write(output, b" ")?;
+            }
+            is_first = false;
+            bytes += format_duration_component(output, component, self)?;
+            // The fraction is attached to the seconds, which are always the last unit.
+            if suffix == b"s" && precision.map_or(nanoseconds != 0, |precision| precision != 0) {
+                bytes += formatting::write(output, b".")?;
+                bytes += format_duration_component(
+                    output,
+                    Component::DurationSubsecond(modifier::DurationSubsecond {
+                        digits: formatting::subsecond_digits(precision),
+                    }),
+                    self,
+                )?;
+            }
+            bytes += formatting::write(output, suffix)?;
+        }
+        Ok(bytes)
+    }
+}
+
+#[cfg(feature = "formatting")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
+impl fmt::Display for Duration {
+    /// Format the `Duration` as a sequence of units, such as `2d 3h 4m 5.006s`. Units with a value
+    /// of zero are omitted. The precision, if present, is the number of digits after the decimal
+    /// point of the seconds.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(90.5.seconds().to_string(), "1m 30.5s");
+    /// assert_eq!((-1.5).hours().to_string(), "-1h 30m");
+    /// assert_eq!(format!("{:.2}", 90.5.seconds()), "1m 30.50s");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; 48];
+        let mut output = SliceWriter::new(&mut buf);
+        self.format_display_into(&mut output, f.precision())?;
+        formatting::pad(f, output.written())
+    }
+}
+
+#[cfg(feature = "parsing")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+impl FromStr for Duration {
+    type Err = error::Parse;
+
+    /// Parse a `Duration` from a sequence of numbers with unit suffixes, such as `2d 3h 4m
+    /// 5.006s` or `1m30s`. The units are `d`, `h`, `m`, `s`, `ms`, `us` (or `µs`), and `ns`. Any
+    /// number may have a decimal fraction, and the units may optionally be separated by spaces.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, Duration};
+    /// assert_eq!("1h2m3.5s".parse::<Duration>()?, 3_723.5.seconds());
+    /// assert_eq!("250ms".parse::<Duration>()?, 250.milliseconds());
+    /// assert_eq!("-2d 3h".parse::<Duration>()?, (-51).hours());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parsed = Parsed::new();
+        let remaining = parse_duration(s.as_bytes(), &mut parsed)?;
+        if !remaining.is_empty() {
+            return Err(error::Parse::UnexpectedTrailingCharacters);
+        }
+        Ok(parsed.try_into()?)
+    }
+}
+// endregion formatting & parsing
+
 // region: trait impls
 impl TryFrom<StdDuration> for Duration {
     type Error = error::ConversionRange;
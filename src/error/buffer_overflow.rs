@@ -0,0 +1,25 @@
+//! Buffer overflow error
+
+use core::fmt;
+
+/// An error type indicating that a formatted value did not fit in the buffer it was being written
+/// to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferOverflow;
+
+impl fmt::Display for BufferOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("The formatted value does not fit in the provided buffer")
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+impl std::error::Error for BufferOverflow {}
+
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
+impl From<BufferOverflow> for crate::Error {
+    fn from(original: BufferOverflow) -> Self {
+        Self::Format(original.into())
+    }
+}
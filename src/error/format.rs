@@ -1,8 +1,11 @@
 //! Error formatting a struct
 
 use core::fmt;
+#[cfg(feature = "std")]
 use std::io;
 
+use crate::error::BufferOverflow;
+
 /// An error occurred when formatting.
 #[non_exhaustive]
 #[allow(missing_copy_implementations)]
@@ -14,9 +17,18 @@ pub enum Format {
     InsufficientTypeInformation,
     /// The component named has a value that cannot be formatted into the requested format.
     ///
-    /// This variant is only returned when using well-known formats.
+    /// This variant is only returned when using well-known formats, when converting a format
+    /// description to a strftime format string, when formatting a component that is only
+    /// meaningful when parsing, or when formatting an offset that has no abbreviation in the
+    /// locale.
     InvalidComponent(&'static str),
+    /// The formatted value did not fit in the provided buffer.
+    BufferOverflow(BufferOverflow),
+    /// A value of `core::fmt::Error` was returned internally.
+    Fmt(fmt::Error),
     /// A value of `std::io::Error` was returned internally.
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
     StdIo(io::Error),
 }
 
@@ -32,11 +44,28 @@ impl fmt::Display for Format {
                 "The {} component cannot be formatted into the requested format.",
                 component
             ),
+            Self::BufferOverflow(err) => err.fmt(f),
+            Self::Fmt(err) => err.fmt(f),
+            #[cfg(feature = "std")]
             Self::StdIo(err) => err.fmt(f),
         }
     }
 }
 
+impl From<BufferOverflow> for Format {
+    fn from(err: BufferOverflow) -> Self {
+        Self::BufferOverflow(err)
+    }
+}
+
+impl From<fmt::Error> for Format {
+    fn from(err: fmt::Error) -> Self {
+        Self::Fmt(err)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
 impl From<io::Error> for Format {
     fn from(err: io::Error) -> Self {
         Self::StdIo(err)
@@ -49,6 +78,8 @@ impl std::error::Error for Format {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match *self {
             Self::InsufficientTypeInformation | Self::InvalidComponent(_) => None,
+            Self::BufferOverflow(ref err) => Some(err),
+            Self::Fmt(ref err) => Some(err),
             Self::StdIo(ref err) => Some(err),
         }
     }
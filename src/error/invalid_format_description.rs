@@ -34,6 +34,22 @@ pub enum InvalidFormatDescription {
         /// The zero-based index where the component name should start.
         index: usize,
     },
+    /// Something was expected but not present.
+    Expected {
+        /// What was expected to be present, but wasn't.
+        what: &'static str,
+        /// The zero-based index the item was expected to be found at.
+        index: usize,
+    },
+    /// Certain behavior is not supported in the given context.
+    NotSupported {
+        /// The behavior that is not supported.
+        what: &'static str,
+        /// The context in which the behavior is not supported.
+        context: &'static str,
+        /// The zero-based index the error occurred at.
+        index: usize,
+    },
 }
 
 #[cfg_attr(
@@ -64,6 +80,16 @@ impl fmt::Display for InvalidFormatDescription {
             MissingComponentName { index } => {
                 write!(f, "missing component name at byte index {}", index)
             }
+            Expected { what, index } => write!(f, "expected {} at byte index {}", what, index),
+            NotSupported {
+                what,
+                context,
+                index,
+            } => write!(
+                f,
+                "{} is not supported in {} at byte index {}",
+                what, context, index
+            ),
         }
     }
 }
@@ -1,3 +1,5 @@
+#[cfg(feature = "formatting")]
+mod buffer_overflow;
 mod component_range;
 mod conversion_range;
 #[cfg(feature = "formatting")]
@@ -15,6 +17,8 @@ mod try_from_parsed;
 
 use core::fmt;
 
+#[cfg(feature = "formatting")]
+pub use buffer_overflow::BufferOverflow;
 pub use component_range::ComponentRange;
 pub use conversion_range::ConversionRange;
 #[cfg(feature = "formatting")]
@@ -56,6 +60,10 @@ pub enum Error {
     UnexpectedTrailingCharacters,
     #[cfg(feature = "parsing")]
     #[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+    #[non_exhaustive]
+    UnexpectedPartialCharacter,
+    #[cfg(feature = "parsing")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
     TryFromParsed(TryFromParsed),
     #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
     #[cfg_attr(
@@ -79,6 +87,10 @@ impl fmt::Display for Error {
             #[cfg(feature = "parsing")]
             Self::UnexpectedTrailingCharacters => f.write_str("unexpected trailing characters"),
             #[cfg(feature = "parsing")]
+            Self::UnexpectedPartialCharacter => {
+                f.write_str("the value ended partway through a character")
+            }
+            #[cfg(feature = "parsing")]
             Self::TryFromParsed(e) => e.fmt(f),
             #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
             Self::InvalidFormatDescription(e) => e.fmt(f),
@@ -100,7 +112,7 @@ impl std::error::Error for Error {
             #[cfg(feature = "parsing")]
             Self::ParseFromDescription(err) => Some(err),
             #[cfg(feature = "parsing")]
-            Self::UnexpectedTrailingCharacters => None,
+            Self::UnexpectedTrailingCharacters | Self::UnexpectedPartialCharacter => None,
             #[cfg(feature = "parsing")]
             Self::TryFromParsed(err) => Some(err),
             #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
@@ -18,6 +18,10 @@ pub enum Parse {
     /// The input should have ended, but there were characters remaining.
     #[non_exhaustive]
     UnexpectedTrailingCharacters,
+    /// The value ended partway through a character of the input, so the remaining input is not a
+    /// valid string.
+    #[non_exhaustive]
+    UnexpectedPartialCharacter,
 }
 
 impl fmt::Display for Parse {
@@ -26,6 +30,9 @@ impl fmt::Display for Parse {
             Self::TryFromParsed(err) => err.fmt(f),
             Self::ParseFromDescription(err) => err.fmt(f),
             Self::UnexpectedTrailingCharacters => f.write_str("unexpected trailing characters"),
+            Self::UnexpectedPartialCharacter => {
+                f.write_str("the value ended partway through a character")
+            }
         }
     }
 }
@@ -37,7 +44,7 @@ impl std::error::Error for Parse {
         match self {
             Self::TryFromParsed(err) => Some(err),
             Self::ParseFromDescription(err) => Some(err),
-            Self::UnexpectedTrailingCharacters => None,
+            Self::UnexpectedTrailingCharacters | Self::UnexpectedPartialCharacter => None,
         }
     }
 }
@@ -60,6 +67,7 @@ impl From<Parse> for crate::Error {
             Parse::TryFromParsed(err) => Self::TryFromParsed(err),
             Parse::ParseFromDescription(err) => Self::ParseFromDescription(err),
             Parse::UnexpectedTrailingCharacters => Self::UnexpectedTrailingCharacters,
+            Parse::UnexpectedPartialCharacter => Self::UnexpectedPartialCharacter,
         }
     }
 }
@@ -89,10 +97,16 @@ impl Parse {
                     &&*format!("valid {}", component),
                 )
             }
+            Self::ParseFromDescription(ParseFromDescription::NoMatchingAlternative { .. }) => {
+                unreachable!("The deserializing format does not contain any alternatives.")
+            }
             Self::UnexpectedTrailingCharacters => D::Error::invalid_value(
                 serde::de::Unexpected::Other("literal"),
                 &"no extraneous characters",
             ),
+            Self::UnexpectedPartialCharacter => {
+                unreachable!("The deserializer does not parse a prefix of the input.")
+            }
         }
     }
 }
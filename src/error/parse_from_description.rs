@@ -12,6 +12,12 @@ pub enum ParseFromDescription {
     InvalidLiteral,
     /// A dynamic component was not valid.
     InvalidComponent(&'static str),
+    /// None of the alternatives of a [`FormatItem::First`](crate::format_description::FormatItem)
+    /// could be parsed.
+    NoMatchingAlternative {
+        /// The number of alternatives, all of which failed to parse.
+        count: usize,
+    },
 }
 
 impl fmt::Display for ParseFromDescription {
@@ -21,6 +27,9 @@ impl fmt::Display for ParseFromDescription {
             Self::InvalidComponent(name) => {
                 write!(f, "the '{}' component could not be parsed", name)
             }
+            Self::NoMatchingAlternative { count } => {
+                write!(f, "none of the {} alternatives could be parsed", count)
+            }
         }
     }
 }
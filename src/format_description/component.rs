@@ -23,6 +23,18 @@ pub enum Component {
     WeekNumber(modifier::WeekNumber),
     /// Year of the date.
     Year(modifier::Year),
+    /// Quarter of the year.
+    Quarter(modifier::Quarter),
+    /// Week within the month.
+    WeekOfMonth(modifier::WeekOfMonth),
+    /// Week within the quarter.
+    WeekOfQuarter(modifier::WeekOfQuarter),
+    /// Occurrence of the weekday within the month.
+    WeekdayInMonth(modifier::WeekdayInMonth),
+    /// Century of the year.
+    Century(modifier::Century),
+    /// Era of the year (e.g. "BC" or "AD"), as provided by the locale.
+    Era(modifier::Era),
     /// Hour of the day.
     Hour(modifier::Hour),
     /// Minute within the hour.
@@ -39,6 +51,28 @@ pub enum Component {
     OffsetMinute(modifier::OffsetMinute),
     /// Second within the minute of the UTC offset.
     OffsetSecond(modifier::OffsetSecond),
+    /// Abbreviated name of the UTC offset, as provided by the locale.
+    OffsetAbbreviation(modifier::OffsetAbbreviation),
+    /// The UTC offset as a whole, with `Z` optionally used for a zero offset.
+    Offset(modifier::Offset),
+    /// Number of whole days in a duration.
+    DurationDay(modifier::DurationDay),
+    /// Number of whole hours in a duration.
+    DurationHour(modifier::DurationHour),
+    /// Number of whole minutes in a duration.
+    DurationMinute(modifier::DurationMinute),
+    /// Number of whole seconds in a duration.
+    DurationSecond(modifier::DurationSecond),
+    /// Fraction of a second in a duration.
+    DurationSubsecond(modifier::DurationSubsecond),
+    /// Sign of a duration.
+    DurationSign(modifier::DurationSign),
+    /// Number of units of time since the Unix epoch.
+    UnixTimestamp(modifier::UnixTimestamp),
+    /// A number of bytes that are skipped when parsing. This component cannot be formatted.
+    Ignore(modifier::Ignore),
+    /// The end of the input. Parsing fails if any input remains. Nothing is formatted.
+    End(modifier::End),
 }
 
 /// A component with no modifiers present.
@@ -57,6 +91,18 @@ pub(crate) enum NakedComponent {
     WeekNumber,
     /// Year of the date.
     Year,
+    /// Quarter of the year.
+    Quarter,
+    /// Week within the month.
+    WeekOfMonth,
+    /// Week within the quarter.
+    WeekOfQuarter,
+    /// Occurrence of the weekday within the month.
+    WeekdayInMonth,
+    /// Century of the year.
+    Century,
+    /// Era of the year.
+    Era,
     /// Hour of the day.
     Hour,
     /// Minute within the hour.
@@ -73,6 +119,28 @@ pub(crate) enum NakedComponent {
     OffsetMinute,
     /// Second within the minute of the UTC offset.
     OffsetSecond,
+    /// Abbreviated name of the UTC offset.
+    OffsetAbbreviation,
+    /// The UTC offset as a whole.
+    Offset,
+    /// Number of whole days in a duration.
+    DurationDay,
+    /// Number of whole hours in a duration.
+    DurationHour,
+    /// Number of whole minutes in a duration.
+    DurationMinute,
+    /// Number of whole seconds in a duration.
+    DurationSecond,
+    /// Fraction of a second in a duration.
+    DurationSubsecond,
+    /// Sign of a duration.
+    DurationSign,
+    /// Number of units of time since the Unix epoch.
+    UnixTimestamp,
+    /// A number of bytes that are skipped when parsing.
+    Ignore,
+    /// The end of the input.
+    End,
 }
 
 #[cfg(feature = "alloc")]
@@ -90,6 +158,12 @@ impl NakedComponent {
             b"weekday" => Ok(Self::Weekday),
             b"week_number" => Ok(Self::WeekNumber),
             b"year" => Ok(Self::Year),
+            b"quarter" => Ok(Self::Quarter),
+            b"week_of_month" => Ok(Self::WeekOfMonth),
+            b"week_of_quarter" => Ok(Self::WeekOfQuarter),
+            b"weekday_in_month" => Ok(Self::WeekdayInMonth),
+            b"century" => Ok(Self::Century),
+            b"era" => Ok(Self::Era),
             b"hour" => Ok(Self::Hour),
             b"minute" => Ok(Self::Minute),
             b"period" => Ok(Self::Period),
@@ -98,6 +172,17 @@ impl NakedComponent {
             b"offset_hour" => Ok(Self::OffsetHour),
             b"offset_minute" => Ok(Self::OffsetMinute),
             b"offset_second" => Ok(Self::OffsetSecond),
+            b"offset_abbreviation" => Ok(Self::OffsetAbbreviation),
+            b"offset" => Ok(Self::Offset),
+            b"duration_day" => Ok(Self::DurationDay),
+            b"duration_hour" => Ok(Self::DurationHour),
+            b"duration_minute" => Ok(Self::DurationMinute),
+            b"duration_second" => Ok(Self::DurationSecond),
+            b"duration_subsecond" => Ok(Self::DurationSubsecond),
+            b"duration_sign" => Ok(Self::DurationSign),
+            b"unix_timestamp" => Ok(Self::UnixTimestamp),
+            b"ignore" => Ok(Self::Ignore),
+            b"end" => Ok(Self::End),
             b"" => Err(InvalidFormatDescription::MissingComponentName {
                 index: component_index,
             }),
@@ -108,32 +193,68 @@ impl NakedComponent {
         }
     }
 
-    /// Attach the necessary modifiers to the component.
-    pub(crate) fn attach_modifiers(self, modifiers: &Modifiers) -> Component {
-        match self {
+    /// Attach the necessary modifiers to the component. `index` is where any missing modifier
+    /// would be expected.
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn attach_modifiers(
+        self,
+        modifiers: &Modifiers,
+        index: usize,
+    ) -> Result<Component, InvalidFormatDescription> {
+        Ok(match self {
             Self::Day => Component::Day(modifier::Day {
                 padding: modifiers.padding.unwrap_or_default(),
+                suffix: modifiers.suffix.unwrap_or_default(),
             }),
             Self::Month => Component::Month(modifier::Month {
                 padding: modifiers.padding.unwrap_or_default(),
                 repr: modifiers.month_repr.unwrap_or_default(),
+                case_sensitive: modifiers.case_sensitive.unwrap_or(true),
             }),
             Self::Ordinal => Component::Ordinal(modifier::Ordinal {
                 padding: modifiers.padding.unwrap_or_default(),
+                suffix: modifiers.suffix.unwrap_or_default(),
             }),
             Self::Weekday => Component::Weekday(modifier::Weekday {
                 repr: modifiers.weekday_repr.unwrap_or_default(),
                 one_indexed: modifiers.weekday_is_one_indexed.unwrap_or(true),
+                case_sensitive: modifiers.case_sensitive.unwrap_or(true),
             }),
             Self::WeekNumber => Component::WeekNumber(modifier::WeekNumber {
                 padding: modifiers.padding.unwrap_or_default(),
                 repr: modifiers.week_number_repr.unwrap_or_default(),
+                suffix: modifiers.suffix.unwrap_or_default(),
             }),
             Self::Year => Component::Year(modifier::Year {
                 padding: modifiers.padding.unwrap_or_default(),
                 repr: modifiers.year_repr.unwrap_or_default(),
                 iso_week_based: modifiers.year_is_iso_week_based.unwrap_or_default(),
                 sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
+                digits: modifiers.year_digits.unwrap_or(4),
+            }),
+            Self::Quarter => Component::Quarter(modifier::Quarter {
+                padding: modifiers.padding.unwrap_or_default(),
+                suffix: modifiers.suffix.unwrap_or_default(),
+            }),
+            Self::WeekOfMonth => Component::WeekOfMonth(modifier::WeekOfMonth {
+                padding: modifiers.padding.unwrap_or_default(),
+                suffix: modifiers.suffix.unwrap_or_default(),
+            }),
+            Self::WeekOfQuarter => Component::WeekOfQuarter(modifier::WeekOfQuarter {
+                padding: modifiers.padding.unwrap_or_default(),
+                suffix: modifiers.suffix.unwrap_or_default(),
+            }),
+            Self::WeekdayInMonth => Component::WeekdayInMonth(modifier::WeekdayInMonth {
+                padding: modifiers.padding.unwrap_or_default(),
+                suffix: modifiers.suffix.unwrap_or_default(),
+            }),
+            Self::Century => Component::Century(modifier::Century {
+                padding: modifiers.padding.unwrap_or_default(),
+                suffix: modifiers.suffix.unwrap_or_default(),
+            }),
+            Self::Era => Component::Era(modifier::Era {
+                repr: modifiers.era_repr.unwrap_or_default(),
+                case_sensitive: modifiers.case_sensitive.unwrap_or(true),
             }),
             Self::Hour => Component::Hour(modifier::Hour {
                 padding: modifiers.padding.unwrap_or_default(),
@@ -144,12 +265,14 @@ impl NakedComponent {
             }),
             Self::Period => Component::Period(modifier::Period {
                 is_uppercase: modifiers.period_is_uppercase.unwrap_or(true),
+                case_sensitive: modifiers.case_sensitive.unwrap_or(true),
             }),
             Self::Second => Component::Second(modifier::Second {
                 padding: modifiers.padding.unwrap_or_default(),
             }),
             Self::Subsecond => Component::Subsecond(modifier::Subsecond {
                 digits: modifiers.subsecond_digits.unwrap_or_default(),
+                rounding: modifiers.subsecond_rounding.unwrap_or_default(),
             }),
             Self::OffsetHour => Component::OffsetHour(modifier::OffsetHour {
                 sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
@@ -161,6 +284,48 @@ impl NakedComponent {
             Self::OffsetSecond => Component::OffsetSecond(modifier::OffsetSecond {
                 padding: modifiers.padding.unwrap_or_default(),
             }),
-        }
+            Self::OffsetAbbreviation => {
+                Component::OffsetAbbreviation(modifier::OffsetAbbreviation {
+                    case_sensitive: modifiers.case_sensitive.unwrap_or(true),
+                })
+            }
+            Self::Offset => Component::Offset(modifier::Offset {
+                zulu: modifiers.offset_zulu.unwrap_or(true),
+                separator: modifiers.offset_separator.unwrap_or_default(),
+                minute_is_optional: modifiers.offset_minute_is_optional.unwrap_or_default(),
+            }),
+            Self::DurationDay => Component::DurationDay(modifier::DurationDay),
+            Self::DurationHour => Component::DurationHour(modifier::DurationHour {
+                padding: modifiers.padding.unwrap_or_default(),
+                repr: modifiers.duration_repr.unwrap_or_default(),
+            }),
+            Self::DurationMinute => Component::DurationMinute(modifier::DurationMinute {
+                padding: modifiers.padding.unwrap_or_default(),
+                repr: modifiers.duration_repr.unwrap_or_default(),
+            }),
+            Self::DurationSecond => Component::DurationSecond(modifier::DurationSecond {
+                padding: modifiers.padding.unwrap_or_default(),
+                repr: modifiers.duration_repr.unwrap_or_default(),
+            }),
+            Self::DurationSubsecond => Component::DurationSubsecond(modifier::DurationSubsecond {
+                digits: modifiers.subsecond_digits.unwrap_or_default(),
+            }),
+            Self::DurationSign => Component::DurationSign(modifier::DurationSign {
+                sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
+            }),
+            Self::UnixTimestamp => Component::UnixTimestamp(modifier::UnixTimestamp {
+                precision: modifiers.unix_timestamp_precision.unwrap_or_default(),
+                sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
+            }),
+            Self::Ignore => Component::Ignore(modifier::Ignore {
+                count: modifiers
+                    .ignore_count
+                    .ok_or(InvalidFormatDescription::Expected {
+                        what: "`count` modifier",
+                        index,
+                    })?,
+            }),
+            Self::End => Component::End(modifier::End),
+        })
     }
 }
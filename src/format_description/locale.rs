@@ -0,0 +1,277 @@
+//! Names of months, weekdays and periods, as well as other language-specific text, as used by the
+//! respective components.
+
+use crate::format_description::modifier::EraRepr;
+#[cfg(feature = "formatting")]
+use crate::formatting::relative::Unit;
+use crate::UtcOffset;
+
+/// Names of months, weekdays and periods in a given language.
+///
+/// These names are used when formatting and parsing the
+/// [`Month`](crate::format_description::Component::Month),
+/// [`Weekday`](crate::format_description::Component::Weekday),
+/// [`Period`](crate::format_description::Component::Period),
+/// [`Era`](crate::format_description::Component::Era) and
+/// [`OffsetAbbreviation`](crate::format_description::Component::OffsetAbbreviation) components,
+/// for [ordinal suffixes](crate::format_description::modifier::Suffix::Ordinal), and for the
+/// phrases used by [`Relative`](crate::formatting::relative::Relative). Methods that do not accept
+/// a locale use [`English`].
+///
+/// ```rust
+/// # use time::format_description::locale::Locale;
+/// # use time::macros::{date, format_description};
+/// struct German;
+///
+/// impl Locale for German {
+///     fn long_month_names(&self) -> &[&str; 12] {
+///         &[
+///             "Januar",
+///             "Februar",
+///             "März",
+///             "April",
+///             "Mai",
+///             "Juni",
+///             "Juli",
+///             "August",
+///             "September",
+///             "Oktober",
+///             "November",
+///             "Dezember",
+///         ]
+///     }
+///
+///     fn short_month_names(&self) -> &[&str; 12] {
+///         &[
+///             "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+///         ]
+///     }
+///
+///     fn narrow_month_names(&self) -> &[&str; 12] {
+///         &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]
+///     }
+///
+///     fn long_weekday_names(&self) -> &[&str; 7] {
+///         &[
+///             "Montag",
+///             "Dienstag",
+///             "Mittwoch",
+///             "Donnerstag",
+///             "Freitag",
+///             "Samstag",
+///             "Sonntag",
+///         ]
+///     }
+///
+///     fn short_weekday_names(&self) -> &[&str; 7] {
+///         &["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"]
+///     }
+///
+///     fn narrow_weekday_names(&self) -> &[&str; 7] {
+///         &["M", "D", "M", "D", "F", "S", "S"]
+///     }
+///
+///     fn period_markers(&self, _is_uppercase: bool) -> &[&str; 2] {
+///         &["vorm.", "nachm."]
+///     }
+/// }
+///
+/// let format = format_description!("[weekday], [day]. [month repr:long] [year]");
+/// assert_eq!(
+///     date!("2021-03-04").format_with_locale(&format, &German)?,
+///     "Donnerstag, 04. März 2021"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+pub trait Locale {
+    /// The full names of the months, starting with January.
+    fn long_month_names(&self) -> &[&str; 12];
+
+    /// The abbreviated names of the months, starting with January.
+    fn short_month_names(&self) -> &[&str; 12];
+
+    /// The narrowest names of the months, starting with January. These are typically a single
+    /// character, and are frequently ambiguous.
+    fn narrow_month_names(&self) -> &[&str; 12];
+
+    /// The full names of the days of the week, starting with Monday.
+    fn long_weekday_names(&self) -> &[&str; 7];
+
+    /// The abbreviated names of the days of the week, starting with Monday.
+    fn short_weekday_names(&self) -> &[&str; 7];
+
+    /// The narrowest names of the days of the week, starting with Monday. These are typically a
+    /// single character, and are frequently ambiguous.
+    fn narrow_weekday_names(&self) -> &[&str; 7];
+
+    /// The markers for times before and after noon, in that order. Locales that do not
+    /// distinguish between cases may ignore `is_uppercase`.
+    fn period_markers(&self, is_uppercase: bool) -> &[&str; 2];
+
+    /// Abbreviations of UTC offsets and the offset each one stands for.
+    ///
+    /// When formatting, the first abbreviation matching the offset is used, so entries that
+    /// should be preferred must come first. When parsing, the longest matching abbreviation is
+    /// used. Defaults to [`DEFAULT_OFFSET_ABBREVIATIONS`].
+    fn offset_abbreviations(&self) -> &[(&str, UtcOffset)] {
+        DEFAULT_OFFSET_ABBREVIATIONS
+    }
+
+    /// The suffix following a number when it is used as an ordinal, as used by the `suffix:ordinal`
+    /// modifier. Defaults to English, such as `st` for 1, `nd` for 22, and `th` for 13.
+    fn ordinal_suffix(&self, value: u16) -> &str {
+        match (value % 10, value % 100) {
+            (_, 11..=13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        }
+    }
+
+    /// The names of the eras before and since year 1, in that order. Defaults to `BC` and `AD`, or
+    /// `BCE` and `CE` for [`EraRepr::CommonEra`].
+    fn era_names(&self, repr: EraRepr) -> &[&str; 2] {
+        match repr {
+            EraRepr::AnnoDomini => &["BC", "AD"],
+            EraRepr::CommonEra => &["BCE", "CE"],
+        }
+    }
+
+    /// The phrase used for a duration that is too short to be described in units. Defaults to
+    /// `just now`.
+    #[cfg(feature = "formatting")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
+    fn relative_now(&self) -> &str {
+        "just now"
+    }
+
+    /// The text before and after a number of units relative to now. The count is provided so that
+    /// the correct plural form can be chosen.
+    ///
+    /// Defaults to English, such as `("in ", " minutes")` for a duration in the future and
+    /// `("", " minutes ago")` for one in the past.
+    #[cfg(feature = "formatting")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
+    fn relative_time(&self, count: u64, unit: Unit, is_future: bool) -> (&str, &str) {
+        let [singular, plural, singular_past, plural_past] = match unit {
+            Unit::Second => [" second", " seconds", " second ago", " seconds ago"],
+            Unit::Minute => [" minute", " minutes", " minute ago", " minutes ago"],
+            Unit::Hour => [" hour", " hours", " hour ago", " hours ago"],
+            Unit::Day => [" day", " days", " day ago", " days ago"],
+            Unit::Week => [" week", " weeks", " week ago", " weeks ago"],
+            Unit::Month => [" month", " months", " month ago", " months ago"],
+            Unit::Year => [" year", " years", " year ago", " years ago"],
+        };
+        match (count == 1, is_future) {
+            (true, true) => ("in ", singular),
+            (false, true) => ("in ", plural),
+            (true, false) => ("", singular_past),
+            (false, false) => ("", plural_past),
+        }
+    }
+}
+
+/// Construct an offset from its components without any checks.
+const fn offset(hours: i8, minutes: i8) -> UtcOffset {
+    UtcOffset::__from_hms_unchecked(hours, minutes, 0)
+}
+
+/// Commonly used abbreviations of UTC offsets.
+///
+/// Abbreviations are frequently ambiguous; where this is the case, the most widely used meaning
+/// is present. Standard times are listed before daylight saving times, so that an offset that is
+/// shared by both (such as -05:00 for `EST` and `CDT`) is formatted as standard time.
+pub const DEFAULT_OFFSET_ABBREVIATIONS: &[(&str, UtcOffset)] = &[
+    ("UTC", offset(0, 0)),
+    ("GMT", offset(0, 0)),
+    ("EST", offset(-5, 0)),
+    ("CST", offset(-6, 0)),
+    ("MST", offset(-7, 0)),
+    ("PST", offset(-8, 0)),
+    ("AKST", offset(-9, 0)),
+    ("HST", offset(-10, 0)),
+    ("EDT", offset(-4, 0)),
+    ("CDT", offset(-5, 0)),
+    ("MDT", offset(-6, 0)),
+    ("PDT", offset(-7, 0)),
+    ("AKDT", offset(-8, 0)),
+    ("WET", offset(0, 0)),
+    ("CET", offset(1, 0)),
+    ("EET", offset(2, 0)),
+    ("MSK", offset(3, 0)),
+    ("IST", offset(5, 30)),
+    ("AWST", offset(8, 0)),
+    ("JST", offset(9, 0)),
+    ("KST", offset(9, 0)),
+    ("ACST", offset(9, 30)),
+    ("AEST", offset(10, 0)),
+    ("NZST", offset(12, 0)),
+    ("WEST", offset(1, 0)),
+    ("CEST", offset(2, 0)),
+    ("EEST", offset(3, 0)),
+    ("ACDT", offset(10, 30)),
+    ("AEDT", offset(11, 0)),
+    ("NZDT", offset(13, 0)),
+];
+
+/// The English locale. This is the default for all methods that do not accept a locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct English;
+
+impl Locale for English {
+    fn long_month_names(&self) -> &[&str; 12] {
+        &[
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ]
+    }
+
+    fn short_month_names(&self) -> &[&str; 12] {
+        &[
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ]
+    }
+
+    fn narrow_month_names(&self) -> &[&str; 12] {
+        &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]
+    }
+
+    fn long_weekday_names(&self) -> &[&str; 7] {
+        &[
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ]
+    }
+
+    fn short_weekday_names(&self) -> &[&str; 7] {
+        &["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]
+    }
+
+    fn narrow_weekday_names(&self) -> &[&str; 7] {
+        &["M", "T", "W", "T", "F", "S", "S"]
+    }
+
+    fn period_markers(&self, is_uppercase: bool) -> &[&str; 2] {
+        if is_uppercase {
+            &["AM", "PM"]
+        } else {
+            &["am", "pm"]
+        }
+    }
+}
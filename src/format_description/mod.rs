@@ -1,9 +1,14 @@
 //! Description of how types should be formatted and parsed.
 
 mod component;
+pub mod locale;
 pub mod modifier;
 #[cfg(feature = "alloc")]
+mod owned_format_item;
+#[cfg(feature = "alloc")]
 pub(crate) mod parse;
+#[cfg(feature = "alloc")]
+mod strftime;
 
 #[cfg(feature = "alloc")]
 use alloc::string::String;
@@ -12,7 +17,14 @@ use core::fmt;
 
 pub use self::component::Component;
 #[cfg(feature = "alloc")]
-pub use self::parse::parse;
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub use self::owned_format_item::OwnedFormatItem;
+#[cfg(feature = "alloc")]
+pub use self::parse::{parse, parse_owned};
+#[cfg(feature = "alloc")]
+pub use self::strftime::parse_strftime;
+#[cfg(all(feature = "formatting", feature = "alloc"))]
+pub use self::strftime::to_strftime;
 
 /// Helper methods.
 #[cfg(feature = "alloc")]
@@ -31,9 +43,65 @@ mod helper {
 
 /// Well-known formats, typically RFCs.
 pub mod well_known {
+    pub mod iso8601;
+
+    /// The format described in [RFC 2822](https://tools.ietf.org/html/rfc2822#section-3.3).
+    ///
+    /// When parsing, the obsolete syntax of the RFC is also accepted. This includes two-digit
+    /// years, comments, and named time zones such as `GMT` or `EST`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Rfc2822;
+
     /// The format described in [RFC 3339](https://tools.ietf.org/html/rfc3339#section-5.6).
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct Rfc3339;
+
+    /// The format described in [ISO 8601](https://www.iso.org/iso-8601-date-and-time-format.html).
+    ///
+    /// The [configuration](iso8601::Config) only affects formatting. When parsing, any conforming
+    /// variant is accepted, including the basic and extended formats, calendar, week, and ordinal
+    /// dates, reduced precision, and a decimal fraction on the smallest unit of the time.
+    ///
+    /// A [`Duration`](crate::Duration) is formatted and parsed as an ISO 8601 duration, such as
+    /// `P3DT4H` or `-PT1.5S`, regardless of the configuration. Years and months are rejected when
+    /// parsing, as their length is not fixed.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Iso8601;
+    /// # use time::macros::datetime;
+    /// # use time::OffsetDateTime;
+    /// assert_eq!(
+    ///     OffsetDateTime::parse("20210315T1015Z", &Iso8601::DEFAULT)?,
+    ///     datetime!("2021-03-15 10:15 UTC")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Iso8601 {
+        /// The configuration used when formatting.
+        pub(crate) config: iso8601::Config,
+    }
+
+    impl Iso8601 {
+        /// The format using the [default configuration](iso8601::Config::DEFAULT).
+        pub const DEFAULT: Self = Self::with_config(iso8601::Config::DEFAULT);
+
+        /// Create a format using the provided configuration.
+        pub const fn with_config(config: iso8601::Config) -> Self {
+            Self { config }
+        }
+
+        /// Obtain the configuration used when formatting.
+        pub const fn config(self) -> iso8601::Config {
+            self.config
+        }
+    }
+
+    impl Default for Iso8601 {
+        fn default() -> Self {
+            Self::DEFAULT
+        }
+    }
 }
 
 /// A complete description of how to format and parse a type.
@@ -51,6 +119,15 @@ pub enum FormatItem<'a> {
     /// A series of literals or components that collectively form a partial or complete
     /// description.
     Compound(&'a [Self]),
+    /// An item that may or may not be present when parsing. If parsing fails, there will be no
+    /// effect on the resulting `struct`.
+    ///
+    /// When formatting, the item is always present in the output.
+    Optional(&'a Self),
+    /// A series of items where, when parsing, the first successful parse is used. When
+    /// formatting, the first item is used. If no items are present, both formatting and parsing
+    /// are no-ops.
+    First(&'a [Self]),
 }
 
 #[cfg(feature = "alloc")]
@@ -60,6 +137,8 @@ impl fmt::Debug for FormatItem<'_> {
             FormatItem::Literal(literal) => f.write_str(&String::from_utf8_lossy(literal)),
             FormatItem::Component(component) => component.fmt(f),
             FormatItem::Compound(compound) => compound.fmt(f),
+            FormatItem::Optional(item) => f.debug_tuple("Optional").field(item).finish(),
+            FormatItem::First(items) => f.debug_tuple("First").field(items).finish(),
         }
     }
 }
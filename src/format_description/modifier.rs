@@ -12,6 +12,8 @@ use crate::{error::InvalidFormatDescription, format_description::helper};
 pub struct Day {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// The suffix following the number.
+    pub suffix: Suffix,
 }
 
 /// The representation of a month.
@@ -23,6 +25,11 @@ pub enum MonthRepr {
     Long,
     /// The short form of the month name (e.g. "Jan").
     Short,
+    /// The narrow form of the month name (e.g. "J").
+    ///
+    /// As narrow names are frequently ambiguous, parsing only succeeds if exactly one name
+    /// matches.
+    Narrow,
 }
 
 /// Month of the year.
@@ -32,6 +39,10 @@ pub struct Month {
     pub padding: Padding,
     /// What form of representation should be used?
     pub repr: MonthRepr,
+    /// Is the value case sensitive when parsing?
+    ///
+    /// This setting has no effect on the numerical representation or when formatting.
+    pub case_sensitive: bool,
 }
 
 /// Ordinal day of the year.
@@ -39,6 +50,8 @@ pub struct Month {
 pub struct Ordinal {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// The suffix following the number.
+    pub suffix: Suffix,
 }
 
 /// The representation used for the day of the week.
@@ -49,6 +62,11 @@ pub enum WeekdayRepr {
     Short,
     /// The long form of the weekday (e.g. "Monday").
     Long,
+    /// The narrow form of the weekday (e.g. "M").
+    ///
+    /// As narrow names are frequently ambiguous, parsing only succeeds if exactly one name
+    /// matches.
+    Narrow,
     /// A numerical representation using Sunday as the first day of the week.
     ///
     /// Sunday is either 0 or 1, depending on the other modifier's value.
@@ -68,6 +86,10 @@ pub struct Weekday {
     ///
     /// This setting has no effect on textual representations.
     pub one_indexed: bool,
+    /// Is the value case sensitive when parsing?
+    ///
+    /// This setting has no effect on numerical representations or when formatting.
+    pub case_sensitive: bool,
 }
 
 /// The representation used for the week number.
@@ -89,6 +111,8 @@ pub struct WeekNumber {
     pub padding: Padding,
     /// What kind of representation should be used?
     pub repr: WeekNumberRepr,
+    /// The suffix following the number.
+    pub suffix: Suffix,
 }
 
 /// The representation used for a year value.
@@ -98,6 +122,11 @@ pub enum YearRepr {
     Full,
     /// Only the last two digits of the year.
     LastTwo,
+    /// The number of the year within its era, where there is no year zero. The year before 1 is
+    /// 1 BC, which is year 0 in the full representation. This is typically used with the
+    /// [`Era`](crate::format_description::Component::Era) component. ISO week-based years cannot
+    /// be parsed in this representation.
+    Historical,
 }
 
 /// Year of the date.
@@ -111,6 +140,82 @@ pub struct Year {
     pub iso_week_based: bool,
     /// Whether the `+` sign is present when a positive year contains fewer than five digits.
     pub sign_is_mandatory: bool,
+    /// The minimum number of digits in the full year, between four and six.
+    ///
+    /// When more than four, the expanded representation of ISO 8601 is used: the sign is always
+    /// present, and at least that many digits are required when parsing. This setting has no
+    /// effect on other representations.
+    pub digits: u8,
+}
+
+/// Quarter of the year, where January through March is the first quarter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quarter {
+    /// The padding to obtain the minimum width.
+    pub padding: Padding,
+    /// The suffix following the number.
+    pub suffix: Suffix,
+}
+
+/// Week within the month. Weeks begin on Monday, and the first week is the one containing the
+/// first day of the month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekOfMonth {
+    /// The padding to obtain the minimum width.
+    pub padding: Padding,
+    /// The suffix following the number.
+    pub suffix: Suffix,
+}
+
+/// Week within the quarter. Weeks begin on Monday, and the first week is the one containing the
+/// first day of the quarter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekOfQuarter {
+    /// The padding to obtain the minimum width.
+    pub padding: Padding,
+    /// The suffix following the number.
+    pub suffix: Suffix,
+}
+
+/// Occurrence of the weekday within the month (e.g. 2 for the second Tuesday).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekdayInMonth {
+    /// The padding to obtain the minimum width.
+    pub padding: Padding,
+    /// The suffix following the number.
+    pub suffix: Suffix,
+}
+
+/// Century of the year, being the year divided by 100 and rounded towards zero (e.g. 20 for 2021).
+///
+/// When combined with the last two digits of the year, the full year can be parsed. With the
+/// `large-dates` feature, a signed century not beginning with zero may have up to four digits, so
+/// any digits immediately following it are consumed as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Century {
+    /// The padding to obtain the minimum width.
+    pub padding: Padding,
+    /// The suffix following the number.
+    pub suffix: Suffix,
+}
+
+/// The notation used for the era.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EraRepr {
+    /// Anno Domini notation (e.g. "BC" and "AD").
+    AnnoDomini,
+    /// Common Era notation (e.g. "BCE" and "CE").
+    CommonEra,
+}
+
+/// Era of the year, as provided by the locale. Years before 1 are in the earlier era.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Era {
+    /// Which notation should be used?
+    pub repr: EraRepr,
+    /// Is the value case sensitive when parsing?
+    pub case_sensitive: bool,
 }
 // endregion date modifiers
 
@@ -136,6 +241,10 @@ pub struct Minute {
 pub struct Period {
     /// Is the period uppercase or lowercase?
     pub is_uppercase: bool,
+    /// Is the value case sensitive when parsing?
+    ///
+    /// This setting has no effect when formatting.
+    pub case_sensitive: bool,
 }
 
 /// Second within the minute.
@@ -169,6 +278,57 @@ pub enum SubsecondDigits {
     /// Any number of digits (up to nine) that is at least one. When formatting, the minimum digits
     /// necessary will be used.
     OneOrMore,
+    /// Between `min` and `max` digits, both of which are between one and nine. When formatting,
+    /// the value has `max` digits with trailing zeros removed, but no fewer than `min` digits.
+    #[allow(missing_docs)]
+    Range { min: u8, max: u8 },
+}
+
+impl SubsecondDigits {
+    /// The minimum and maximum number of digits, in that order.
+    pub(crate) const fn bounds(self) -> (u8, u8) {
+        match self {
+            Self::One => (1, 1),
+            Self::Two => (2, 2),
+            Self::Three => (3, 3),
+            Self::Four => (4, 4),
+            Self::Five => (5, 5),
+            Self::Six => (6, 6),
+            Self::Seven => (7, 7),
+            Self::Eight => (8, 8),
+            Self::Nine => (9, 9),
+            Self::OneOrMore => (1, 9),
+            Self::Range { min, max } => {
+                let max = if max == 0 {
+                    1
+                } else if max > 9 {
+                    9
+                } else {
+                    max
+                };
+                let min = if min == 0 {
+                    1
+                } else if min > max {
+                    max
+                } else {
+                    min
+                };
+                (min, max)
+            }
+        }
+    }
+}
+
+/// How the subsecond is rounded to the number of digits formatted.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubsecondRounding {
+    /// Discard any further digits, so that `.9996` is `.999` with three digits.
+    Truncate,
+    /// Round to the nearest value, with halfway cases rounded up.
+    HalfUp,
+    /// Round to the nearest value, with halfway cases rounded to an even last digit.
+    HalfEven,
 }
 
 /// Subsecond within the second.
@@ -176,6 +336,12 @@ pub enum SubsecondDigits {
 pub struct Subsecond {
     /// How many digits are present in the component?
     pub digits: SubsecondDigits,
+    /// How the value is rounded when formatting. Rounding up may carry into the second, and from
+    /// there into larger components. This has no effect on parsing.
+    ///
+    /// As the time is rounded as a whole, only the first subsecond component that rounds is
+    /// honored. Any other subsecond components are truncated from the rounded value.
+    pub rounding: SubsecondRounding,
 }
 // endregion time modifiers
 
@@ -202,8 +368,131 @@ pub struct OffsetSecond {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
 }
+
+/// Abbreviated name of the UTC offset, such as `EST` or `CEST`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OffsetAbbreviation {
+    /// Is the value case sensitive when parsing?
+    pub case_sensitive: bool,
+}
+
+/// The separator between the hour and minute of the UTC offset.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetSeparator {
+    /// A colon is present (e.g. `+05:30`).
+    Colon,
+    /// There is no separator (e.g. `+0530`).
+    None,
+    /// A colon is present when formatting. When parsing, the colon may be omitted.
+    Optional,
+}
+
+/// The UTC offset as a whole, such as `+05:30` or `Z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Offset {
+    /// Whether a zero offset is represented as `Z`. When parsing, `z` is also accepted.
+    pub zulu: bool,
+    /// The separator between the hour and minute.
+    pub separator: OffsetSeparator,
+    /// Whether the minute may be omitted. When formatting, it is omitted only if it is zero.
+    pub minute_is_optional: bool,
+}
 // endregion offset modifiers
 
+// region: duration modifiers
+/// Whether a component of a duration includes the time in larger units.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationRepr {
+    /// The entire duration in this unit (e.g. 36 hours).
+    Total,
+    /// The time remaining after larger units are removed (e.g. 12 hours after one day).
+    Remainder,
+}
+
+/// Number of whole days in the duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationDay;
+
+/// Number of whole hours in the duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationHour {
+    /// The padding to obtain the minimum width.
+    pub padding: Padding,
+    /// Whether the value includes whole days.
+    pub repr: DurationRepr,
+}
+
+/// Number of whole minutes in the duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationMinute {
+    /// The padding to obtain the minimum width.
+    pub padding: Padding,
+    /// Whether the value includes whole hours.
+    pub repr: DurationRepr,
+}
+
+/// Number of whole seconds in the duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationSecond {
+    /// The padding to obtain the minimum width.
+    pub padding: Padding,
+    /// Whether the value includes whole minutes.
+    pub repr: DurationRepr,
+}
+
+/// Fraction of a second in the duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationSubsecond {
+    /// How many digits are present in the component?
+    pub digits: SubsecondDigits,
+}
+
+/// Sign of the duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationSign {
+    /// Whether the `+` sign is present on positive values.
+    pub sign_is_mandatory: bool,
+}
+// endregion duration modifiers
+
+// region: other modifiers
+/// The unit of a Unix timestamp.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnixTimestampPrecision {
+    /// The number of seconds since the Unix epoch.
+    Second,
+    /// The number of milliseconds since the Unix epoch.
+    Millisecond,
+    /// The number of microseconds since the Unix epoch.
+    Microsecond,
+    /// The number of nanoseconds since the Unix epoch.
+    Nanosecond,
+}
+
+/// The number of units of time since the Unix epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnixTimestamp {
+    /// The unit of the timestamp.
+    pub precision: UnixTimestampPrecision,
+    /// Whether the `+` sign is present on positive values.
+    pub sign_is_mandatory: bool,
+}
+
+/// A number of bytes to skip when parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ignore {
+    /// The number of bytes to skip.
+    pub count: u16,
+}
+
+/// The end of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct End;
+// endregion other modifiers
+
 /// Type of padding to ensure a minimum width.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -217,6 +506,16 @@ pub enum Padding {
     None,
 }
 
+/// Text following a number.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Suffix {
+    /// There is no suffix.
+    None,
+    /// The ordinal suffix provided by the locale (e.g. "st" in "1st" or "nd" in "22nd").
+    Ordinal,
+}
+
 macro_rules! impl_default {
     ($($type:ty => $default:expr;)*) => {$(
         impl Default for $type {
@@ -229,8 +528,14 @@ macro_rules! impl_default {
 
 impl_default! {
     Padding => Self::Zero;
+    DurationRepr => Self::Remainder;
+    EraRepr => Self::AnnoDomini;
     MonthRepr => Self::Numerical;
+    OffsetSeparator => Self::Colon;
     SubsecondDigits => Self::OneOrMore;
+    SubsecondRounding => Self::Truncate;
+    Suffix => Self::None;
+    UnixTimestampPrecision => Self::Second;
     WeekdayRepr => Self::Long;
     WeekNumberRepr => Self::Iso;
     YearRepr => Self::Full;
@@ -244,13 +549,24 @@ pub(crate) struct Modifiers {
     pub(crate) hour_is_12_hour_clock: Option<bool>,
     pub(crate) period_is_uppercase: Option<bool>,
     pub(crate) month_repr: Option<MonthRepr>,
+    pub(crate) duration_repr: Option<DurationRepr>,
     pub(crate) subsecond_digits: Option<SubsecondDigits>,
+    pub(crate) subsecond_rounding: Option<SubsecondRounding>,
+    pub(crate) unix_timestamp_precision: Option<UnixTimestampPrecision>,
     pub(crate) weekday_repr: Option<WeekdayRepr>,
     pub(crate) weekday_is_one_indexed: Option<bool>,
     pub(crate) week_number_repr: Option<WeekNumberRepr>,
     pub(crate) year_repr: Option<YearRepr>,
     pub(crate) year_is_iso_week_based: Option<bool>,
+    pub(crate) year_digits: Option<u8>,
+    pub(crate) era_repr: Option<EraRepr>,
+    pub(crate) offset_zulu: Option<bool>,
+    pub(crate) offset_separator: Option<OffsetSeparator>,
+    pub(crate) offset_minute_is_optional: Option<bool>,
     pub(crate) sign_is_mandatory: Option<bool>,
+    pub(crate) ignore_count: Option<u16>,
+    pub(crate) case_sensitive: Option<bool>,
+    pub(crate) suffix: Option<Suffix>,
 }
 
 impl Modifiers {
@@ -283,7 +599,11 @@ impl Modifiers {
             }
 
             match (component_name, modifier) {
-                (b"day", b"padding:space")
+                (b"century", b"padding:space")
+                | (b"day", b"padding:space")
+                | (b"duration_hour", b"padding:space")
+                | (b"duration_minute", b"padding:space")
+                | (b"duration_second", b"padding:space")
                 | (b"hour", b"padding:space")
                 | (b"minute", b"padding:space")
                 | (b"month", b"padding:space")
@@ -291,10 +611,18 @@ impl Modifiers {
                 | (b"offset_minute", b"padding:space")
                 | (b"offset_second", b"padding:space")
                 | (b"ordinal", b"padding:space")
+                | (b"quarter", b"padding:space")
                 | (b"second", b"padding:space")
                 | (b"week_number", b"padding:space")
+                | (b"week_of_month", b"padding:space")
+                | (b"week_of_quarter", b"padding:space")
+                | (b"weekday_in_month", b"padding:space")
                 | (b"year", b"padding:space") => modifiers.padding = Some(Padding::Space),
-                (b"day", b"padding:zero")
+                (b"century", b"padding:zero")
+                | (b"day", b"padding:zero")
+                | (b"duration_hour", b"padding:zero")
+                | (b"duration_minute", b"padding:zero")
+                | (b"duration_second", b"padding:zero")
                 | (b"hour", b"padding:zero")
                 | (b"minute", b"padding:zero")
                 | (b"month", b"padding:zero")
@@ -302,10 +630,18 @@ impl Modifiers {
                 | (b"offset_minute", b"padding:zero")
                 | (b"offset_second", b"padding:zero")
                 | (b"ordinal", b"padding:zero")
+                | (b"quarter", b"padding:zero")
                 | (b"second", b"padding:zero")
                 | (b"week_number", b"padding:zero")
+                | (b"week_of_month", b"padding:zero")
+                | (b"week_of_quarter", b"padding:zero")
+                | (b"weekday_in_month", b"padding:zero")
                 | (b"year", b"padding:zero") => modifiers.padding = Some(Padding::Zero),
-                (b"day", b"padding:none")
+                (b"century", b"padding:none")
+                | (b"day", b"padding:none")
+                | (b"duration_hour", b"padding:none")
+                | (b"duration_minute", b"padding:none")
+                | (b"duration_second", b"padding:none")
                 | (b"hour", b"padding:none")
                 | (b"minute", b"padding:none")
                 | (b"month", b"padding:none")
@@ -313,54 +649,167 @@ impl Modifiers {
                 | (b"offset_minute", b"padding:none")
                 | (b"offset_second", b"padding:none")
                 | (b"ordinal", b"padding:none")
+                | (b"quarter", b"padding:none")
                 | (b"second", b"padding:none")
                 | (b"week_number", b"padding:none")
+                | (b"week_of_month", b"padding:none")
+                | (b"week_of_quarter", b"padding:none")
+                | (b"weekday_in_month", b"padding:none")
                 | (b"year", b"padding:none") => modifiers.padding = Some(Padding::None),
+                (b"duration_hour", b"repr:total")
+                | (b"duration_minute", b"repr:total")
+                | (b"duration_second", b"repr:total") => {
+                    modifiers.duration_repr = Some(DurationRepr::Total);
+                }
+                (b"duration_hour", b"repr:remainder")
+                | (b"duration_minute", b"repr:remainder")
+                | (b"duration_second", b"repr:remainder") => {
+                    modifiers.duration_repr = Some(DurationRepr::Remainder);
+                }
+                (b"era", b"repr:ad") => modifiers.era_repr = Some(EraRepr::AnnoDomini),
+                (b"era", b"repr:ce") => modifiers.era_repr = Some(EraRepr::CommonEra),
                 (b"hour", b"repr:24") => modifiers.hour_is_12_hour_clock = Some(false),
                 (b"hour", b"repr:12") => modifiers.hour_is_12_hour_clock = Some(true),
+                (b"ignore", modifier) if modifier.starts_with(b"count:") => {
+                    modifiers.ignore_count = Some(
+                        core::str::from_utf8(&modifier[b"count:".len()..])
+                            .ok()
+                            .and_then(|count| count.parse().ok())
+                            .ok_or_else(|| InvalidFormatDescription::InvalidModifier {
+                                value: String::from_utf8_lossy(modifier).into_owned(),
+                                index: *index,
+                            })?,
+                    )
+                }
+                (b"era", b"case_sensitive:true")
+                | (b"month", b"case_sensitive:true")
+                | (b"offset_abbreviation", b"case_sensitive:true")
+                | (b"period", b"case_sensitive:true")
+                | (b"weekday", b"case_sensitive:true") => modifiers.case_sensitive = Some(true),
+                (b"era", b"case_sensitive:false")
+                | (b"month", b"case_sensitive:false")
+                | (b"offset_abbreviation", b"case_sensitive:false")
+                | (b"period", b"case_sensitive:false")
+                | (b"weekday", b"case_sensitive:false") => modifiers.case_sensitive = Some(false),
+                (b"century", b"suffix:none")
+                | (b"day", b"suffix:none")
+                | (b"ordinal", b"suffix:none")
+                | (b"quarter", b"suffix:none")
+                | (b"week_number", b"suffix:none")
+                | (b"week_of_month", b"suffix:none")
+                | (b"week_of_quarter", b"suffix:none")
+                | (b"weekday_in_month", b"suffix:none") => modifiers.suffix = Some(Suffix::None),
+                (b"century", b"suffix:ordinal")
+                | (b"day", b"suffix:ordinal")
+                | (b"ordinal", b"suffix:ordinal")
+                | (b"quarter", b"suffix:ordinal")
+                | (b"week_number", b"suffix:ordinal")
+                | (b"week_of_month", b"suffix:ordinal")
+                | (b"week_of_quarter", b"suffix:ordinal")
+                | (b"weekday_in_month", b"suffix:ordinal") => {
+                    modifiers.suffix = Some(Suffix::Ordinal);
+                }
                 (b"month", b"repr:numerical") => modifiers.month_repr = Some(MonthRepr::Numerical),
                 (b"month", b"repr:long") => modifiers.month_repr = Some(MonthRepr::Long),
                 (b"month", b"repr:short") => modifiers.month_repr = Some(MonthRepr::Short),
-                (b"offset_hour", b"sign:automatic") | (b"year", b"sign:automatic") => {
-                    modifiers.sign_is_mandatory = Some(false)
+                (b"month", b"repr:narrow") => modifiers.month_repr = Some(MonthRepr::Narrow),
+                (b"offset", b"zulu:true") => modifiers.offset_zulu = Some(true),
+                (b"offset", b"zulu:false") => modifiers.offset_zulu = Some(false),
+                (b"offset", b"separator:colon") => {
+                    modifiers.offset_separator = Some(OffsetSeparator::Colon);
                 }
-                (b"offset_hour", b"sign:mandatory") | (b"year", b"sign:mandatory") => {
-                    modifiers.sign_is_mandatory = Some(true)
+                (b"offset", b"separator:none") => {
+                    modifiers.offset_separator = Some(OffsetSeparator::None);
                 }
+                (b"offset", b"separator:optional") => {
+                    modifiers.offset_separator = Some(OffsetSeparator::Optional);
+                }
+                (b"offset", b"minute:mandatory") => {
+                    modifiers.offset_minute_is_optional = Some(false);
+                }
+                (b"offset", b"minute:optional") => modifiers.offset_minute_is_optional = Some(true),
+                (b"duration_sign", b"sign:automatic")
+                | (b"offset_hour", b"sign:automatic")
+                | (b"unix_timestamp", b"sign:automatic")
+                | (b"year", b"sign:automatic") => modifiers.sign_is_mandatory = Some(false),
+                (b"duration_sign", b"sign:mandatory")
+                | (b"offset_hour", b"sign:mandatory")
+                | (b"unix_timestamp", b"sign:mandatory")
+                | (b"year", b"sign:mandatory") => modifiers.sign_is_mandatory = Some(true),
                 (b"period", b"case:upper") => modifiers.period_is_uppercase = Some(true),
                 (b"period", b"case:lower") => modifiers.period_is_uppercase = Some(false),
-                (b"subsecond", b"digits:1") => {
+                (b"duration_subsecond", b"digits:1") | (b"subsecond", b"digits:1") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::One)
                 }
-                (b"subsecond", b"digits:2") => {
+                (b"duration_subsecond", b"digits:2") | (b"subsecond", b"digits:2") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::Two)
                 }
-                (b"subsecond", b"digits:3") => {
+                (b"duration_subsecond", b"digits:3") | (b"subsecond", b"digits:3") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::Three)
                 }
-                (b"subsecond", b"digits:4") => {
+                (b"duration_subsecond", b"digits:4") | (b"subsecond", b"digits:4") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::Four)
                 }
-                (b"subsecond", b"digits:5") => {
+                (b"duration_subsecond", b"digits:5") | (b"subsecond", b"digits:5") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::Five)
                 }
-                (b"subsecond", b"digits:6") => {
+                (b"duration_subsecond", b"digits:6") | (b"subsecond", b"digits:6") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::Six)
                 }
-                (b"subsecond", b"digits:7") => {
+                (b"duration_subsecond", b"digits:7") | (b"subsecond", b"digits:7") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::Seven)
                 }
-                (b"subsecond", b"digits:8") => {
+                (b"duration_subsecond", b"digits:8") | (b"subsecond", b"digits:8") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::Eight)
                 }
-                (b"subsecond", b"digits:9") => {
+                (b"duration_subsecond", b"digits:9") | (b"subsecond", b"digits:9") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::Nine)
                 }
-                (b"subsecond", b"digits:1+") => {
+                (b"duration_subsecond", b"digits:1+") | (b"subsecond", b"digits:1+") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::OneOrMore)
                 }
+                (b"duration_subsecond", modifier) | (b"subsecond", modifier)
+                    if modifier.starts_with(b"digits:") =>
+                {
+                    modifiers.subsecond_digits = Some(match &modifier[b"digits:".len()..] {
+                        &[min @ b'1'..=b'9', b'-', max @ b'1'..=b'9'] if min <= max => {
+                            SubsecondDigits::Range {
+                                min: min - b'0',
+                                max: max - b'0',
+                            }
+                        }
+                        _ => {
+                            return Err(InvalidFormatDescription::InvalidModifier {
+                                value: String::from_utf8_lossy(modifier).into_owned(),
+                                index: *index,
+                            });
+                        }
+                    });
+                }
+                (b"subsecond", b"rounding:truncate") => {
+                    modifiers.subsecond_rounding = Some(SubsecondRounding::Truncate);
+                }
+                (b"subsecond", b"rounding:half_up") => {
+                    modifiers.subsecond_rounding = Some(SubsecondRounding::HalfUp);
+                }
+                (b"subsecond", b"rounding:half_even") => {
+                    modifiers.subsecond_rounding = Some(SubsecondRounding::HalfEven);
+                }
+                (b"unix_timestamp", b"precision:second") => {
+                    modifiers.unix_timestamp_precision = Some(UnixTimestampPrecision::Second)
+                }
+                (b"unix_timestamp", b"precision:millisecond") => {
+                    modifiers.unix_timestamp_precision = Some(UnixTimestampPrecision::Millisecond)
+                }
+                (b"unix_timestamp", b"precision:microsecond") => {
+                    modifiers.unix_timestamp_precision = Some(UnixTimestampPrecision::Microsecond)
+                }
+                (b"unix_timestamp", b"precision:nanosecond") => {
+                    modifiers.unix_timestamp_precision = Some(UnixTimestampPrecision::Nanosecond)
+                }
                 (b"weekday", b"repr:short") => modifiers.weekday_repr = Some(WeekdayRepr::Short),
                 (b"weekday", b"repr:long") => modifiers.weekday_repr = Some(WeekdayRepr::Long),
+                (b"weekday", b"repr:narrow") => modifiers.weekday_repr = Some(WeekdayRepr::Narrow),
                 (b"weekday", b"repr:sunday") => modifiers.weekday_repr = Some(WeekdayRepr::Sunday),
                 (b"weekday", b"repr:monday") => modifiers.weekday_repr = Some(WeekdayRepr::Monday),
                 (b"weekday", b"one_indexed:true") => modifiers.weekday_is_one_indexed = Some(true),
@@ -378,6 +827,10 @@ impl Modifiers {
                 }
                 (b"year", b"repr:full") => modifiers.year_repr = Some(YearRepr::Full),
                 (b"year", b"repr:last_two") => modifiers.year_repr = Some(YearRepr::LastTwo),
+                (b"year", b"repr:historical") => modifiers.year_repr = Some(YearRepr::Historical),
+                (b"year", b"digits:4") => modifiers.year_digits = Some(4),
+                (b"year", b"digits:5") => modifiers.year_digits = Some(5),
+                (b"year", b"digits:6") => modifiers.year_digits = Some(6),
                 (b"year", b"base:calendar") => modifiers.year_is_iso_week_based = Some(false),
                 (b"year", b"base:iso_week") => modifiers.year_is_iso_week_based = Some(true),
                 _ => {
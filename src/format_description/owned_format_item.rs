@@ -0,0 +1,98 @@
+//! A format item with owned data.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::format_description::{Component, FormatItem};
+
+/// A complete description of how to format and parse a type.
+///
+/// This has the same shape as [`FormatItem`], but owns its literals and nested items. As such, it
+/// does not borrow from the format description it was parsed from, and can be stored and sent
+/// across threads freely.
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Eq)]
+pub enum OwnedFormatItem {
+    /// Bytes that are formatted as-is.
+    ///
+    /// **Note**: If you call the `format` method that returns a `String`, these bytes will be
+    /// passed through `String::from_utf8_lossy`.
+    Literal(Box<[u8]>),
+    /// A minimal representation of a single non-literal item.
+    Component(Component),
+    /// A series of literals or components that collectively form a partial or complete
+    /// description.
+    Compound(Box<[Self]>),
+    /// An item that may or may not be present when parsing. If parsing fails, there will be no
+    /// effect on the resulting `struct`.
+    ///
+    /// When formatting, the item is always present in the output.
+    Optional(Box<Self>),
+    /// A series of items where, when parsing, the first successful parse is used. When
+    /// formatting, the first item is used. If no items are present, both formatting and parsing
+    /// are no-ops.
+    First(Box<[Self]>),
+}
+
+impl fmt::Debug for OwnedFormatItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => f.write_str(&String::from_utf8_lossy(literal)),
+            Self::Component(component) => component.fmt(f),
+            Self::Compound(compound) => compound.fmt(f),
+            Self::Optional(item) => f.debug_tuple("Optional").field(item).finish(),
+            Self::First(items) => f.debug_tuple("First").field(items).finish(),
+        }
+    }
+}
+
+impl From<&FormatItem<'_>> for OwnedFormatItem {
+    fn from(item: &FormatItem<'_>) -> Self {
+        match *item {
+            FormatItem::Literal(literal) => Self::Literal(literal.into()),
+            FormatItem::Component(component) => Self::Component(component),
+            FormatItem::Compound(compound) => compound.into(),
+            FormatItem::Optional(item) => Self::Optional(Box::new(item.into())),
+            FormatItem::First(items) => Self::First(items.iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl From<FormatItem<'_>> for OwnedFormatItem {
+    fn from(item: FormatItem<'_>) -> Self {
+        (&item).into()
+    }
+}
+
+impl From<&[FormatItem<'_>]> for OwnedFormatItem {
+    fn from(items: &[FormatItem<'_>]) -> Self {
+        Self::Compound(items.iter().map(Into::into).collect())
+    }
+}
+
+impl From<Vec<FormatItem<'_>>> for OwnedFormatItem {
+    fn from(items: Vec<FormatItem<'_>>) -> Self {
+        items.as_slice().into()
+    }
+}
+
+impl PartialEq<FormatItem<'_>> for OwnedFormatItem {
+    fn eq(&self, rhs: &FormatItem<'_>) -> bool {
+        match (self, rhs) {
+            (Self::Literal(lhs), FormatItem::Literal(rhs)) => **lhs == **rhs,
+            (Self::Component(lhs), FormatItem::Component(rhs)) => lhs == rhs,
+            (Self::Compound(lhs), FormatItem::Compound(rhs))
+            | (Self::First(lhs), FormatItem::First(rhs)) => **lhs == **rhs,
+            (Self::Optional(lhs), FormatItem::Optional(rhs)) => **lhs == **rhs,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<OwnedFormatItem> for FormatItem<'_> {
+    fn eq(&self, rhs: &OwnedFormatItem) -> bool {
+        rhs == self
+    }
+}
@@ -1,16 +1,57 @@
 //! Parse a format description into a standardized representation.
 
+use alloc::boxed::Box;
 use alloc::vec::Vec;
 
 use crate::error::InvalidFormatDescription;
 use crate::format_description::component::{Component, NakedComponent};
-use crate::format_description::{helper, modifier, FormatItem};
+use crate::format_description::{helper, modifier, OwnedFormatItem};
+
+/// An item of the format description, which may contain nested items.
+#[derive(Debug)]
+enum Item<'a> {
+    /// Bytes that are formatted as-is.
+    Literal(&'a [u8]),
+    /// A single non-literal item.
+    Component(Component),
+    /// `[optional [...]]`
+    Optional(Vec<Self>),
+    /// `[first [...] [...]]`
+    First(Vec<Vec<Self>>),
+    /// `[[...]]`, only present in version 2 of the grammar.
+    Group(Vec<Self>),
+}
+
+impl Item<'_> {
+    /// Convert the item to an [`OwnedFormatItem`].
+    fn into_owned_format_item(self) -> OwnedFormatItem {
+        match self {
+            Self::Literal(literal) => OwnedFormatItem::Literal(literal.into()),
+            Self::Component(component) => OwnedFormatItem::Component(component),
+            Self::Optional(items) => OwnedFormatItem::Optional(Box::new(compound(items))),
+            Self::First(alternatives) => {
+                OwnedFormatItem::First(alternatives.into_iter().map(compound).collect())
+            }
+            Self::Group(items) => compound(items),
+        }
+    }
+}
+
+/// Convert a sequence of items into an [`OwnedFormatItem::Compound`].
+fn compound(items: Vec<Item<'_>>) -> OwnedFormatItem {
+    OwnedFormatItem::Compound(
+        items
+            .into_iter()
+            .map(Item::into_owned_format_item)
+            .collect(),
+    )
+}
 
 /// The item parsed and remaining chunk of the format description after one iteration.
 #[derive(Debug)]
-struct ParsedItem<'a> {
+struct ParsedItem<'a, T = Item<'a>> {
     /// The item that was parsed.
-    item: FormatItem<'a>,
+    item: T,
     /// What is left of the input string after the item was parsed.
     remaining: &'a [u8],
 }
@@ -32,38 +73,176 @@ fn parse_component(mut s: &[u8], index: &mut usize) -> Result<Component, Invalid
     s = &s[whitespace_loc..];
     s = helper::consume_whitespace(s, index);
 
-    Ok(NakedComponent::parse(component_name, component_index)?
-        .attach_modifiers(&modifier::Modifiers::parse(component_name, s, index)?))
+    let component = NakedComponent::parse(component_name, component_index)?;
+    let modifiers = modifier::Modifiers::parse(component_name, s, index)?;
+    component.attach_modifiers(&modifiers, *index)
 }
 
-/// Parse a literal string from the format description.
-fn parse_literal<'a>(s: &'a [u8], index: &mut usize) -> ParsedItem<'a> {
-    let loc = s.iter().position(|&c| c == b'[').unwrap_or(s.len());
+/// Parse a literal string from the format description. When `is_nested` is true, the literal also
+/// ends at a closing bracket. In version 2 of the grammar, the literal also ends at a backslash.
+fn parse_literal<'a>(
+    s: &'a [u8],
+    index: &mut usize,
+    is_nested: bool,
+    version: u8,
+) -> ParsedItem<'a> {
+    let loc = s
+        .iter()
+        .position(|&c| c == b'[' || (is_nested && c == b']') || (version == 2 && c == b'\\'))
+        .unwrap_or(s.len());
     *index += loc;
     ParsedItem {
-        item: FormatItem::Literal(&s[..loc]),
+        item: Item::Literal(&s[..loc]),
         remaining: &s[loc..],
     }
 }
 
-/// Parse either a literal or a component from the format description.
+/// Parse a nested description, including the surrounding brackets.
+fn parse_nested<'a>(
+    s: &'a [u8],
+    index: &mut usize,
+    version: u8,
+) -> Result<ParsedItem<'a, Vec<Item<'a>>>, InvalidFormatDescription> {
+    let opening_index = *index;
+    let mut s = match s {
+        [b'[', remaining @ ..] => remaining,
+        _ => {
+            return Err(InvalidFormatDescription::Expected {
+                what: "opening bracket",
+                index: *index,
+            });
+        }
+    };
+    *index += 1;
+
+    let mut items = Vec::new();
+    loop {
+        match s {
+            [] => {
+                return Err(InvalidFormatDescription::UnclosedOpeningBracket {
+                    index: opening_index,
+                });
+            }
+            [b']', remaining @ ..] => {
+                *index += 1;
+                return Ok(ParsedItem {
+                    item: items,
+                    remaining,
+                });
+            }
+            _ => {
+                let ParsedItem { item, remaining } = parse_item(s, index, true, version)?;
+                s = remaining;
+                items.push(item);
+            }
+        }
+    }
+}
+
+/// Parse a section containing nested descriptions (`[optional [...]]`, `[first [...] [...]]`, or
+/// `[[...]]` in version 2 of the grammar), returning `None` if the item is not such a section.
+/// `index` is only advanced if a section is present.
+fn parse_section<'a>(
+    s: &'a [u8],
+    index: &mut usize,
+    version: u8,
+) -> Result<Option<ParsedItem<'a>>, InvalidFormatDescription> {
+    let opening_index = *index;
+    let mut loc = *index + 1;
+    let keyword = helper::consume_whitespace(&s[1..], &mut loc);
+    let (keyword_name, s): (&[u8], _) = match keyword {
+        [b'o', b'p', b't', b'i', b'o', b'n', b'a', b'l', remaining @ ..] => {
+            (b"optional", remaining)
+        }
+        [b'f', b'i', b'r', b's', b't', remaining @ ..] => (b"first", remaining),
+        [b'[', ..] if version == 2 => (b"", keyword),
+        _ => return Ok(None),
+    };
+    match s.first() {
+        None | Some(b'[') | Some(b']') => {}
+        Some(c) if c.is_ascii_whitespace() => {}
+        _ => return Ok(None),
+    }
+    loc += keyword.len() - s.len();
+
+    let s = helper::consume_whitespace(s, &mut loc);
+    let ParsedItem {
+        item: items,
+        mut remaining,
+    } = parse_nested(s, &mut loc, version)?;
+    let item = if keyword_name.is_empty() {
+        Item::Group(items)
+    } else if keyword_name == b"optional" {
+        Item::Optional(items)
+    } else {
+        let mut alternatives = Vec::new();
+        alternatives.push(items);
+        loop {
+            remaining = helper::consume_whitespace(remaining, &mut loc);
+            if !remaining.starts_with(&[b'[']) {
+                break;
+            }
+            let nested = parse_nested(remaining, &mut loc, version)?;
+            alternatives.push(nested.item);
+            remaining = nested.remaining;
+        }
+        Item::First(alternatives)
+    };
+
+    let remaining = match helper::consume_whitespace(remaining, &mut loc) {
+        [b']', remaining @ ..] => remaining,
+        _ => {
+            return Err(InvalidFormatDescription::UnclosedOpeningBracket {
+                index: opening_index,
+            });
+        }
+    };
+    *index = loc + 1;
+
+    Ok(Some(ParsedItem { item, remaining }))
+}
+
+/// Parse either a literal or a component from the format description. When `is_nested` is true,
+/// the item is inside a bracketed section.
 fn parse_item<'a>(
     s: &'a [u8],
     index: &mut usize,
+    is_nested: bool,
+    version: u8,
 ) -> Result<ParsedItem<'a>, InvalidFormatDescription> {
-    if let [b'[', b'[', remaining @ ..] = s {
-        *index += 2;
-        return Ok(ParsedItem {
-            item: FormatItem::Literal(&[b'[']),
-            remaining,
-        });
-    };
+    if version == 1 {
+        if let [b'[', b'[', remaining @ ..] = s {
+            *index += 2;
+            return Ok(ParsedItem {
+                item: Item::Literal(&[b'[']),
+                remaining,
+            });
+        };
+    } else if s.starts_with(&[b'\\']) {
+        return match s {
+            [_, c, remaining @ ..] if [b'\\', b'[', b']'].contains(c) => {
+                *index += 2;
+                Ok(ParsedItem {
+                    item: Item::Literal(&s[1..2]),
+                    remaining,
+                })
+            }
+            _ => Err(InvalidFormatDescription::Expected {
+                what: "`\\`, `[`, or `]` after backslash",
+                index: *index + 1,
+            }),
+        };
+    }
 
     if s.starts_with(&[b'[']) {
+        if let Some(parsed_item) = parse_section(s, index, version)? {
+            return Ok(parsed_item);
+        }
+
         if let Some(bracket_index) = s.iter().position(|&c| c == b']') {
             *index += 1; // opening bracket
             let ret_val = ParsedItem {
-                item: FormatItem::Component(parse_component(&s[1..bracket_index], index)?),
+                item: Item::Component(parse_component(&s[1..bracket_index], index)?),
                 remaining: &s[bracket_index + 1..],
             };
             *index += 1; // closing bracket
@@ -72,23 +251,133 @@ fn parse_item<'a>(
             Err(InvalidFormatDescription::UnclosedOpeningBracket { index: *index })
         }
     } else {
-        Ok(parse_literal(s, index))
+        Ok(parse_literal(s, index, is_nested, version))
     }
 }
 
-/// Parse a sequence of items from the format description.
-#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
-pub fn parse(s: &str) -> Result<Vec<FormatItem<'_>>, InvalidFormatDescription> {
+/// Parse the version marker (`[version 1]` or `[version 2]`) at the start of the format
+/// description, returning the version and the remaining input. Format descriptions without a
+/// marker use version 1 of the grammar.
+fn parse_version<'a>(
+    s: &'a [u8],
+    index: &mut usize,
+) -> Result<ParsedItem<'a, u8>, InvalidFormatDescription> {
+    let unversioned = ParsedItem {
+        item: 1,
+        remaining: s,
+    };
+
+    let mut loc = *index + 1;
+    let keyword = match s {
+        [b'[', remaining @ ..] => helper::consume_whitespace(remaining, &mut loc),
+        _ => return Ok(unversioned),
+    };
+    let s = match keyword {
+        [b'v', b'e', b'r', b's', b'i', b'o', b'n', remaining @ ..] => remaining,
+        _ => return Ok(unversioned),
+    };
+    match s.first() {
+        None | Some(b']') => {}
+        Some(c) if c.is_ascii_whitespace() => {}
+        _ => return Ok(unversioned),
+    }
+    loc += keyword.len() - s.len();
+
+    let s = helper::consume_whitespace(s, &mut loc);
+    let version_index = loc;
+    let (version, s) = match s {
+        [b'1', remaining @ ..] => (1, remaining),
+        [b'2', remaining @ ..] => (2, remaining),
+        _ => {
+            return Err(InvalidFormatDescription::Expected {
+                what: "version 1 or 2",
+                index: version_index,
+            });
+        }
+    };
+    loc += 1;
+
+    match helper::consume_whitespace(s, &mut loc) {
+        [b']', remaining @ ..] => {
+            *index = loc + 1;
+            Ok(ParsedItem {
+                item: version,
+                remaining,
+            })
+        }
+        [] => Err(InvalidFormatDescription::UnclosedOpeningBracket { index: *index }),
+        _ => Err(InvalidFormatDescription::Expected {
+            what: "version 1 or 2",
+            index: version_index,
+        }),
+    }
+}
+
+/// Parse a sequence of items from the format description, which may contain nested items.
+fn parse_items(s: &str) -> Result<Vec<Item<'_>>, InvalidFormatDescription> {
     let mut compound = Vec::new();
     let mut loc = 0;
 
-    let mut s = s.as_bytes();
+    let ParsedItem {
+        item: version,
+        remaining: mut s,
+    } = parse_version(s.as_bytes(), &mut loc)?;
 
     while !s.is_empty() {
-        let ParsedItem { item, remaining } = parse_item(s, &mut loc)?;
+        let ParsedItem { item, remaining } = parse_item(s, &mut loc, false, version)?;
         s = remaining;
         compound.push(item);
     }
 
     Ok(compound)
 }
+
+/// Parse a sequence of items from the format description. The returned items do not borrow from the
+/// input.
+///
+/// A format description may start with a version marker, `[version 1]` or `[version 2]`. Without
+/// a marker, version 1 of the grammar is used. Version 2 differs in the following ways:
+///
+/// - `\` escapes the following `\`, `[`, or `]`, so that it is treated as a literal. `[[` is no
+///   longer an escape sequence.
+/// - `[[...]]` groups the nested items, which become an [`OwnedFormatItem::Compound`].
+///
+/// Sections containing nested descriptions are supported:
+///
+/// - `[optional [...]]` is used when parsing if it matches, and is always present when formatting.
+/// - `[first [...] [...]]` uses the first alternative that matches when parsing, and the first
+///   alternative when formatting.
+///
+/// ```rust
+/// # use time::format_description;
+/// # use time::macros::time;
+/// let format = format_description::parse(r"[version 2]\[[hour]:[minute]\]")?;
+/// assert_eq!(time!("10:15").format(&format)?, "[10:15]");
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub fn parse(s: &str) -> Result<Vec<OwnedFormatItem>, InvalidFormatDescription> {
+    Ok(parse_items(s)?
+        .into_iter()
+        .map(Item::into_owned_format_item)
+        .collect())
+}
+
+/// Parse a format description into an [`OwnedFormatItem`], which does not borrow from the input.
+///
+/// The grammar is the same as for [`parse`], with the items wrapped in an
+/// [`OwnedFormatItem::Compound`].
+///
+/// ```rust
+/// # use time::format_description;
+/// # use time::macros::time;
+/// # use time::Time;
+/// let format = format_description::parse_owned("[hour]:[minute][optional [:[second]]]")?;
+/// assert_eq!(Time::parse("10:15", &format)?, time!("10:15"));
+/// assert_eq!(Time::parse("10:15:30", &format)?, time!("10:15:30"));
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub fn parse_owned(s: &str) -> Result<OwnedFormatItem, InvalidFormatDescription> {
+    Ok(compound(parse_items(s)?))
+}
@@ -0,0 +1,531 @@
+//! Conversion between format descriptions and strftime format strings.
+
+#[cfg(feature = "formatting")]
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "formatting")]
+use crate::error;
+use crate::error::InvalidFormatDescription;
+use crate::format_description::modifier::{
+    self, MonthRepr, Padding, Suffix, UnixTimestampPrecision, WeekNumberRepr, WeekdayRepr, YearRepr,
+};
+use crate::format_description::{Component, FormatItem};
+
+// region: components
+/// `%Y`
+const YEAR: Component = Component::Year(modifier::Year {
+    padding: Padding::Zero,
+    repr: YearRepr::Full,
+    iso_week_based: false,
+    sign_is_mandatory: false,
+    digits: 4,
+});
+/// `%y`
+const YEAR_LAST_TWO: Component = Component::Year(modifier::Year {
+    padding: Padding::Zero,
+    repr: YearRepr::LastTwo,
+    iso_week_based: false,
+    sign_is_mandatory: false,
+    digits: 4,
+});
+/// `%m`
+const MONTH: Component = Component::Month(modifier::Month {
+    padding: Padding::Zero,
+    repr: MonthRepr::Numerical,
+    case_sensitive: true,
+});
+/// `%b`
+const MONTH_SHORT: Component = Component::Month(modifier::Month {
+    padding: Padding::Zero,
+    repr: MonthRepr::Short,
+    case_sensitive: true,
+});
+/// `%d`
+const DAY: Component = Component::Day(modifier::Day {
+    padding: Padding::Zero,
+    suffix: Suffix::None,
+});
+/// `%e`
+const DAY_SPACE_PADDED: Component = Component::Day(modifier::Day {
+    padding: Padding::Space,
+    suffix: Suffix::None,
+});
+/// `%a`
+const WEEKDAY_SHORT: Component = Component::Weekday(modifier::Weekday {
+    repr: WeekdayRepr::Short,
+    one_indexed: true,
+    case_sensitive: true,
+});
+/// `%H`
+const HOUR: Component = Component::Hour(modifier::Hour {
+    padding: Padding::Zero,
+    is_12_hour_clock: false,
+});
+/// `%I`
+const HOUR_12: Component = Component::Hour(modifier::Hour {
+    padding: Padding::Zero,
+    is_12_hour_clock: true,
+});
+/// `%M`
+const MINUTE: Component = Component::Minute(modifier::Minute {
+    padding: Padding::Zero,
+});
+/// `%S`
+const SECOND: Component = Component::Second(modifier::Second {
+    padding: Padding::Zero,
+});
+/// `%p`
+const PERIOD: Component = Component::Period(modifier::Period {
+    is_uppercase: true,
+    case_sensitive: true,
+});
+// endregion components
+
+/// Obtain the component for a conversion specifier that has a numerical value, applying the
+/// padding if provided.
+fn numerical_component(specifier: char, padding: Option<Padding>) -> Option<Component> {
+    let zero_padded = padding.unwrap_or(Padding::Zero);
+    let space_padded = padding.unwrap_or(Padding::Space);
+    Some(match specifier {
+        'd' => Component::Day(modifier::Day {
+            padding: zero_padded,
+            suffix: Suffix::None,
+        }),
+        'e' => Component::Day(modifier::Day {
+            padding: space_padded,
+            suffix: Suffix::None,
+        }),
+        'm' => Component::Month(modifier::Month {
+            padding: zero_padded,
+            repr: MonthRepr::Numerical,
+            case_sensitive: true,
+        }),
+        'j' => Component::Ordinal(modifier::Ordinal {
+            padding: zero_padded,
+            suffix: Suffix::None,
+        }),
+        'U' | 'W' | 'V' => Component::WeekNumber(modifier::WeekNumber {
+            padding: zero_padded,
+            repr: match specifier {
+                'U' => WeekNumberRepr::Sunday,
+                'W' => WeekNumberRepr::Monday,
+                _ => WeekNumberRepr::Iso,
+            },
+            suffix: Suffix::None,
+        }),
+        'C' => Component::Century(modifier::Century {
+            padding: zero_padded,
+            suffix: Suffix::None,
+        }),
+        'Y' | 'y' | 'G' | 'g' => Component::Year(modifier::Year {
+            padding: zero_padded,
+            repr: if specifier == 'Y' || specifier == 'G' {
+                YearRepr::Full
+            } else {
+                YearRepr::LastTwo
+            },
+            iso_week_based: specifier == 'G' || specifier == 'g',
+            sign_is_mandatory: false,
+            digits: 4,
+        }),
+        'H' | 'I' => Component::Hour(modifier::Hour {
+            padding: zero_padded,
+            is_12_hour_clock: specifier == 'I',
+        }),
+        'k' | 'l' => Component::Hour(modifier::Hour {
+            padding: space_padded,
+            is_12_hour_clock: specifier == 'l',
+        }),
+        'M' => Component::Minute(modifier::Minute {
+            padding: zero_padded,
+        }),
+        'S' => Component::Second(modifier::Second {
+            padding: zero_padded,
+        }),
+        _ => return None,
+    })
+}
+
+/// Obtain the items for a conversion specifier that does not have a numerical value. Composite
+/// specifiers are expanded to their equivalent in the POSIX locale.
+const fn fixed_items(specifier: char) -> Option<&'static [FormatItem<'static>]> {
+    use FormatItem::{Component as C, Literal as L};
+
+    Some(match specifier {
+        'a' => &[C(WEEKDAY_SHORT)],
+        'A' => &[C(Component::Weekday(modifier::Weekday {
+            repr: WeekdayRepr::Long,
+            one_indexed: true,
+            case_sensitive: true,
+        }))],
+        'b' | 'h' => &[C(MONTH_SHORT)],
+        'B' => &[C(Component::Month(modifier::Month {
+            padding: Padding::Zero,
+            repr: MonthRepr::Long,
+            case_sensitive: true,
+        }))],
+        'u' => &[C(Component::Weekday(modifier::Weekday {
+            repr: WeekdayRepr::Monday,
+            one_indexed: true,
+            case_sensitive: true,
+        }))],
+        'w' => &[C(Component::Weekday(modifier::Weekday {
+            repr: WeekdayRepr::Sunday,
+            one_indexed: false,
+            case_sensitive: true,
+        }))],
+        's' => &[C(Component::UnixTimestamp(modifier::UnixTimestamp {
+            precision: UnixTimestampPrecision::Second,
+            sign_is_mandatory: false,
+        }))],
+        'p' => &[C(PERIOD)],
+        'P' => &[C(Component::Period(modifier::Period {
+            is_uppercase: false,
+            case_sensitive: true,
+        }))],
+        'z' => &[
+            C(Component::OffsetHour(modifier::OffsetHour {
+                padding: Padding::Zero,
+                sign_is_mandatory: true,
+            })),
+            C(Component::OffsetMinute(modifier::OffsetMinute {
+                padding: Padding::Zero,
+            })),
+        ],
+        'Z' => &[C(Component::OffsetAbbreviation(
+            modifier::OffsetAbbreviation {
+                case_sensitive: true,
+            },
+        ))],
+        'c' => &[
+            C(WEEKDAY_SHORT),
+            L(b" "),
+            C(MONTH_SHORT),
+            L(b" "),
+            C(DAY_SPACE_PADDED),
+            L(b" "),
+            C(HOUR),
+            L(b":"),
+            C(MINUTE),
+            L(b":"),
+            C(SECOND),
+            L(b" "),
+            C(YEAR),
+        ],
+        'D' | 'x' => &[C(MONTH), L(b"/"), C(DAY), L(b"/"), C(YEAR_LAST_TWO)],
+        'F' => &[C(YEAR), L(b"-"), C(MONTH), L(b"-"), C(DAY)],
+        'R' => &[C(HOUR), L(b":"), C(MINUTE)],
+        'r' => &[
+            C(HOUR_12),
+            L(b":"),
+            C(MINUTE),
+            L(b":"),
+            C(SECOND),
+            L(b" "),
+            C(PERIOD),
+        ],
+        'T' | 'X' => &[C(HOUR), L(b":"), C(MINUTE), L(b":"), C(SECOND)],
+        'n' => &[L(b"\n")],
+        't' => &[L(b"\t")],
+        '%' => &[L(b"%")],
+        _ => return None,
+    })
+}
+
+/// Obtain a description of a valid conversion specifier that has no equivalent in format
+/// descriptions.
+const fn unsupported(specifier: char) -> Option<&'static str> {
+    Some(match specifier {
+        '+' => "date and time in date(1) format",
+        _ => return None,
+    })
+}
+
+/// Parse a strftime format string into a sequence of items.
+///
+/// The items are equivalent to those returned by [`parse`](crate::format_description::parse())
+/// for the equivalent format description, but borrow from the input. The conversion specifiers defined by POSIX are
+/// supported, as are `%k`, `%l`, `%P`, and the `-`, `_` and `0` flags to change the padding of
+/// numerical values. Specifiers whose meaning depends on the locale (such as `%c`) are expanded as
+/// they would be in the POSIX locale, and the `E` and `O` modifiers are ignored.
+///
+/// ```rust
+/// # use time::format_description::{self, OwnedFormatItem};
+/// assert_eq!(
+///     OwnedFormatItem::from(format_description::parse_strftime("%Y-%m-%dT%H:%M:%S%z")?),
+///     format_description::parse_owned(
+///         "[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour \
+///          sign:mandatory][offset_minute]"
+///     )?
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub fn parse_strftime(s: &str) -> Result<Vec<FormatItem<'_>>, InvalidFormatDescription> {
+    let mut items = Vec::new();
+    let mut index = 0;
+
+    while index < s.len() {
+        let literal_len = s[index..].find('%').unwrap_or(s.len() - index);
+        if literal_len != 0 {
+            items.push(FormatItem::Literal(
+                &s.as_bytes()[index..index + literal_len],
+            ));
+            index += literal_len;
+            continue;
+        }
+
+        let opening_index = index;
+        index += 1;
+        let padding = match s.as_bytes().get(index) {
+            Some(b'-') => Some(Padding::None),
+            Some(b'_') => Some(Padding::Space),
+            Some(b'0') => Some(Padding::Zero),
+            _ => None,
+        };
+        if padding.is_some() {
+            index += 1;
+        }
+        if let Some(b'E') | Some(b'O') = s.as_bytes().get(index) {
+            index += 1;
+        }
+        let specifier = s[index..]
+            .chars()
+            .next()
+            .ok_or(InvalidFormatDescription::Expected {
+                what: "conversion specifier",
+                index,
+            })?;
+        index += specifier.len_utf8();
+
+        if let Some(component) = numerical_component(specifier, padding) {
+            items.push(FormatItem::Component(component));
+        } else if let Some(fixed) = fixed_items(specifier) {
+            if padding.is_some() {
+                return Err(InvalidFormatDescription::NotSupported {
+                    what: "padding flag",
+                    context: "conversion specifiers without a numerical value",
+                    index: opening_index,
+                });
+            }
+            items.extend_from_slice(fixed);
+        } else if let Some(what) = unsupported(specifier) {
+            return Err(InvalidFormatDescription::NotSupported {
+                what,
+                context: "format descriptions",
+                index: opening_index,
+            });
+        } else {
+            return Err(InvalidFormatDescription::InvalidComponentName {
+                name: s[opening_index..index].into(),
+                index: opening_index,
+            });
+        }
+    }
+
+    Ok(items)
+}
+
+/// Convert a sequence of items into an equivalent strftime format string.
+///
+/// Items are converted to the conversion specifiers defined by POSIX where possible. Components
+/// that are padded with spaces or not padded at all use the `_` and `-` flags, and `%k`, `%l` and
+/// `%P` are used where appropriate. Components without an equivalent conversion specifier, such as
+/// `[subsecond]`, and sections containing nested items result in an error.
+///
+/// ```rust
+/// # use time::{format_description, macros::format_description};
+/// assert_eq!(
+///     format_description::to_strftime(&format_description!(
+///         "[weekday repr:short], [day padding:none] [month repr:short] [year] 100%"
+///     ))?,
+///     "%a, %-d %b %Y 100%%"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg(feature = "formatting")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
+pub fn to_strftime(items: &[FormatItem<'_>]) -> Result<String, error::Format> {
+    let mut output = String::new();
+    write_items(&mut output, items)?;
+    Ok(output)
+}
+
+/// Write the strftime equivalent of the items to the output.
+#[cfg(feature = "formatting")]
+fn write_items(output: &mut String, mut items: &[FormatItem<'_>]) -> Result<(), error::Format> {
+    while let Some((item, remaining)) = items.split_first() {
+        items = remaining;
+        match *item {
+            FormatItem::Literal(literal) => {
+                output.push_str(&String::from_utf8_lossy(literal).replace('%', "%%"));
+            }
+            FormatItem::Component(Component::OffsetHour(modifier::OffsetHour {
+                padding: Padding::Zero,
+                sign_is_mandatory: true,
+            })) => match items {
+                [FormatItem::Component(Component::OffsetMinute(modifier::OffsetMinute {
+                    padding: Padding::Zero,
+                })), remaining @ ..] => {
+                    output.push_str("%z");
+                    items = remaining;
+                }
+                _ => return Err(error::Format::InvalidComponent("offset_hour")),
+            },
+            FormatItem::Component(component) => write_component(output, component)?,
+            FormatItem::Compound(items) => write_items(output, items)?,
+            FormatItem::Optional(_) => return Err(error::Format::InvalidComponent("optional")),
+            FormatItem::First(_) => return Err(error::Format::InvalidComponent("first")),
+        }
+    }
+    Ok(())
+}
+
+/// Write the conversion specifier equivalent to the component to the output.
+#[cfg(feature = "formatting")]
+#[allow(clippy::too_many_lines)]
+fn write_component(output: &mut String, component: Component) -> Result<(), error::Format> {
+    use Component::*;
+
+    let (specifier, padding) = match component {
+        Day(modifier::Day {
+            padding: Padding::Space,
+            suffix: Suffix::None,
+        }) => ('e', None),
+        Day(modifier::Day {
+            padding,
+            suffix: Suffix::None,
+        }) => ('d', Some(padding)),
+        Month(modifier::Month {
+            padding,
+            repr: MonthRepr::Numerical,
+            ..
+        }) => ('m', Some(padding)),
+        Month(modifier::Month {
+            repr: MonthRepr::Long,
+            ..
+        }) => ('B', None),
+        Month(modifier::Month {
+            repr: MonthRepr::Short,
+            ..
+        }) => ('b', None),
+        Ordinal(modifier::Ordinal {
+            padding,
+            suffix: Suffix::None,
+        }) => ('j', Some(padding)),
+        Weekday(modifier::Weekday {
+            repr: WeekdayRepr::Short,
+            ..
+        }) => ('a', None),
+        Weekday(modifier::Weekday {
+            repr: WeekdayRepr::Long,
+            ..
+        }) => ('A', None),
+        Weekday(modifier::Weekday {
+            repr: WeekdayRepr::Sunday,
+            one_indexed: false,
+            ..
+        }) => ('w', None),
+        Weekday(modifier::Weekday {
+            repr: WeekdayRepr::Monday,
+            one_indexed: true,
+            ..
+        }) => ('u', None),
+        WeekNumber(modifier::WeekNumber {
+            padding,
+            repr,
+            suffix: Suffix::None,
+        }) => (
+            match repr {
+                WeekNumberRepr::Iso => 'V',
+                WeekNumberRepr::Sunday => 'U',
+                WeekNumberRepr::Monday => 'W',
+            },
+            Some(padding),
+        ),
+        Year(modifier::Year {
+            padding,
+            repr,
+            iso_week_based,
+            sign_is_mandatory: false,
+            digits: 4,
+        }) => (
+            match (repr, iso_week_based) {
+                (YearRepr::Full, false) => 'Y',
+                (YearRepr::LastTwo, false) => 'y',
+                (YearRepr::Full, true) => 'G',
+                (YearRepr::LastTwo, true) => 'g',
+                (YearRepr::Historical, _) => {
+                    return Err(error::Format::InvalidComponent("year"));
+                }
+            },
+            Some(padding),
+        ),
+        Century(modifier::Century {
+            padding,
+            suffix: Suffix::None,
+        }) => ('C', Some(padding)),
+        Hour(modifier::Hour {
+            padding: Padding::Space,
+            is_12_hour_clock,
+        }) => (if is_12_hour_clock { 'l' } else { 'k' }, None),
+        Hour(modifier::Hour {
+            padding,
+            is_12_hour_clock,
+        }) => (if is_12_hour_clock { 'I' } else { 'H' }, Some(padding)),
+        Minute(modifier::Minute { padding }) => ('M', Some(padding)),
+        Period(modifier::Period { is_uppercase, .. }) => {
+            (if is_uppercase { 'p' } else { 'P' }, None)
+        }
+        Second(modifier::Second { padding }) => ('S', Some(padding)),
+        UnixTimestamp(modifier::UnixTimestamp {
+            precision: UnixTimestampPrecision::Second,
+            sign_is_mandatory: false,
+        }) => ('s', None),
+        OffsetAbbreviation(modifier::OffsetAbbreviation {
+            case_sensitive: true,
+        }) => ('Z', None),
+        Day(_) => return Err(error::Format::InvalidComponent("day")),
+        Month(_) => return Err(error::Format::InvalidComponent("month")),
+        Ordinal(_) => return Err(error::Format::InvalidComponent("ordinal")),
+        Weekday(_) => return Err(error::Format::InvalidComponent("weekday")),
+        WeekNumber(_) => return Err(error::Format::InvalidComponent("week_number")),
+        Year(_) => return Err(error::Format::InvalidComponent("year")),
+        Quarter(_) => return Err(error::Format::InvalidComponent("quarter")),
+        WeekOfMonth(_) => return Err(error::Format::InvalidComponent("week_of_month")),
+        WeekOfQuarter(_) => return Err(error::Format::InvalidComponent("week_of_quarter")),
+        WeekdayInMonth(_) => return Err(error::Format::InvalidComponent("weekday_in_month")),
+        Century(_) => return Err(error::Format::InvalidComponent("century")),
+        Era(_) => return Err(error::Format::InvalidComponent("era")),
+        Subsecond(_) => return Err(error::Format::InvalidComponent("subsecond")),
+        OffsetHour(_) => return Err(error::Format::InvalidComponent("offset_hour")),
+        OffsetMinute(_) => return Err(error::Format::InvalidComponent("offset_minute")),
+        OffsetSecond(_) => return Err(error::Format::InvalidComponent("offset_second")),
+        OffsetAbbreviation(_) => {
+            return Err(error::Format::InvalidComponent("offset_abbreviation"));
+        }
+        Offset(_) => return Err(error::Format::InvalidComponent("offset")),
+        DurationDay(_) => return Err(error::Format::InvalidComponent("duration_day")),
+        DurationHour(_) => return Err(error::Format::InvalidComponent("duration_hour")),
+        DurationMinute(_) => return Err(error::Format::InvalidComponent("duration_minute")),
+        DurationSecond(_) => return Err(error::Format::InvalidComponent("duration_second")),
+        DurationSubsecond(_) => {
+            return Err(error::Format::InvalidComponent("duration_subsecond"));
+        }
+        DurationSign(_) => return Err(error::Format::InvalidComponent("duration_sign")),
+        UnixTimestamp(_) => return Err(error::Format::InvalidComponent("unix_timestamp")),
+        Ignore(_) => return Err(error::Format::InvalidComponent("ignore")),
+        // Nothing is formatted, so there is nothing to convert.
+        End(_) => return Ok(()),
+    };
+
+    output.push('%');
+    match padding {
+        Some(Padding::Space) => output.push('_'),
+        Some(Padding::None) => output.push('-'),
+        Some(Padding::Zero) | None => {}
+    }
+    output.push(specifier);
+    Ok(())
+}
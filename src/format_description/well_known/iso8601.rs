@@ -0,0 +1,206 @@
+//! Configuration for the [`Iso8601`](super::Iso8601) format.
+
+/// Which components of a value are formatted.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormattedComponents {
+    /// The date only.
+    Date,
+    /// The time only.
+    Time,
+    /// The UTC offset only.
+    Offset,
+    /// The date and time.
+    DateTime,
+    /// The time and UTC offset.
+    TimeOffset,
+    /// The date, time, and UTC offset.
+    DateTimeOffset,
+}
+
+#[cfg(feature = "formatting")]
+impl FormattedComponents {
+    /// Whether the date is formatted.
+    pub(crate) const fn has_date(self) -> bool {
+        matches!(self, Self::Date | Self::DateTime | Self::DateTimeOffset)
+    }
+
+    /// Whether the time is formatted.
+    pub(crate) const fn has_time(self) -> bool {
+        matches!(
+            self,
+            Self::Time | Self::DateTime | Self::TimeOffset | Self::DateTimeOffset
+        )
+    }
+
+    /// Whether the UTC offset is formatted.
+    pub(crate) const fn has_offset(self) -> bool {
+        matches!(self, Self::Offset | Self::TimeOffset | Self::DateTimeOffset)
+    }
+}
+
+/// The kind of date that is formatted.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateKind {
+    /// A calendar date, such as `2021-03-15`.
+    Calendar,
+    /// An ISO week date, such as `2021-W11-1`.
+    Week,
+    /// An ordinal date, such as `2021-074`.
+    Ordinal,
+}
+
+/// The smallest unit of the time that is formatted, along with the number of digits after the
+/// decimal point of that unit.
+///
+/// A `decimal_digits` value of zero omits the fractional part entirely. At most nine digits are
+/// formatted; any larger value is treated as nine.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimePrecision {
+    /// Format the hour only, such as `T10` or `T10.25`.
+    #[allow(missing_docs)]
+    Hour { decimal_digits: u8 },
+    /// Format the hour and minute, such as `T10:15` or `T10:15.5`.
+    #[allow(missing_docs)]
+    Minute { decimal_digits: u8 },
+    /// Format the hour, minute, and second, such as `T10:15:30` or `T10:15:30.123`.
+    #[allow(missing_docs)]
+    Second { decimal_digits: u8 },
+}
+
+/// The smallest unit of the UTC offset that is formatted.
+///
+/// A UTC offset of zero is always formatted as `Z`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetPrecision {
+    /// Format the offset hour only, such as `+01`. Offsets with a non-zero minute cannot be
+    /// formatted with this precision.
+    Hour,
+    /// Format the offset hour and minute, such as `+01:30`.
+    Minute,
+}
+
+/// Configuration for formatting values as ISO 8601.
+///
+/// The configuration is built using `const fn` methods, allowing it to be used in a `const`
+/// context. It has no effect on parsing.
+///
+/// ```rust
+/// # use time::format_description::well_known::{iso8601, Iso8601};
+/// # use time::macros::datetime;
+/// const FORMAT: Iso8601 = Iso8601::with_config(
+///     iso8601::Config::DEFAULT
+///         .set_date_kind(iso8601::DateKind::Week)
+///         .set_use_separators(false),
+/// );
+/// assert_eq!(
+///     datetime!("2021-03-15 10:15 UTC").format(&FORMAT)?,
+///     "2021W111T101500.000000000Z"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Which components are formatted.
+    pub(crate) formatted_components: FormattedComponents,
+    /// Whether the extended format (with separators) is used, rather than the basic format.
+    pub(crate) use_separators: bool,
+    /// The kind of date that is formatted.
+    pub(crate) date_kind: DateKind,
+    /// The precision of the time.
+    pub(crate) time_precision: TimePrecision,
+    /// The precision of the UTC offset.
+    pub(crate) offset_precision: OffsetPrecision,
+    /// The number of digits in the year, between four and six. More than four uses the expanded
+    /// representation.
+    pub(crate) year_digits: u8,
+}
+
+impl Config {
+    /// The default configuration: an extended calendar date, a time with nanosecond precision, and
+    /// a UTC offset with minute precision, such as `2021-03-15T10:15:30.000000000+01:00`.
+    pub const DEFAULT: Self = Self {
+        formatted_components: FormattedComponents::DateTimeOffset,
+        use_separators: true,
+        date_kind: DateKind::Calendar,
+        time_precision: TimePrecision::Second { decimal_digits: 9 },
+        offset_precision: OffsetPrecision::Minute,
+        year_digits: 4,
+    };
+
+    /// Set which components are formatted.
+    pub const fn set_formatted_components(self, formatted_components: FormattedComponents) -> Self {
+        Self {
+            formatted_components,
+            ..self
+        }
+    }
+
+    /// Set whether the extended format (with separators) is used. If `false`, the basic format
+    /// is used.
+    pub const fn set_use_separators(self, use_separators: bool) -> Self {
+        Self {
+            use_separators,
+            ..self
+        }
+    }
+
+    /// Set the kind of date that is formatted.
+    pub const fn set_date_kind(self, date_kind: DateKind) -> Self {
+        Self { date_kind, ..self }
+    }
+
+    /// Set the precision of the time.
+    pub const fn set_time_precision(self, time_precision: TimePrecision) -> Self {
+        Self {
+            time_precision,
+            ..self
+        }
+    }
+
+    /// Set the precision of the UTC offset.
+    pub const fn set_offset_precision(self, offset_precision: OffsetPrecision) -> Self {
+        Self {
+            offset_precision,
+            ..self
+        }
+    }
+
+    /// Set the number of digits in the year, between four and six.
+    ///
+    /// As with the `decimal_digits` of [`TimePrecision`], values outside this range are not an
+    /// error: any smaller value is treated as four, and any larger value as six.
+    ///
+    /// With more than four digits, the expanded representation is used: the year always has a
+    /// sign, such as `+002021`. Years that do not fit in the given number of digits cannot be
+    /// formatted.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::iso8601;
+    /// assert_eq!(
+    ///     iso8601::Config::DEFAULT.set_year_digits(10),
+    ///     iso8601::Config::DEFAULT.set_year_digits(6)
+    /// );
+    /// ```
+    pub const fn set_year_digits(self, year_digits: u8) -> Self {
+        Self {
+            year_digits: if year_digits < 4 {
+                4
+            } else if year_digits > 6 {
+                6
+            } else {
+                year_digits
+            },
+            ..self
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -1,12 +1,21 @@
 //! A trait that can be used to format an item from its components.
 
-use std::io;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::slice;
 
-use crate::format_description::modifier::Padding;
-use crate::format_description::well_known::Rfc3339;
-use crate::format_description::FormatItem;
-use crate::formatting::{format_component, format_number};
-use crate::{error, Date, Time, UtcOffset};
+use crate::format_description::locale::{English, Locale};
+use crate::format_description::modifier::{self, Padding};
+use crate::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+#[cfg(feature = "alloc")]
+use crate::format_description::OwnedFormatItem;
+use crate::format_description::{Component, FormatItem};
+use crate::formatting::{
+    self, format_component, format_duration_component, format_number, iso8601, write, Output,
+};
+use crate::{error, Date, Duration, Time, UtcOffset};
 
 /// Seal the trait to prevent downstream users from implementing it, while still allowing it to
 /// exist in generic bounds.
@@ -18,27 +27,69 @@ pub(crate) mod sealed {
     #[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
     pub trait Formattable {
         /// An error that may be returned when formatting.
-        type Error: From<io::Error>;
+        type Error;
 
         /// Format the item into the provided output, returning the number of bytes written.
         fn format_into(
             &self,
-            output: &mut impl io::Write,
+            output: &mut impl Output,
+            date: Option<Date>,
+            time: Option<Time>,
+            offset: Option<UtcOffset>,
+        ) -> Result<usize, Self::Error> {
+            self.format_into_with_locale(output, date, time, offset, &English)
+        }
+
+        /// Format the item into the provided output using the names provided by the locale,
+        /// returning the number of bytes written.
+        ///
+        /// Items that do not contain any localized components ignore the locale.
+        fn format_into_with_locale(
+            &self,
+            output: &mut impl Output,
             date: Option<Date>,
             time: Option<Time>,
             offset: Option<UtcOffset>,
+            locale: &dyn Locale,
         ) -> Result<usize, Self::Error>;
 
         /// Format the item directly to a `String`.
+        #[cfg(feature = "alloc")]
         fn format(
             &self,
             date: Option<Date>,
             time: Option<Time>,
             offset: Option<UtcOffset>,
         ) -> Result<String, Self::Error> {
+            self.format_with_locale(date, time, offset, &English)
+        }
+
+        /// Format the item directly to a `String` using the names provided by the locale.
+        #[cfg(feature = "alloc")]
+        fn format_with_locale(
+            &self,
+            date: Option<Date>,
+            time: Option<Time>,
+            offset: Option<UtcOffset>,
+            locale: &dyn Locale,
+        ) -> Result<String, Self::Error> {
+            let mut buf = Vec::new();
+            self.format_into_with_locale(&mut buf, date, time, offset, locale)?;
+            Ok(String::from_utf8_lossy(&buf).into_owned())
+        }
+
+        /// Format a duration into the provided output, returning the number of bytes written.
+        fn format_duration_into(
+            &self,
+            output: &mut impl Output,
+            duration: Duration,
+        ) -> Result<usize, Self::Error>;
+
+        /// Format a duration directly to a `String`.
+        #[cfg(feature = "alloc")]
+        fn format_duration(&self, duration: Duration) -> Result<String, Self::Error> {
             let mut buf = Vec::new();
-            self.format_into(&mut buf, date, time, offset)?;
-            io::Write::flush(&mut buf)?;
+            self.format_duration_into(&mut buf, duration)?;
             Ok(String::from_utf8_lossy(&buf).into_owned())
         }
     }
@@ -48,17 +99,32 @@ pub(crate) mod sealed {
 impl<'a> sealed::Formattable for FormatItem<'a> {
     type Error = error::Format;
 
-    fn format_into(
+    fn format_into_with_locale(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        locale: &dyn Locale,
+    ) -> Result<usize, Self::Error> {
+        let (date, time) = round_subsecond(date, time, subsecond_rounding(slice::from_ref(self)))?;
+        format_item(output, self, date, time, offset, locale)
+    }
+
+    fn format_duration_into(
+        &self,
+        output: &mut impl Output,
+        duration: Duration,
     ) -> Result<usize, Self::Error> {
         Ok(match *self {
-            Self::Literal(literal) => output.write(literal)?,
-            Self::Component(component) => format_component(output, component, date, time, offset)?,
-            Self::Compound(items) => items.format_into(output, date, time, offset)?,
+            Self::Literal(literal) => write(output, literal)?,
+            Self::Component(component) => format_duration_component(output, component, duration)?,
+            Self::Compound(items) => items.format_duration_into(output, duration)?,
+            Self::Optional(item) => item.format_duration_into(output, duration)?,
+            Self::First(items) => match items {
+                [] => 0,
+                [item, ..] => item.format_duration_into(output, duration)?,
+            },
         })
     }
 }
@@ -66,16 +132,26 @@ impl<'a> sealed::Formattable for FormatItem<'a> {
 impl<'a> sealed::Formattable for &[FormatItem<'a>] {
     type Error = error::Format;
 
-    fn format_into(
+    fn format_into_with_locale(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        locale: &dyn Locale,
+    ) -> Result<usize, Self::Error> {
+        let (date, time) = round_subsecond(date, time, subsecond_rounding(self))?;
+        format_items(output, self, date, time, offset, locale)
+    }
+
+    fn format_duration_into(
+        &self,
+        output: &mut impl Output,
+        duration: Duration,
     ) -> Result<usize, Self::Error> {
         let mut bytes = 0;
         for item in self.iter() {
-            bytes += item.format_into(output, date, time, offset)?;
+            bytes += item.format_duration_into(output, duration)?;
         }
         Ok(bytes)
     }
@@ -86,28 +162,318 @@ impl<'a> sealed::Formattable for &[FormatItem<'a>] {
 impl<'a> sealed::Formattable for Vec<FormatItem<'a>> {
     type Error = <&'a [FormatItem<'a>] as sealed::Formattable>::Error;
 
-    fn format_into(
+    fn format_into_with_locale(
+        &self,
+        output: &mut impl Output,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        locale: &dyn Locale,
+    ) -> Result<usize, Self::Error> {
+        self.as_slice()
+            .format_into_with_locale(output, date, time, offset, locale)
+    }
+
+    fn format_duration_into(
+        &self,
+        output: &mut impl Output,
+        duration: Duration,
+    ) -> Result<usize, Self::Error> {
+        self.as_slice().format_duration_into(output, duration)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl sealed::Formattable for OwnedFormatItem {
+    type Error = error::Format;
+
+    fn format_into_with_locale(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        locale: &dyn Locale,
     ) -> Result<usize, Self::Error> {
-        self.as_slice().format_into(output, date, time, offset)
+        let (date, time) = round_subsecond(date, time, subsecond_rounding(slice::from_ref(self)))?;
+        format_item(output, self, date, time, offset, locale)
+    }
+
+    fn format_duration_into(
+        &self,
+        output: &mut impl Output,
+        duration: Duration,
+    ) -> Result<usize, Self::Error> {
+        Ok(match self {
+            Self::Literal(literal) => write(output, literal)?,
+            Self::Component(component) => format_duration_component(output, *component, duration)?,
+            Self::Compound(items) => (&**items).format_duration_into(output, duration)?,
+            Self::Optional(item) => item.format_duration_into(output, duration)?,
+            Self::First(items) => match &**items {
+                [] => 0,
+                [item, ..] => item.format_duration_into(output, duration)?,
+            },
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl sealed::Formattable for &[OwnedFormatItem] {
+    type Error = error::Format;
+
+    fn format_into_with_locale(
+        &self,
+        output: &mut impl Output,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        locale: &dyn Locale,
+    ) -> Result<usize, Self::Error> {
+        let (date, time) = round_subsecond(date, time, subsecond_rounding(self))?;
+        format_items(output, self, date, time, offset, locale)
+    }
+
+    fn format_duration_into(
+        &self,
+        output: &mut impl Output,
+        duration: Duration,
+    ) -> Result<usize, Self::Error> {
+        let mut bytes = 0;
+        for item in self.iter() {
+            bytes += item.format_duration_into(output, duration)?;
+        }
+        Ok(bytes)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl sealed::Formattable for Vec<OwnedFormatItem> {
+    type Error = error::Format;
+
+    fn format_into_with_locale(
+        &self,
+        output: &mut impl Output,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        locale: &dyn Locale,
+    ) -> Result<usize, Self::Error> {
+        self.as_slice()
+            .format_into_with_locale(output, date, time, offset, locale)
+    }
+
+    fn format_duration_into(
+        &self,
+        output: &mut impl Output,
+        duration: Duration,
+    ) -> Result<usize, Self::Error> {
+        self.as_slice().format_duration_into(output, duration)
+    }
+}
+/// Round the time as requested by the first subsecond component that is formatted, if any.
+fn round_subsecond(
+    date: Option<Date>,
+    time: Option<Time>,
+    modifier: Option<modifier::Subsecond>,
+) -> Result<(Option<Date>, Option<Time>), error::Format> {
+    match modifier {
+        Some(modifier) => formatting::round_subsecond(date, time, modifier),
+        None => Ok((date, time)),
+    }
+}
+
+/// A borrowed view of a [`FormatItem`] or `OwnedFormatItem`, allowing the rounding and formatting
+/// logic to be shared between the two.
+enum ItemRef<'a, T> {
+    /// Bytes that are formatted as-is.
+    Literal(&'a [u8]),
+    /// A single non-literal item.
+    Component(Component),
+    /// A series of items.
+    Compound(&'a [T]),
+    /// An item that is always formatted.
+    Optional(&'a T),
+    /// A series of items, of which only the first is formatted.
+    First(&'a [T]),
+}
+
+/// A format item that may contain nested items of the same type.
+trait AsItemRef: Sized {
+    /// Obtain a borrowed view of the item.
+    fn as_item_ref(&self) -> ItemRef<'_, Self>;
+}
+
+impl AsItemRef for FormatItem<'_> {
+    fn as_item_ref(&self) -> ItemRef<'_, Self> {
+        match *self {
+            Self::Literal(literal) => ItemRef::Literal(literal),
+            Self::Component(component) => ItemRef::Component(component),
+            Self::Compound(items) => ItemRef::Compound(items),
+            Self::Optional(item) => ItemRef::Optional(item),
+            Self::First(items) => ItemRef::First(items),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsItemRef for OwnedFormatItem {
+    fn as_item_ref(&self) -> ItemRef<'_, Self> {
+        match self {
+            Self::Literal(literal) => ItemRef::Literal(literal),
+            Self::Component(component) => ItemRef::Component(*component),
+            Self::Compound(items) => ItemRef::Compound(items),
+            Self::Optional(item) => ItemRef::Optional(item),
+            Self::First(items) => ItemRef::First(items),
+        }
+    }
+}
+
+/// The first subsecond component that is formatted and rounds its value, if any. Only the first
+/// item of a [`FormatItem::First`] is considered, as it is the only one formatted.
+fn subsecond_rounding(items: &[impl AsItemRef]) -> Option<modifier::Subsecond> {
+    items.iter().find_map(|item| match item.as_item_ref() {
+        ItemRef::Component(Component::Subsecond(modifier))
+            if modifier.rounding != modifier::SubsecondRounding::Truncate =>
+        {
+            Some(modifier)
+        }
+        ItemRef::Compound(items) => subsecond_rounding(items),
+        ItemRef::Optional(item) => subsecond_rounding(slice::from_ref(item)),
+        ItemRef::First(items) => subsecond_rounding(&items[..items.len().min(1)]),
+        _ => None,
+    })
+}
+
+/// Format the item, where any rounding of the subsecond has already been performed.
+fn format_item(
+    output: &mut impl Output,
+    item: &impl AsItemRef,
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+    locale: &dyn Locale,
+) -> Result<usize, error::Format> {
+    Ok(match item.as_item_ref() {
+        ItemRef::Literal(literal) => write(output, literal)?,
+        ItemRef::Component(component) => {
+            format_component(output, component, date, time, offset, locale)?
+        }
+        ItemRef::Compound(items) => format_items(output, items, date, time, offset, locale)?,
+        ItemRef::Optional(item) => format_item(output, item, date, time, offset, locale)?,
+        ItemRef::First(items) => match items {
+            [] => 0,
+            [item, ..] => format_item(output, item, date, time, offset, locale)?,
+        },
+    })
+}
+
+/// Format the items, where any rounding of the subsecond has already been performed.
+fn format_items(
+    output: &mut impl Output,
+    items: &[impl AsItemRef],
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+    locale: &dyn Locale,
+) -> Result<usize, error::Format> {
+    let mut bytes = 0;
+    for item in items {
+        bytes += format_item(output, item, date, time, offset, locale)?;
     }
+    Ok(bytes)
 }
 // endregion custom formats
 
 // region: well-known formats
+impl sealed::Formattable for Rfc2822 {
+    type Error = error::Format;
+
+    fn format_into_with_locale(
+        &self,
+        output: &mut impl Output,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        _locale: &dyn Locale,
+    ) -> Result<usize, Self::Error> {
+        let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+        let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+        let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+
+        let mut bytes = 0;
+
+        let (year, month, day) = date.to_calendar_date();
+
+        if !(1900..10_000).contains(&year) {
+            return Err(error::Format::InvalidComponent("year"));
+        }
+        if offset.seconds_past_minute() != 0 {
+            return Err(error::Format::InvalidComponent("offset_second"));
+        }
+
+        // The names are always in English, regardless of locale.
+        bytes += write(
+            output,
+            English.short_weekday_names()[date.weekday().number_days_from_monday() as usize]
+                .as_bytes(),
+        )?;
+        bytes += write(output, b", ")?;
+        bytes += format_number(output, day, Padding::Zero, 2)?;
+        bytes += write(output, &[b' '])?;
+        bytes += write(
+            output,
+            English.short_month_names()[month as usize - 1].as_bytes(),
+        )?;
+        bytes += write(output, &[b' '])?;
+        bytes += format_number(output, year as u32, Padding::Zero, 4)?;
+        bytes += write(output, &[b' '])?;
+        bytes += format_number(output, time.hour(), Padding::Zero, 2)?;
+        bytes += write(output, &[b':'])?;
+        bytes += format_number(output, time.minute(), Padding::Zero, 2)?;
+        bytes += write(output, &[b':'])?;
+        bytes += format_number(output, time.second(), Padding::Zero, 2)?;
+        bytes += write(output, &[b' '])?;
+        bytes += write(
+            output,
+            if offset.is_negative() {
+                &[b'-']
+            } else {
+                &[b'+']
+            },
+        )?;
+        bytes += format_number(output, offset.whole_hours().abs() as u8, Padding::Zero, 2)?;
+        bytes += format_number(
+            output,
+            offset.minutes_past_hour().abs() as u8,
+            Padding::Zero,
+            2,
+        )?;
+
+        Ok(bytes)
+    }
+
+    fn format_duration_into(
+        &self,
+        _output: &mut impl Output,
+        _duration: Duration,
+    ) -> Result<usize, Self::Error> {
+        Err(error::Format::InsufficientTypeInformation)
+    }
+}
+
 impl sealed::Formattable for Rfc3339 {
     type Error = error::Format;
 
-    fn format_into(
+    fn format_into_with_locale(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        _locale: &dyn Locale,
     ) -> Result<usize, Self::Error> {
         let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
         let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
@@ -125,19 +491,19 @@ impl sealed::Formattable for Rfc3339 {
         }
 
         bytes += format_number(output, year as u32, Padding::Zero, 4)?;
-        bytes += output.write(&[b'-'])?;
+        bytes += write(output, &[b'-'])?;
         bytes += format_number(output, date.month(), Padding::Zero, 2)?;
-        bytes += output.write(&[b'-'])?;
+        bytes += write(output, &[b'-'])?;
         bytes += format_number(output, date.day(), Padding::Zero, 2)?;
-        bytes += output.write(&[b'T'])?;
+        bytes += write(output, &[b'T'])?;
         bytes += format_number(output, time.hour(), Padding::Zero, 2)?;
-        bytes += output.write(&[b':'])?;
+        bytes += write(output, &[b':'])?;
         bytes += format_number(output, time.minute(), Padding::Zero, 2)?;
-        bytes += output.write(&[b':'])?;
+        bytes += write(output, &[b':'])?;
         bytes += format_number(output, time.second(), Padding::Zero, 2)?;
 
         if time.nanosecond() != 0 {
-            bytes += output.write(&[b'.'])?;
+            bytes += write(output, &[b'.'])?;
 
             let (value, width) = match time.nanosecond() {
                 nanos if nanos % 10 != 0 => (nanos, 9),
@@ -154,17 +520,20 @@ impl sealed::Formattable for Rfc3339 {
         }
 
         if offset == UtcOffset::UTC {
-            bytes += output.write(&[b'Z'])?;
+            bytes += write(output, &[b'Z'])?;
             return Ok(bytes);
         }
 
-        bytes += output.write(if offset.is_negative() {
-            &[b'-']
-        } else {
-            &[b'+']
-        })?;
+        bytes += write(
+            output,
+            if offset.is_negative() {
+                &[b'-']
+            } else {
+                &[b'+']
+            },
+        )?;
         bytes += format_number(output, offset.whole_hours().abs() as u8, Padding::Zero, 2)?;
-        bytes += output.write(&[b':'])?;
+        bytes += write(output, &[b':'])?;
         bytes += format_number(
             output,
             offset.minutes_past_hour().abs() as u8,
@@ -174,5 +543,54 @@ impl sealed::Formattable for Rfc3339 {
 
         Ok(bytes)
     }
+
+    fn format_duration_into(
+        &self,
+        _output: &mut impl Output,
+        _duration: Duration,
+    ) -> Result<usize, Self::Error> {
+        Err(error::Format::InsufficientTypeInformation)
+    }
+}
+
+impl sealed::Formattable for Iso8601 {
+    type Error = error::Format;
+
+    fn format_into_with_locale(
+        &self,
+        output: &mut impl Output,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        _locale: &dyn Locale,
+    ) -> Result<usize, Self::Error> {
+        let config = self.config;
+        let components = config.formatted_components;
+
+        let mut bytes = 0;
+
+        if components.has_date() {
+            let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+            bytes += iso8601::format_date(output, date, config)?;
+        }
+        if components.has_time() {
+            let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+            bytes += iso8601::format_time(output, time, config)?;
+        }
+        if components.has_offset() {
+            let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+            bytes += iso8601::format_offset(output, offset, config)?;
+        }
+
+        Ok(bytes)
+    }
+
+    fn format_duration_into(
+        &self,
+        output: &mut impl Output,
+        duration: Duration,
+    ) -> Result<usize, Self::Error> {
+        iso8601::format_duration(output, duration)
+    }
 }
 // endregion well-known formats
@@ -0,0 +1,234 @@
+//! Helpers for implementing formatting for ISO 8601.
+
+use crate::format_description::modifier;
+use crate::format_description::modifier::Padding;
+use crate::format_description::well_known::iso8601::{
+    Config, DateKind, OffsetPrecision, TimePrecision,
+};
+use crate::formatting::{fmt_subsecond, format_number, write, Output};
+use crate::{error, Date, Duration, Time, UtcOffset};
+
+/// Format the date portion of ISO 8601.
+pub(crate) fn format_date(
+    output: &mut impl Output,
+    date: Date,
+    config: Config,
+) -> Result<usize, error::Format> {
+    let mut bytes = 0;
+
+    let year = match config.date_kind {
+        DateKind::Week => date.to_iso_week_date().0,
+        DateKind::Calendar | DateKind::Ordinal => date.year(),
+    };
+    if config.year_digits > 4 {
+        // The expanded representation always has a sign, and the number of digits is agreed upon
+        // in advance, so a year that does not fit cannot be represented.
+        if year.abs() >= 10_i32.pow(config.year_digits as u32) {
+            return Err(error::Format::InvalidComponent("year"));
+        }
+        bytes += write(output, if year < 0 { b"-" } else { b"+" })?;
+        bytes += format_number(output, year.abs() as u32, Padding::Zero, config.year_digits)?;
+    } else {
+        if !(0..10_000).contains(&year) {
+            return Err(error::Format::InvalidComponent("year"));
+        }
+        bytes += format_number(output, year as u32, Padding::Zero, 4)?;
+    }
+    if config.use_separators {
+        bytes += write(output, &[b'-'])?;
+    }
+
+    match config.date_kind {
+        DateKind::Calendar => {
+            bytes += format_number(output, date.month(), Padding::Zero, 2)?;
+            if config.use_separators {
+                bytes += write(output, &[b'-'])?;
+            }
+            bytes += format_number(output, date.day(), Padding::Zero, 2)?;
+        }
+        DateKind::Week => {
+            bytes += write(output, &[b'W'])?;
+            bytes += format_number(output, date.iso_week(), Padding::Zero, 2)?;
+            if config.use_separators {
+                bytes += write(output, &[b'-'])?;
+            }
+            bytes += format_number(
+                output,
+                date.weekday().number_from_monday(),
+                Padding::None,
+                1,
+            )?;
+        }
+        DateKind::Ordinal => {
+            bytes += format_number(output, date.ordinal(), Padding::Zero, 3)?;
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Format the fractional part of a unit, where `value / unit` is the fraction.
+fn format_fraction(
+    output: &mut impl Output,
+    value: u64,
+    unit: u64,
+    decimal_digits: u8,
+) -> Result<usize, error::Format> {
+    if decimal_digits == 0 {
+        return Ok(0);
+    }
+    let decimal_digits = decimal_digits.min(9);
+    let fraction = value as u128 * 10_u128.pow(decimal_digits as u32) / unit as u128;
+
+    let mut bytes = write(output, &[b'.'])?;
+    bytes += format_number(output, fraction as u32, Padding::Zero, decimal_digits)?;
+    Ok(bytes)
+}
+
+/// Format the time portion of ISO 8601, including the leading `T`.
+pub(crate) fn format_time(
+    output: &mut impl Output,
+    time: Time,
+    config: Config,
+) -> Result<usize, error::Format> {
+    /// Nanoseconds per second.
+    const SECOND: u64 = 1_000_000_000;
+    /// Nanoseconds per minute.
+    const MINUTE: u64 = 60 * SECOND;
+    /// Nanoseconds per hour.
+    const HOUR: u64 = 60 * MINUTE;
+
+    let (hour, minute, second, nanosecond) = time.as_hms_nano();
+    let nanos_past_minute = second as u64 * SECOND + nanosecond as u64;
+    let nanos_past_hour = minute as u64 * MINUTE + nanos_past_minute;
+
+    let mut bytes = write(output, &[b'T'])?;
+    bytes += format_number(output, hour, Padding::Zero, 2)?;
+
+    match config.time_precision {
+        TimePrecision::Hour { decimal_digits } => {
+            bytes += format_fraction(output, nanos_past_hour, HOUR, decimal_digits)?;
+        }
+        TimePrecision::Minute { decimal_digits } => {
+            if config.use_separators {
+                bytes += write(output, &[b':'])?;
+            }
+            bytes += format_number(output, minute, Padding::Zero, 2)?;
+            bytes += format_fraction(output, nanos_past_minute, MINUTE, decimal_digits)?;
+        }
+        TimePrecision::Second { decimal_digits } => {
+            if config.use_separators {
+                bytes += write(output, &[b':'])?;
+            }
+            bytes += format_number(output, minute, Padding::Zero, 2)?;
+            if config.use_separators {
+                bytes += write(output, &[b':'])?;
+            }
+            bytes += format_number(output, second, Padding::Zero, 2)?;
+            bytes += format_fraction(output, nanosecond as u64, SECOND, decimal_digits)?;
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Format the UTC offset portion of ISO 8601.
+pub(crate) fn format_offset(
+    output: &mut impl Output,
+    offset: UtcOffset,
+    config: Config,
+) -> Result<usize, error::Format> {
+    if offset.is_utc() {
+        return write(output, &[b'Z']);
+    }
+    if offset.seconds_past_minute() != 0 {
+        return Err(error::Format::InvalidComponent("offset_second"));
+    }
+
+    let mut bytes = write(
+        output,
+        if offset.is_negative() {
+            &[b'-']
+        } else {
+            &[b'+']
+        },
+    )?;
+    bytes += format_number(output, offset.whole_hours().abs() as u8, Padding::Zero, 2)?;
+
+    match config.offset_precision {
+        OffsetPrecision::Hour if offset.minutes_past_hour() != 0 => {
+            return Err(error::Format::InvalidComponent("offset_minute"));
+        }
+        OffsetPrecision::Hour => {}
+        OffsetPrecision::Minute => {
+            if config.use_separators {
+                bytes += write(output, &[b':'])?;
+            }
+            bytes += format_number(
+                output,
+                offset.minutes_past_hour().abs() as u8,
+                Padding::Zero,
+                2,
+            )?;
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Format a duration as ISO 8601, such as `P3DT4H` or `-PT1.5S`. Zero-valued units are omitted,
+/// and the fractional part of the second uses as few digits as possible.
+pub(crate) fn format_duration(
+    output: &mut impl Output,
+    duration: Duration,
+) -> Result<usize, error::Format> {
+    let seconds = duration.whole_seconds().wrapping_abs() as u64;
+    let nanoseconds = duration.subsec_nanoseconds().abs() as u32;
+    let (days, hours, minutes, seconds) = (
+        seconds / 86_400,
+        seconds / 3_600 % 24,
+        seconds / 60 % 60,
+        seconds % 60,
+    );
+
+    let mut bytes = 0;
+    if duration.is_negative() {
+        bytes += write(output, &[b'-'])?;
+    }
+    bytes += write(output, &[b'P'])?;
+    if days != 0 {
+        bytes += format_number(output, days, Padding::None, 0)?;
+        bytes += write(output, &[b'D'])?;
+    }
+    if days != 0 && hours == 0 && minutes == 0 && seconds == 0 && nanoseconds == 0 {
+        return Ok(bytes);
+    }
+
+    bytes += write(output, &[b'T'])?;
+    if hours != 0 {
+        bytes += format_number(output, hours, Padding::None, 0)?;
+        bytes += write(output, &[b'H'])?;
+    }
+    if minutes != 0 {
+        bytes += format_number(output, minutes, Padding::None, 0)?;
+        bytes += write(output, &[b'M'])?;
+    }
+    // A zero duration is written as `PT0S`, as at least one unit must be present.
+    if seconds != 0 || nanoseconds != 0 || duration.is_zero() {
+        bytes += format_number(output, seconds, Padding::None, 0)?;
+        if nanoseconds != 0 {
+            bytes += write(output, &[b'.'])?;
+            bytes += fmt_subsecond(
+                output,
+                nanoseconds,
+                modifier::Subsecond {
+                    digits: modifier::SubsecondDigits::OneOrMore,
+                    rounding: modifier::SubsecondRounding::Truncate,
+                },
+            )?;
+        }
+        bytes += write(output, &[b'S'])?;
+    }
+
+    Ok(bytes)
+}
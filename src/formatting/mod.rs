@@ -1,38 +1,17 @@
-//! Formatting for the time crate.
+//! Formatting for various types.
 
 pub(crate) mod formattable;
+mod iso8601;
+mod output;
+pub mod relative;
 
-use std::io;
+use core::fmt::{self, Write};
 
+pub use self::output::{FmtWrite, Output, SliceWriter};
+use crate::format_description::locale::Locale;
 use crate::format_description::{modifier, Component};
-use crate::{error, Date, Time, UtcOffset};
-
-#[allow(clippy::clippy::missing_docs_in_private_items)]
-const MONTH_NAMES: [&[u8]; 12] = [
-    b"January",
-    b"February",
-    b"March",
-    b"April",
-    b"May",
-    b"June",
-    b"July",
-    b"August",
-    b"September",
-    b"October",
-    b"November",
-    b"December",
-];
-
-#[allow(clippy::missing_docs_in_private_items)]
-const WEEKDAY_NAMES: [&[u8]; 7] = [
-    b"Monday",
-    b"Tuesday",
-    b"Wednesday",
-    b"Thursday",
-    b"Friday",
-    b"Saturday",
-    b"Sunday",
-];
+use crate::util::{days_in_year_month, DateAdjustment};
+use crate::{error, Date, Duration, PrimitiveDateTime, Time, UtcOffset};
 
 // region: extension trait
 /// A trait that indicates the formatted width of the value can be determined.
@@ -94,112 +73,278 @@ impl DigitCount for u32 {
         }
     }
 }
+impl DigitCount for u64 {
+    fn num_digits(self) -> u8 {
+        let mut digits = 1;
+        let mut value = self;
+        while value >= 10 {
+            value /= 10;
+            digits += 1;
+        }
+        digits
+    }
+}
 // endregion extension trait
 
+/// Write the bytes to the output, returning the number of bytes written.
+pub(crate) fn write(output: &mut impl Output, bytes: &[u8]) -> Result<usize, error::Format> {
+    output.write_bytes(bytes)?;
+    Ok(bytes.len())
+}
+
+/// Write the bytes to the formatter, respecting the requested width, fill, and alignment. Unlike
+/// [`fmt::Formatter::pad`], the precision is not used to truncate the value.
+pub(crate) fn pad(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    let s = core::str::from_utf8(bytes).map_err(|_| fmt::Error)?;
+    let padding = f
+        .width()
+        .map_or(0, |width| width.saturating_sub(s.chars().count()));
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, (padding + 1) / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Obtain the number of subsecond digits for the precision requested by a
+/// [`Formatter`](fmt::Formatter). Without a precision, as many digits as necessary are used. Any
+/// precision greater than nine is treated as nine.
+pub(crate) const fn subsecond_digits(precision: Option<usize>) -> modifier::SubsecondDigits {
+    match precision {
+        None | Some(0) => modifier::SubsecondDigits::OneOrMore,
+        Some(1) => modifier::SubsecondDigits::One,
+        Some(2) => modifier::SubsecondDigits::Two,
+        Some(3) => modifier::SubsecondDigits::Three,
+        Some(4) => modifier::SubsecondDigits::Four,
+        Some(5) => modifier::SubsecondDigits::Five,
+        Some(6) => modifier::SubsecondDigits::Six,
+        Some(7) => modifier::SubsecondDigits::Seven,
+        Some(8) => modifier::SubsecondDigits::Eight,
+        Some(_) => modifier::SubsecondDigits::Nine,
+    }
+}
+
 /// Format a number with the provided padding and width.
 ///
 /// The sign must be written by the caller.
 pub(crate) fn format_number(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     value: impl itoa::Integer + DigitCount + Copy,
     padding: modifier::Padding,
     width: u8,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     match padding {
         modifier::Padding::Space => {
             let mut bytes = 0;
             for _ in 0..(width.saturating_sub(value.num_digits())) {
-                bytes += output.write(&[b' '])?;
+                bytes += write(output, &[b' '])?;
             }
-            bytes += itoa::write(output, value)?;
+            bytes += write(output, itoa::Buffer::new().format(value).as_bytes())?;
             Ok(bytes)
         }
         modifier::Padding::Zero => {
             let mut bytes = 0;
             for _ in 0..(width.saturating_sub(value.num_digits())) {
-                bytes += output.write(&[b'0'])?;
+                bytes += write(output, &[b'0'])?;
             }
-            bytes += itoa::write(output, value)?;
+            bytes += write(output, itoa::Buffer::new().format(value).as_bytes())?;
             Ok(bytes)
         }
-        modifier::Padding::None => itoa::write(output, value),
+        modifier::Padding::None => write(output, itoa::Buffer::new().format(value).as_bytes()),
     }
 }
 
-/// Format the provided component into the designated output. An `Err` will be returned if the
-/// component requires information that it does not provide or if the value cannot be output to the
-/// stream.
+/// Format the provided component into the designated output, using the names provided by the
+/// locale. An `Err` will be returned if the component requires information that it does not
+/// provide or if the value cannot be output to the stream.
 pub(crate) fn format_component(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     component: Component,
     date: Option<Date>,
     time: Option<Time>,
     offset: Option<UtcOffset>,
+    locale: &dyn Locale,
 ) -> Result<usize, error::Format> {
     use Component::*;
     Ok(match (component, date, time, offset) {
-        (Day(modifier), Some(date), ..) => fmt_day(output, date, modifier)?,
-        (Month(modifier), Some(date), ..) => fmt_month(output, date, modifier)?,
-        (Ordinal(modifier), Some(date), ..) => fmt_ordinal(output, date, modifier)?,
-        (Weekday(modifier), Some(date), ..) => fmt_weekday(output, date, modifier)?,
-        (WeekNumber(modifier), Some(date), ..) => fmt_week_number(output, date, modifier)?,
+        (Day(modifier), Some(date), ..) => fmt_day(output, date, modifier, locale)?,
+        (Month(modifier), Some(date), ..) => fmt_month(output, date, modifier, locale)?,
+        (Ordinal(modifier), Some(date), ..) => fmt_ordinal(output, date, modifier, locale)?,
+        (Weekday(modifier), Some(date), ..) => fmt_weekday(output, date, modifier, locale)?,
+        (WeekNumber(modifier), Some(date), ..) => fmt_week_number(output, date, modifier, locale)?,
         (Year(modifier), Some(date), ..) => fmt_year(output, date, modifier)?,
+        (Quarter(modifier), Some(date), ..) => fmt_quarter(output, date, modifier, locale)?,
+        (WeekOfMonth(modifier), Some(date), ..) => {
+            fmt_week_of_month(output, date, modifier, locale)?
+        }
+        (WeekOfQuarter(modifier), Some(date), ..) => {
+            fmt_week_of_quarter(output, date, modifier, locale)?
+        }
+        (WeekdayInMonth(modifier), Some(date), ..) => {
+            fmt_weekday_in_month(output, date, modifier, locale)?
+        }
+        (Century(modifier), Some(date), ..) => fmt_century(output, date, modifier, locale)?,
+        (Era(modifier), Some(date), ..) => fmt_era(output, date, modifier, locale)?,
         (Hour(modifier), _, Some(time), _) => fmt_hour(output, time, modifier)?,
         (Minute(modifier), _, Some(time), _) => fmt_minute(output, time, modifier)?,
-        (Period(modifier), _, Some(time), _) => fmt_period(output, time, modifier)?,
+        (Period(modifier), _, Some(time), _) => fmt_period(output, time, modifier, locale)?,
         (Second(modifier), _, Some(time), _) => fmt_second(output, time, modifier)?,
-        (Subsecond(modifier), _, Some(time), _) => fmt_subsecond(output, time, modifier)?,
+        (Subsecond(modifier), _, Some(time), _) => {
+            fmt_subsecond(output, time.nanosecond(), modifier)?
+        }
         (OffsetHour(modifier), .., Some(offset)) => fmt_offset_hour(output, offset, modifier)?,
         (OffsetMinute(modifier), .., Some(offset)) => fmt_offset_minute(output, offset, modifier)?,
         (OffsetSecond(modifier), .., Some(offset)) => fmt_offset_second(output, offset, modifier)?,
+        (OffsetAbbreviation(_), .., Some(offset)) => {
+            fmt_offset_abbreviation(output, offset, locale)?
+        }
+        (Offset(modifier), .., Some(offset)) => fmt_offset(output, offset, modifier)?,
+        (UnixTimestamp(modifier), Some(date), Some(time), Some(offset)) => {
+            fmt_unix_timestamp(output, date, time, offset, modifier)?
+        }
+        (Ignore(_), ..) => return Err(error::Format::InvalidComponent("ignore")),
+        (End(_), ..) => 0,
+        _ => return Err(error::Format::InsufficientTypeInformation),
+    })
+}
+
+/// Format the provided component of a duration into the designated output. An `Err` will be
+/// returned if the component is not applicable to durations or if the value cannot be output to
+/// the stream.
+pub(crate) fn format_duration_component(
+    output: &mut impl Output,
+    component: Component,
+    duration: Duration,
+) -> Result<usize, error::Format> {
+    use Component::*;
+    let seconds = duration.whole_seconds().wrapping_abs() as u64;
+    let nanoseconds = duration.subsec_nanoseconds().abs() as u32;
+    Ok(match component {
+        DurationDay(_) => format_number(output, seconds / 86_400, modifier::Padding::None, 1)?,
+        DurationHour(modifier::DurationHour { padding, repr }) => {
+            let hours = seconds / 3_600;
+            let value = match repr {
+                modifier::DurationRepr::Total => hours,
+                modifier::DurationRepr::Remainder => hours % 24,
+            };
+            format_number(output, value, padding, 2)?
+        }
+        DurationMinute(modifier::DurationMinute { padding, repr }) => {
+            let minutes = seconds / 60;
+            let value = match repr {
+                modifier::DurationRepr::Total => minutes,
+                modifier::DurationRepr::Remainder => minutes % 60,
+            };
+            format_number(output, value, padding, 2)?
+        }
+        DurationSecond(modifier::DurationSecond { padding, repr }) => {
+            let value = match repr {
+                modifier::DurationRepr::Total => seconds,
+                modifier::DurationRepr::Remainder => seconds % 60,
+            };
+            format_number(output, value, padding, 2)?
+        }
+        DurationSubsecond(modifier::DurationSubsecond { digits }) => {
+            let modifier = modifier::Subsecond {
+                digits,
+                rounding: modifier::SubsecondRounding::Truncate,
+            };
+            fmt_subsecond(output, nanoseconds, modifier)?
+        }
+        DurationSign(modifier::DurationSign { sign_is_mandatory }) => {
+            if duration.is_negative() {
+                write(output, &[b'-'])?
+            } else if sign_is_mandatory {
+                write(output, &[b'+'])?
+            } else {
+                0
+            }
+        }
+        Ignore(_) => return Err(error::Format::InvalidComponent("ignore")),
+        End(_) => 0,
         _ => return Err(error::Format::InsufficientTypeInformation),
     })
 }
 
+/// Format the suffix of a number into the designated output.
+fn fmt_suffix(
+    output: &mut impl Output,
+    value: u16,
+    suffix: modifier::Suffix,
+    locale: &dyn Locale,
+) -> Result<usize, error::Format> {
+    match suffix {
+        modifier::Suffix::None => Ok(0),
+        modifier::Suffix::Ordinal => write(output, locale.ordinal_suffix(value).as_bytes()),
+    }
+}
+
 // region: date formatters
 /// Format the day into the designated output.
 fn fmt_day(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
-    modifier::Day { padding }: modifier::Day,
-) -> Result<usize, io::Error> {
-    format_number(output, date.day(), padding, 2)
+    modifier::Day { padding, suffix }: modifier::Day,
+    locale: &dyn Locale,
+) -> Result<usize, error::Format> {
+    Ok(format_number(output, date.day(), padding, 2)?
+        + fmt_suffix(output, date.day().into(), suffix, locale)?)
 }
 
 /// Format the month into the designated output.
 fn fmt_month(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
-    modifier::Month { padding, repr }: modifier::Month,
-) -> Result<usize, io::Error> {
-    match repr {
-        modifier::MonthRepr::Numerical => format_number(output, date.month(), padding, 2),
-        modifier::MonthRepr::Long => output.write(MONTH_NAMES[date.month() as usize - 1]),
-        modifier::MonthRepr::Short => output.write(&MONTH_NAMES[date.month() as usize - 1][..3]),
-    }
+    modifier::Month { padding, repr, .. }: modifier::Month,
+    locale: &dyn Locale,
+) -> Result<usize, error::Format> {
+    let names = match repr {
+        modifier::MonthRepr::Numerical => return format_number(output, date.month(), padding, 2),
+        modifier::MonthRepr::Long => locale.long_month_names(),
+        modifier::MonthRepr::Short => locale.short_month_names(),
+        modifier::MonthRepr::Narrow => locale.narrow_month_names(),
+    };
+    write(output, names[date.month() as usize - 1].as_bytes())
 }
 
 /// Format the ordinal into the designated output.
 fn fmt_ordinal(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
-    modifier::Ordinal { padding }: modifier::Ordinal,
-) -> Result<usize, io::Error> {
-    format_number(output, date.ordinal(), padding, 3)
+    modifier::Ordinal { padding, suffix }: modifier::Ordinal,
+    locale: &dyn Locale,
+) -> Result<usize, error::Format> {
+    Ok(format_number(output, date.ordinal(), padding, 3)?
+        + fmt_suffix(output, date.ordinal(), suffix, locale)?)
 }
 
 /// Format the weekday into the designated output.
 fn fmt_weekday(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
-    modifier::Weekday { repr, one_indexed }: modifier::Weekday,
-) -> Result<usize, io::Error> {
+    modifier::Weekday {
+        repr, one_indexed, ..
+    }: modifier::Weekday,
+    locale: &dyn Locale,
+) -> Result<usize, error::Format> {
+    let index = date.weekday().number_days_from_monday() as usize;
     match repr {
         modifier::WeekdayRepr::Short => {
-            output.write(&WEEKDAY_NAMES[date.weekday().number_days_from_monday() as usize][..3])
+            write(output, locale.short_weekday_names()[index].as_bytes())
         }
-        modifier::WeekdayRepr::Long => {
-            output.write(WEEKDAY_NAMES[date.weekday().number_days_from_monday() as usize])
+        modifier::WeekdayRepr::Long => write(output, locale.long_weekday_names()[index].as_bytes()),
+        modifier::WeekdayRepr::Narrow => {
+            write(output, locale.narrow_weekday_names()[index].as_bytes())
         }
         modifier::WeekdayRepr::Sunday => format_number(
             output,
@@ -218,33 +363,35 @@ fn fmt_weekday(
 
 /// Format the week number into the designated output.
 fn fmt_week_number(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
-    modifier::WeekNumber { padding, repr }: modifier::WeekNumber,
-) -> Result<usize, io::Error> {
-    format_number(
-        output,
-        match repr {
-            modifier::WeekNumberRepr::Iso => date.iso_week(),
-            modifier::WeekNumberRepr::Sunday => date.sunday_based_week(),
-            modifier::WeekNumberRepr::Monday => date.monday_based_week(),
-        },
+    modifier::WeekNumber {
         padding,
-        2,
-    )
+        repr,
+        suffix,
+    }: modifier::WeekNumber,
+    locale: &dyn Locale,
+) -> Result<usize, error::Format> {
+    let week = match repr {
+        modifier::WeekNumberRepr::Iso => date.iso_week(),
+        modifier::WeekNumberRepr::Sunday => date.sunday_based_week(),
+        modifier::WeekNumberRepr::Monday => date.monday_based_week(),
+    };
+    Ok(format_number(output, week, padding, 2)? + fmt_suffix(output, week.into(), suffix, locale)?)
 }
 
 /// Format the year into the designated output.
 fn fmt_year(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::Year {
         padding,
         repr,
         iso_week_based,
         sign_is_mandatory,
+        digits,
     }: modifier::Year,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     let full_year = if iso_week_based {
         date.iso_year_week().0
     } else {
@@ -253,38 +400,131 @@ fn fmt_year(
     let value = match repr {
         modifier::YearRepr::Full => full_year,
         modifier::YearRepr::LastTwo => (full_year % 100).abs(),
+        modifier::YearRepr::Historical if full_year > 0 => full_year,
+        modifier::YearRepr::Historical => 1 - full_year,
     };
+    // Values wider than this are never truncated, so large years are written in full.
     let width = match repr {
-        #[cfg(feature = "large-dates")]
-        modifier::YearRepr::Full if value.abs() >= 100_000 => 6,
-        #[cfg(feature = "large-dates")]
-        modifier::YearRepr::Full if value.abs() >= 10_000 => 5,
-        modifier::YearRepr::Full => 4,
+        modifier::YearRepr::Full => digits.max(4),
         modifier::YearRepr::LastTwo => 2,
+        modifier::YearRepr::Historical => 4,
     };
     let mut bytes = 0;
-    if repr != modifier::YearRepr::LastTwo {
+    if repr == modifier::YearRepr::Full {
         if full_year < 0 {
-            bytes += output.write(&[b'-'])?;
-        } else if sign_is_mandatory || cfg!(feature = "large-dates") && full_year >= 10_000 {
-            bytes += output.write(&[b'+'])?;
+            bytes += write(output, &[b'-'])?;
+        } else if sign_is_mandatory
+            || digits > 4
+            || cfg!(feature = "large-dates") && full_year >= 10_000
+        {
+            bytes += write(output, &[b'+'])?;
         }
     }
     bytes += format_number(output, value.abs() as u32, padding, width)?;
     Ok(bytes)
 }
+
+/// Format the quarter into the designated output.
+fn fmt_quarter(
+    output: &mut impl Output,
+    date: Date,
+    modifier::Quarter { padding, suffix }: modifier::Quarter,
+    locale: &dyn Locale,
+) -> Result<usize, error::Format> {
+    let quarter = (date.month() - 1) / 3 + 1;
+    Ok(format_number(output, quarter, padding, 1)?
+        + fmt_suffix(output, quarter.into(), suffix, locale)?)
+}
+
+/// Format the week of the month into the designated output.
+fn fmt_week_of_month(
+    output: &mut impl Output,
+    date: Date,
+    modifier::WeekOfMonth { padding, suffix }: modifier::WeekOfMonth,
+    locale: &dyn Locale,
+) -> Result<usize, error::Format> {
+    // The number of days from Monday to the first day of the month.
+    let first_weekday = (date.weekday().number_days_from_monday() as i8 - (date.day() as i8 - 1))
+        .rem_euclid(7) as u8;
+    let week = (date.day() - 1 + first_weekday) / 7 + 1;
+    Ok(format_number(output, week, padding, 1)? + fmt_suffix(output, week.into(), suffix, locale)?)
+}
+
+/// Format the week of the quarter into the designated output.
+fn fmt_week_of_quarter(
+    output: &mut impl Output,
+    date: Date,
+    modifier::WeekOfQuarter { padding, suffix }: modifier::WeekOfQuarter,
+    locale: &dyn Locale,
+) -> Result<usize, error::Format> {
+    let first_month = (date.month() - 1) / 3 * 3 + 1;
+    let day_of_quarter = (first_month..date.month())
+        .map(|month| days_in_year_month(date.year(), month) as u16)
+        .sum::<u16>()
+        + date.day() as u16
+        - 1;
+    // The number of days from Monday to the first day of the quarter.
+    let first_weekday = (date.weekday().number_days_from_monday() as i16 - day_of_quarter as i16)
+        .rem_euclid(7) as u16;
+    let week = ((day_of_quarter + first_weekday) / 7 + 1) as u8;
+    Ok(format_number(output, week, padding, 2)? + fmt_suffix(output, week.into(), suffix, locale)?)
+}
+
+/// Format the occurrence of the weekday within the month into the designated output.
+fn fmt_weekday_in_month(
+    output: &mut impl Output,
+    date: Date,
+    modifier::WeekdayInMonth { padding, suffix }: modifier::WeekdayInMonth,
+    locale: &dyn Locale,
+) -> Result<usize, error::Format> {
+    let occurrence = (date.day() - 1) / 7 + 1;
+    Ok(format_number(output, occurrence, padding, 1)?
+        + fmt_suffix(output, occurrence.into(), suffix, locale)?)
+}
+
+/// Format the century into the designated output.
+fn fmt_century(
+    output: &mut impl Output,
+    date: Date,
+    modifier::Century { padding, suffix }: modifier::Century,
+    locale: &dyn Locale,
+) -> Result<usize, error::Format> {
+    let century = (date.year() / 100).abs() as u16;
+    let mut bytes = 0;
+    if date.year() < 0 {
+        bytes += write(output, &[b'-'])?;
+    } else if cfg!(feature = "large-dates") && date.year() >= 10_000 {
+        bytes += write(output, &[b'+'])?;
+    }
+    bytes += format_number(output, century, padding, 2)?;
+    bytes += fmt_suffix(output, century, suffix, locale)?;
+    Ok(bytes)
+}
+
+/// Format the era into the designated output.
+fn fmt_era(
+    output: &mut impl Output,
+    date: Date,
+    modifier::Era { repr, .. }: modifier::Era,
+    locale: &dyn Locale,
+) -> Result<usize, error::Format> {
+    write(
+        output,
+        locale.era_names(repr)[(date.year() > 0) as usize].as_bytes(),
+    )
+}
 // endregion date formatters
 
 // region: time formatters
 /// Format the hour into the designated output.
 fn fmt_hour(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
     modifier::Hour {
         padding,
         is_12_hour_clock,
     }: modifier::Hour,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     let value = match (time.hour(), is_12_hour_clock) {
         (hour, false) => hour,
         (0, true) | (12, true) => 12,
@@ -296,83 +536,103 @@ fn fmt_hour(
 
 /// Format the minute into the designated output.
 fn fmt_minute(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
     modifier::Minute { padding }: modifier::Minute,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number(output, time.minute(), padding, 2)
 }
 
 /// Format the period into the designated output.
 fn fmt_period(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
-    modifier::Period { is_uppercase }: modifier::Period,
-) -> Result<usize, io::Error> {
-    match (time.hour() >= 12, is_uppercase) {
-        (false, false) => output.write(b"am"),
-        (false, true) => output.write(b"AM"),
-        (true, false) => output.write(b"pm"),
-        (true, true) => output.write(b"PM"),
-    }
+    modifier::Period { is_uppercase, .. }: modifier::Period,
+    locale: &dyn Locale,
+) -> Result<usize, error::Format> {
+    write(
+        output,
+        locale.period_markers(is_uppercase)[(time.hour() >= 12) as usize].as_bytes(),
+    )
 }
 
 /// Format the second into the designated output.
 fn fmt_second(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
     modifier::Second { padding }: modifier::Second,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number(output, time.second(), padding, 2)
 }
 
 /// Format the subsecond into the designated output.
-fn fmt_subsecond(
-    output: &mut impl io::Write,
-    time: Time,
-    modifier::Subsecond { digits }: modifier::Subsecond,
-) -> Result<usize, io::Error> {
-    let (value, width) = match digits {
-        modifier::SubsecondDigits::One => (time.nanosecond() / 100_000_000, 1),
-        modifier::SubsecondDigits::Two => (time.nanosecond() / 10_000_000, 2),
-        modifier::SubsecondDigits::Three => (time.nanosecond() / 1_000_000, 3),
-        modifier::SubsecondDigits::Four => (time.nanosecond() / 100_000, 4),
-        modifier::SubsecondDigits::Five => (time.nanosecond() / 10_000, 5),
-        modifier::SubsecondDigits::Six => (time.nanosecond() / 1_000, 6),
-        modifier::SubsecondDigits::Seven => (time.nanosecond() / 100, 7),
-        modifier::SubsecondDigits::Eight => (time.nanosecond() / 10, 8),
-        modifier::SubsecondDigits::Nine => (time.nanosecond(), 9),
-        modifier::SubsecondDigits::OneOrMore => match time.nanosecond() {
-            nanos if nanos % 10 != 0 => (nanos, 9),
-            nanos if (nanos / 10) % 10 != 0 => (nanos / 10, 8),
-            nanos if (nanos / 100) % 10 != 0 => (nanos / 100, 7),
-            nanos if (nanos / 1_000) % 10 != 0 => (nanos / 1_000, 6),
-            nanos if (nanos / 10_000) % 10 != 0 => (nanos / 10_000, 5),
-            nanos if (nanos / 100_000) % 10 != 0 => (nanos / 100_000, 4),
-            nanos if (nanos / 1_000_000) % 10 != 0 => (nanos / 1_000_000, 3),
-            nanos if (nanos / 10_000_000) % 10 != 0 => (nanos / 10_000_000, 2),
-            nanos => (nanos / 100_000_000, 1),
-        },
-    };
+pub(crate) fn fmt_subsecond(
+    output: &mut impl Output,
+    nanosecond: u32,
+    modifier::Subsecond { digits, .. }: modifier::Subsecond,
+) -> Result<usize, error::Format> {
+    let (min_digits, max_digits) = digits.bounds();
+    let mut value = nanosecond / 10_u32.pow((9 - max_digits) as _);
+    let mut width = max_digits;
+    while width > min_digits && value % 10 == 0 {
+        value /= 10;
+        width -= 1;
+    }
     format_number(output, value, modifier::Padding::Zero, width)
 }
+
+/// Round the time to the number of digits in the subsecond component, carrying into the date when
+/// the time rounds up to midnight. A time without a date wraps around instead.
+pub(crate) fn round_subsecond(
+    date: Option<Date>,
+    time: Option<Time>,
+    modifier::Subsecond { digits, rounding }: modifier::Subsecond,
+) -> Result<(Option<Date>, Option<Time>), error::Format> {
+    let time = match time {
+        Some(time) => time,
+        None => return Ok((date, time)),
+    };
+    let unit = 10_u32.pow((9 - digits.bounds().1) as _);
+    let (quotient, remainder) = (time.nanosecond() / unit, time.nanosecond() % unit);
+    let round_up = match rounding {
+        modifier::SubsecondRounding::Truncate => false,
+        modifier::SubsecondRounding::HalfUp => remainder * 2 >= unit,
+        modifier::SubsecondRounding::HalfEven => {
+            remainder * 2 > unit || remainder * 2 == unit && quotient % 2 == 1
+        }
+    };
+    if !round_up {
+        return Ok((date, Some(time)));
+    }
+
+    let (date_adjustment, time) =
+        time.adjusting_add(Duration::nanoseconds((unit - remainder) as _));
+    let date = match (date, date_adjustment) {
+        (Some(date), DateAdjustment::Next) => Some(
+            date.next_day()
+                .ok_or(error::Format::InvalidComponent("subsecond"))?,
+        ),
+        (date, _) => date,
+    };
+    Ok((date, Some(time)))
+}
 // endregion time formatters
 
 // region: offset formatters
 /// Format the offset hour into the designated output.
 fn fmt_offset_hour(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     offset: UtcOffset,
     modifier::OffsetHour {
         padding,
         sign_is_mandatory,
     }: modifier::OffsetHour,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     let mut bytes = 0;
     if offset.is_negative() {
-        bytes += output.write(&[b'-'])?;
+        bytes += write(output, &[b'-'])?;
     } else if sign_is_mandatory {
-        bytes += output.write(&[b'+'])?;
+        bytes += write(output, &[b'+'])?;
     }
     bytes += format_number(output, offset.whole_hours().abs() as u8, padding, 2)?;
     Ok(bytes)
@@ -380,19 +640,114 @@ fn fmt_offset_hour(
 
 /// Format the offset minute into the designated output.
 fn fmt_offset_minute(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     offset: UtcOffset,
     modifier::OffsetMinute { padding }: modifier::OffsetMinute,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number(output, offset.minutes_past_hour().abs() as u8, padding, 2)
 }
 
 /// Format the offset second into the designated output.
 fn fmt_offset_second(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     offset: UtcOffset,
     modifier::OffsetSecond { padding }: modifier::OffsetSecond,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number(output, offset.seconds_past_minute().abs() as u8, padding, 2)
 }
+
+/// Format the offset abbreviation into the designated output.
+fn fmt_offset_abbreviation(
+    output: &mut impl Output,
+    offset: UtcOffset,
+    locale: &dyn Locale,
+) -> Result<usize, error::Format> {
+    match locale
+        .offset_abbreviations()
+        .iter()
+        .find(|&&(_, abbreviated_offset)| abbreviated_offset == offset)
+    {
+        Some(&(name, _)) => write(output, name.as_bytes()),
+        None => Err(error::Format::InvalidComponent("offset_abbreviation")),
+    }
+}
+
+/// Format the whole offset into the designated output.
+fn fmt_offset(
+    output: &mut impl Output,
+    offset: UtcOffset,
+    modifier::Offset {
+        zulu,
+        separator,
+        minute_is_optional,
+    }: modifier::Offset,
+) -> Result<usize, error::Format> {
+    if offset.seconds_past_minute() != 0 {
+        return Err(error::Format::InvalidComponent("offset_second"));
+    }
+    if zulu && offset.is_utc() {
+        return write(output, &[b'Z']);
+    }
+
+    let mut bytes = write(
+        output,
+        if offset.is_negative() {
+            &[b'-']
+        } else {
+            &[b'+']
+        },
+    )?;
+    bytes += format_number(
+        output,
+        offset.whole_hours().abs() as u8,
+        modifier::Padding::Zero,
+        2,
+    )?;
+
+    let minutes = offset.minutes_past_hour().abs() as u8;
+    if minute_is_optional && minutes == 0 {
+        return Ok(bytes);
+    }
+    if separator != modifier::OffsetSeparator::None {
+        bytes += write(output, &[b':'])?;
+    }
+    bytes += format_number(output, minutes, modifier::Padding::Zero, 2)?;
+    Ok(bytes)
+}
 // endregion offset formatters
+
+// region: other formatters
+/// Format the Unix timestamp into the designated output.
+fn fmt_unix_timestamp(
+    output: &mut impl Output,
+    date: Date,
+    time: Time,
+    offset: UtcOffset,
+    modifier::UnixTimestamp {
+        precision,
+        sign_is_mandatory,
+    }: modifier::UnixTimestamp,
+) -> Result<usize, error::Format> {
+    let timestamp = PrimitiveDateTime::new(date, time)
+        .assume_offset(offset)
+        .unix_timestamp_nanos();
+    let value = match precision {
+        modifier::UnixTimestampPrecision::Second => timestamp.div_euclid(1_000_000_000),
+        modifier::UnixTimestampPrecision::Millisecond => timestamp.div_euclid(1_000_000),
+        modifier::UnixTimestampPrecision::Microsecond => timestamp.div_euclid(1_000),
+        modifier::UnixTimestampPrecision::Nanosecond => timestamp,
+    };
+
+    let mut bytes = 0;
+    if value < 0 {
+        bytes += write(output, &[b'-'])?;
+    } else if sign_is_mandatory {
+        bytes += write(output, &[b'+'])?;
+    }
+    bytes += write(
+        output,
+        itoa::Buffer::new().format(value.abs() as u128).as_bytes(),
+    )?;
+    Ok(bytes)
+}
+// endregion other formatters
@@ -0,0 +1,122 @@
+//! Destinations that formatted values can be written to.
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::error;
+
+/// A destination that formatted values can be written to.
+///
+/// This is implemented for all types implementing [`std::io::Write`] when the `std` feature is
+/// enabled. Without it, [`FmtWrite`] and [`SliceWriter`] can be used to write to any
+/// [`core::fmt::Write`] or a caller-provided buffer respectively.
+pub trait Output {
+    /// Write the entirety of `bytes` to the output, returning an error if this is not possible.
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), error::Format>;
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+impl<W: io::Write + ?Sized> Output for W {
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), error::Format> {
+        Ok(self.write_all(bytes)?)
+    }
+}
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+impl Output for alloc::vec::Vec<u8> {
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), error::Format> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// An adapter that writes formatted values to a [`core::fmt::Write`], such as a `String` or a
+/// [`core::fmt::Formatter`].
+///
+/// All output of the time crate is valid UTF-8, with the exception of literals that were
+/// constructed manually. Writing a literal that is not valid UTF-8 returns an error.
+///
+/// ```rust
+/// # use time::formatting::FmtWrite;
+/// # use time::macros::{date, format_description};
+/// let mut output = FmtWrite(String::new());
+/// date!("2021-03-15").format_into(&mut output, &format_description!("[year]-[month]-[day]"))?;
+/// assert_eq!(output.0, "2021-03-15");
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FmtWrite<W>(pub W);
+
+impl<W: fmt::Write> Output for FmtWrite<W> {
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), error::Format> {
+        let s = core::str::from_utf8(bytes).map_err(|_| fmt::Error)?;
+        Ok(self.0.write_str(s)?)
+    }
+}
+
+/// An adapter that writes formatted values to a caller-provided buffer.
+///
+/// If the formatted value does not fit in the remaining space, an
+/// [`error::Format::BufferOverflow`] is returned and nothing more is written.
+///
+/// ```rust
+/// # use time::formatting::SliceWriter;
+/// # use time::macros::{format_description, time};
+/// let mut buf = [0; 8];
+/// let mut output = SliceWriter::new(&mut buf);
+/// time!("10:15:30").format_into(&mut output, &format_description!("[hour]:[minute]"))?;
+/// assert_eq!(output.written(), b"10:15");
+/// assert!(time!("10:15:30")
+///     .format_into(&mut output, &format_description!("[hour]:[minute]"))
+///     .is_err());
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct SliceWriter<'a> {
+    /// The buffer being written to.
+    buf: &'a mut [u8],
+    /// The number of bytes that have been written to the buffer.
+    len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    /// Create a new `SliceWriter` that writes to the start of the provided buffer.
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// Obtain the bytes that have been written to the buffer.
+    pub fn written(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Obtain the number of bytes that have been written to the buffer.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no bytes have been written to the buffer.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Obtain the number of bytes that can still be written to the buffer.
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.len
+    }
+}
+
+impl Output for SliceWriter<'_> {
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), error::Format> {
+        let end = self.len + bytes.len();
+        let destination = self
+            .buf
+            .get_mut(self.len..end)
+            .ok_or(error::BufferOverflow)?;
+        destination.copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+}
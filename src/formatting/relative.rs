@@ -0,0 +1,264 @@
+//! Describing a duration relative to now, such as "5 minutes ago" or "in 3 weeks".
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::format_description::locale::{English, Locale};
+use crate::format_description::modifier::Padding;
+use crate::formatting::{format_number, write, Output};
+#[cfg(feature = "alloc")]
+use crate::OffsetDateTime;
+use crate::{error, Duration};
+
+/// A unit of time used when describing a duration relative to now.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    /// Seconds.
+    Second,
+    /// Minutes.
+    Minute,
+    /// Hours.
+    Hour,
+    /// Days.
+    Day,
+    /// Weeks.
+    Week,
+    /// Months, each of which is the average length of a month in the Gregorian calendar.
+    Month,
+    /// Years, each of which is the average length of a year in the Gregorian calendar.
+    Year,
+}
+
+/// How the number of units is rounded.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round towards zero, so that 1 minute and 50 seconds is "1 minute".
+    Floor,
+    /// Round to the nearest whole number, with halfway cases rounded away from zero.
+    Nearest,
+    /// Round away from zero, so that 1 minute and 10 seconds is "2 minutes".
+    Ceil,
+}
+
+/// Configuration for describing a duration relative to now, such as "just now", "5 minutes ago"
+/// or "in 3 weeks".
+///
+/// A positive duration is in the future, while a negative duration is in the past. The smallest
+/// unit whose rounded count is below its threshold is used; years have no threshold. Durations
+/// shorter than the [`just_now`](Self::set_just_now) threshold, or that round to zero, are
+/// described as "just now".
+///
+/// The configuration is built using `const fn` methods, allowing it to be used in a `const`
+/// context.
+///
+/// ```rust
+/// # use time::formatting::relative::{Relative, Rounding, Unit};
+/// # use time::ext::NumericalDuration;
+/// assert_eq!(Relative::DEFAULT.format((-5).minutes())?, "5 minutes ago");
+/// assert_eq!(Relative::DEFAULT.format(3.weeks())?, "in 3 weeks");
+/// assert_eq!(Relative::DEFAULT.format(3.seconds())?, "just now");
+///
+/// const FLOOR_DAYS: Relative = Relative::DEFAULT
+///     .set_rounding(Rounding::Floor)
+///     .set_threshold(Unit::Day, 30);
+/// assert_eq!(FLOOR_DAYS.format(17.5.days())?, "in 17 days");
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Relative {
+    /// Durations shorter than this are described as "just now".
+    just_now: Duration,
+    /// How the number of units is rounded.
+    rounding: Rounding,
+    /// The number of seconds at which minutes are used instead.
+    seconds: u32,
+    /// The number of minutes at which hours are used instead.
+    minutes: u32,
+    /// The number of hours at which days are used instead.
+    hours: u32,
+    /// The number of days at which weeks are used instead.
+    days: u32,
+    /// The number of weeks at which months are used instead.
+    weeks: u32,
+    /// The number of months at which years are used instead.
+    months: u32,
+}
+
+impl Relative {
+    /// The default configuration. Durations under ten seconds are "just now", counts are rounded
+    /// to the nearest whole number, and the thresholds are 60 seconds, 60 minutes, 24 hours,
+    /// 7 days, 5 weeks, and 12 months.
+    pub const DEFAULT: Self = Self {
+        just_now: Duration::seconds(10),
+        rounding: Rounding::Nearest,
+        seconds: 60,
+        minutes: 60,
+        hours: 24,
+        days: 7,
+        weeks: 5,
+        months: 12,
+    };
+
+    /// Set the duration below which the phrase for "just now" is used.
+    pub const fn set_just_now(self, just_now: Duration) -> Self {
+        Self { just_now, ..self }
+    }
+
+    /// Set how the number of units is rounded.
+    pub const fn set_rounding(self, rounding: Rounding) -> Self {
+        Self { rounding, ..self }
+    }
+
+    /// Set the count of the unit at which the next larger unit is used instead. Years are never
+    /// replaced by a larger unit, so their threshold is ignored.
+    pub const fn set_threshold(self, unit: Unit, threshold: u32) -> Self {
+        match unit {
+            Unit::Second => Self {
+                seconds: threshold,
+                ..self
+            },
+            Unit::Minute => Self {
+                minutes: threshold,
+                ..self
+            },
+            Unit::Hour => Self {
+                hours: threshold,
+                ..self
+            },
+            Unit::Day => Self {
+                days: threshold,
+                ..self
+            },
+            Unit::Week => Self {
+                weeks: threshold,
+                ..self
+            },
+            Unit::Month => Self {
+                months: threshold,
+                ..self
+            },
+            Unit::Year => self,
+        }
+    }
+
+    /// Determine the unit and count used to describe the duration. `None` is returned if the
+    /// duration is described as "just now".
+    fn unit_and_count(self, duration: Duration) -> Option<(Unit, u64)> {
+        let nanoseconds = duration.whole_nanoseconds().abs() as u128;
+        if nanoseconds < self.just_now.whole_nanoseconds().abs() as u128 {
+            return None;
+        }
+
+        let units = [
+            (Unit::Second, 1, Some(self.seconds)),
+            (Unit::Minute, 60, Some(self.minutes)),
+            (Unit::Hour, 3_600, Some(self.hours)),
+            (Unit::Day, 86_400, Some(self.days)),
+            (Unit::Week, 604_800, Some(self.weeks)),
+            (Unit::Month, 2_629_746, Some(self.months)),
+            (Unit::Year, 31_556_952, None),
+        ];
+        for &(unit, seconds_per_unit, threshold) in units.iter() {
+            let nanos_per_unit = seconds_per_unit * 1_000_000_000_u128;
+            let count = match self.rounding {
+                Rounding::Floor => nanoseconds / nanos_per_unit,
+                Rounding::Nearest => (nanoseconds + nanos_per_unit / 2) / nanos_per_unit,
+                Rounding::Ceil => (nanoseconds + nanos_per_unit - 1) / nanos_per_unit,
+            };
+            if threshold.map_or(true, |threshold| count < threshold as u128) {
+                return if count == 0 {
+                    None
+                } else {
+                    Some((unit, count as u64))
+                };
+            }
+        }
+        unreachable!("years have no threshold")
+    }
+
+    /// Describe the duration relative to now, writing the phrase to the output. The number of
+    /// bytes written is returned.
+    pub fn format_into(
+        self,
+        output: &mut impl Output,
+        duration: Duration,
+    ) -> Result<usize, error::Format> {
+        self.format_into_with_locale(output, duration, &English)
+    }
+
+    /// Describe the duration relative to now using the phrases provided by the locale, writing
+    /// the phrase to the output. The number of bytes written is returned.
+    pub fn format_into_with_locale(
+        self,
+        output: &mut impl Output,
+        duration: Duration,
+        locale: &dyn Locale,
+    ) -> Result<usize, error::Format> {
+        let (unit, count) = match self.unit_and_count(duration) {
+            Some(unit_and_count) => unit_and_count,
+            None => return write(output, locale.relative_now().as_bytes()),
+        };
+
+        let (before, after) = locale.relative_time(count, unit, duration.is_positive());
+        let mut bytes = 0;
+        bytes += write(output, before.as_bytes())?;
+        bytes += format_number(output, count, Padding::None, 0)?;
+        bytes += write(output, after.as_bytes())?;
+        Ok(bytes)
+    }
+
+    /// Describe the duration relative to now.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    pub fn format(self, duration: Duration) -> Result<String, error::Format> {
+        self.format_with_locale(duration, &English)
+    }
+
+    /// Describe the duration relative to now using the phrases provided by the locale.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    pub fn format_with_locale(
+        self,
+        duration: Duration,
+        locale: &dyn Locale,
+    ) -> Result<String, error::Format> {
+        let mut buf = Vec::new();
+        self.format_into_with_locale(&mut buf, duration, locale)?;
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+
+    /// Describe the first `OffsetDateTime` relative to the second, which is typically the current
+    /// time.
+    ///
+    /// ```rust
+    /// # use time::formatting::relative::Relative;
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     Relative::DEFAULT.format_between(
+    ///         datetime!("2021-03-15 09:00 UTC"),
+    ///         datetime!("2021-03-15 12:00 +1"),
+    ///     )?,
+    ///     "2 hours ago"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    pub fn format_between(
+        self,
+        datetime: OffsetDateTime,
+        now: OffsetDateTime,
+    ) -> Result<String, error::Format> {
+        self.format(datetime - now)
+    }
+}
+
+impl Default for Relative {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
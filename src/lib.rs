@@ -20,9 +20,10 @@
 //!
 //!   Enables macros that provide compile-time verification of values and intuitive syntax.
 //!
-//! - `formatting` (_implicitly enables `std`_)
+//! - `formatting`
 //!
-//!   Enables formatting of most structs.
+//!   Enables formatting of most structs. Formatting directly to a `String` requires the `alloc`
+//!   feature, and formatting to a `std::io::Write` requires the `std` feature.
 //!
 //! - `parsing`
 //!
@@ -49,7 +50,7 @@
 //!
 //!   Enables [serde](https://docs.rs/serde) support for all types.
 //!
-//! - `serde-human-readable` (_implicitly enables `serde`, `formatting`, and `parsing`_)
+//! - `serde-human-readable` (_implicitly enables `serde`, `formatting`, `parsing`, and `alloc`_)
 //!
 //!   Allows serde representations to use a human-readable format. This is determined by the
 //!   serializer, not the user. If this feature is not enabled or if the serializer requests a
@@ -272,7 +273,7 @@ pub mod ext;
 pub mod format_description;
 #[cfg(feature = "formatting")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
-mod formatting;
+pub mod formatting;
 /// The [`Instant`] struct and its associated `impl`s.
 #[cfg(feature = "std")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
@@ -43,6 +43,37 @@ pub use time_macros::datetime;
 /// # Ok::<_, time::Error>(())
 /// ```
 ///
+/// Sections containing nested descriptions are also accepted:
+///
+/// - `[optional [...]]` is used when parsing if it matches, and is always present when
+///   formatting.
+/// - `[first [...] [...]]` uses the first alternative that matches when parsing, and the first
+///   alternative when formatting.
+///
+/// ```rust
+/// # use time::{macros::{date, format_description, time}, Date, Time};
+/// let format = format_description!("[hour]:[minute][optional [:[second]]]");
+/// assert_eq!(Time::parse("10:15", &format)?, time!("10:15"));
+/// assert_eq!(Time::parse("10:15:30", &format)?, time!("10:15:30"));
+/// assert_eq!(time!("10:15").format(&format)?, "10:15:00");
+///
+/// let format = format_description!("[first [[year]-[month]-[day]] [[day]/[month]/[year]]]");
+/// assert_eq!(Date::parse("15/03/2021", &format)?, date!("2021-03-15"));
+/// assert_eq!(date!("2021-03-15").format(&format)?, "2021-03-15");
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// Version 2 of the grammar, selected with a leading `[version 2]`, is also accepted. Groups
+/// (`[[...]]`) become a
+/// [`FormatItem::Compound`](crate::format_description::FormatItem::Compound).
+///
+/// ```rust
+/// # use time::{format_description::FormatItem, macros::{format_description, time}};
+/// const FORMAT: &[FormatItem<'_>] = format_description!(r"[version 2]\[[[[hour]:[minute]]]\]");
+/// assert_eq!(time!("10:15").format(&FORMAT)?, "[10:15]");
+/// # Ok::<_, time::Error>(())
+/// ```
+///
 /// [`format_description::parse()`]: crate::format_description::parse()
 #[cfg(any(feature = "formatting", feature = "parsing"))]
 #[cfg_attr(
@@ -1,20 +1,30 @@
+#[cfg(all(feature = "formatting", feature = "alloc"))]
+use alloc::string::String;
 use core::cmp::Ordering;
 #[cfg(feature = "std")]
 use core::convert::From;
+#[cfg(feature = "parsing")]
+use core::convert::TryInto;
 #[cfg(feature = "formatting")]
 use core::fmt;
 use core::hash::{Hash, Hasher};
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 use core::time::Duration as StdDuration;
-#[cfg(feature = "formatting")]
-use std::io;
+#[cfg(all(feature = "parsing", feature = "std"))]
+use std::io::{self, BufRead};
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+use crate::format_description::locale::Locale;
 #[cfg(feature = "formatting")]
 use crate::formatting::formattable::sealed::Formattable;
+#[cfg(feature = "formatting")]
+use crate::formatting::{self, Output, SliceWriter};
 #[cfg(feature = "parsing")]
 use crate::parsing::parsable::sealed::Parsable;
+#[cfg(all(feature = "parsing", feature = "std"))]
+use crate::parsing::Parsed;
 use crate::{error, Date, Duration, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
 /// The Julian day of the Unix epoch.
@@ -790,7 +800,7 @@ impl OffsetDateTime {
     /// [`format_description::parse`](crate::format_description::parse()).
     pub fn format_into<F: Formattable>(
         self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         format: &F,
     ) -> Result<usize, F::Error> {
         let local = self.utc_datetime.utc_to_offset(self.offset);
@@ -818,10 +828,48 @@ impl OffsetDateTime {
     /// );
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
     pub fn format<F: Formattable>(self, format: &F) -> Result<String, F::Error> {
         let local = self.utc_datetime.utc_to_offset(self.offset);
         format.format(Some(local.date), Some(local.time), Some(self.offset))
     }
+
+    /// Format the `OffsetDateTime` using the provided format description and the names provided
+    /// by the locale. The formatted value will be output to the provided writer.
+    pub fn format_into_with_locale<F: Formattable>(
+        self,
+        output: &mut impl Output,
+        format: &F,
+        locale: &dyn Locale,
+    ) -> Result<usize, F::Error> {
+        let local = self.utc_datetime.utc_to_offset(self.offset);
+        format.format_into_with_locale(
+            output,
+            Some(local.date),
+            Some(local.time),
+            Some(self.offset),
+            locale,
+        )
+    }
+
+    /// Format the `OffsetDateTime` using the provided format description and the names provided
+    /// by the locale.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    pub fn format_with_locale<F: Formattable>(
+        self,
+        format: &F,
+        locale: &dyn Locale,
+    ) -> Result<String, F::Error> {
+        let local = self.utc_datetime.utc_to_offset(self.offset);
+        format.format_with_locale(
+            Some(local.date),
+            Some(local.time),
+            Some(self.offset),
+            locale,
+        )
+    }
 }
 
 #[cfg(feature = "parsing")]
@@ -846,13 +894,93 @@ impl OffsetDateTime {
     pub fn parse(input: &str, description: &impl Parsable) -> Result<Self, error::Parse> {
         description.parse_offset_date_time(input.as_bytes())
     }
+
+    /// Parse an `OffsetDateTime` from the input using the provided format description and the
+    /// names provided by the locale.
+    pub fn parse_with_locale(
+        input: &str,
+        description: &impl Parsable,
+        locale: &dyn Locale,
+    ) -> Result<Self, error::Parse> {
+        Ok(description
+            .parse_with_locale(input.as_bytes(), locale)?
+            .try_into()?)
+    }
+
+    /// Parse an `OffsetDateTime` from the start of the input using the provided format description. The
+    /// remaining input is returned alongside the value.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime, OffsetDateTime};
+    /// let format = format_description::parse(
+    ///     "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour sign:mandatory]",
+    /// )?;
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_prefix("2020-01-02 03:04:05 +06 rest", &format)?,
+    ///     (datetime!("2020-01-02 03:04:05 +06"), " rest")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &impl Parsable,
+    ) -> Result<(Self, &'a str), error::Parse> {
+        let (parsed, remaining) = description.parse_prefix(input)?;
+        Ok((parsed.try_into()?, remaining))
+    }
+
+    /// Parse an `OffsetDateTime` from the start of the reader using the provided format description. Only the
+    /// bytes that were parsed are consumed, so the remainder can be read afterwards. Unless the
+    /// value is at the end of the reader, it must be followed by at least 16 bytes in the reader's
+    /// buffer, as it could otherwise continue past the end of the buffer.
+    ///
+    /// Errors that occur while parsing are returned with [`io::ErrorKind::InvalidData`],
+    /// wrapping an [`error::Parse`]. If an error is returned, it is unspecified how many bytes
+    /// were consumed from the reader.
+    ///
+    /// ```rust
+    /// # use std::io::BufRead;
+    /// # use time::{format_description, macros::datetime, OffsetDateTime};
+    /// let format = format_description::parse(
+    ///     "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour sign:mandatory]",
+    /// )?;
+    /// let mut reader = "2020-01-02 03:04:05 +06 starting up the server\n".as_bytes();
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_from_reader(&mut reader, &format)?,
+    ///     datetime!("2020-01-02 03:04:05 +06")
+    /// );
+    /// let mut message = String::new();
+    /// reader.read_line(&mut message)?;
+    /// assert_eq!(message, " starting up the server\n");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+    pub fn parse_from_reader(
+        reader: &mut impl BufRead,
+        description: &impl Parsable,
+    ) -> io::Result<Self> {
+        let mut parsed = Parsed::new();
+        description.parse_into_from_reader(reader, &mut parsed)?;
+        parsed.try_into().map_err(|err| {
+            io::Error::new(io::ErrorKind::InvalidData, error::Parse::TryFromParsed(err))
+        })
+    }
 }
 
 #[cfg(feature = "formatting")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
 impl fmt::Display for OffsetDateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {} {}", self.date(), self.time(), self.offset)
+        let mut buf = [0; 42];
+        let mut output = SliceWriter::new(&mut buf);
+        self.date().format_display_into(&mut output)?;
+        output.write_bytes(&[b' ']).map_err(|_| fmt::Error)?;
+        self.time()
+            .format_display_into(&mut output, f.precision())?;
+        output.write_bytes(&[b' ']).map_err(|_| fmt::Error)?;
+        self.offset.format_display_into(&mut output)?;
+        formatting::pad(f, output.written())
     }
 }
 // endregion formatting & parsing
@@ -25,6 +25,62 @@ pub(crate) fn first_match<'a, 'b: 'a, T: Copy + 'a>(
     }
 }
 
+/// Whether the input starts with the name. When not case sensitive, ASCII characters are compared
+/// case-insensitively.
+fn starts_with_name(input: &[u8], name: &str, case_sensitive: bool) -> bool {
+    let name = name.as_bytes();
+    match input.get(..name.len()) {
+        Some(prefix) if case_sensitive => prefix == name,
+        Some(prefix) => prefix.eq_ignore_ascii_case(name),
+        None => false,
+    }
+}
+
+/// Consume the longest matching name, returning its index. Choosing the longest match avoids
+/// mistakenly consuming a name that is a prefix of another.
+pub(crate) fn longest_match_index<'a>(
+    names: &[&str],
+    input: &'a [u8],
+    case_sensitive: bool,
+) -> Option<ParsedItem<'a, usize>> {
+    names
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| starts_with_name(input, name, case_sensitive))
+        .max_by_key(|(_, name)| name.len())
+        .map(|(index, name)| ParsedItem(&input[name.len()..], index))
+}
+
+/// Consume the longest matching name, returning its associated value.
+pub(crate) fn longest_match<'a, T: Copy>(
+    options: &[(&str, T)],
+    input: &'a [u8],
+    case_sensitive: bool,
+) -> Option<ParsedItem<'a, T>> {
+    options
+        .iter()
+        .filter(|(name, _)| starts_with_name(input, name, case_sensitive))
+        .max_by_key(|(name, _)| name.len())
+        .map(|&(name, value)| ParsedItem(&input[name.len()..], value))
+}
+
+/// Consume the name if exactly one name matches, returning its index. If the input is ambiguous,
+/// `None` is returned.
+pub(crate) fn only_match_index<'a>(
+    names: &[&str],
+    input: &'a [u8],
+    case_sensitive: bool,
+) -> Option<ParsedItem<'a, usize>> {
+    let mut matches = names
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| starts_with_name(input, name, case_sensitive));
+    match (matches.next(), matches.next()) {
+        (Some((index, name)), None) => Some(ParsedItem(&input[name.len()..], index)),
+        _ => None,
+    }
+}
+
 /// Consume between `n` and `m` instances of the provided parser.
 pub(crate) fn n_to_m<'a, T>(
     n: u8,
@@ -134,3 +190,96 @@ pub(crate) fn opt<'a, T>(
         None => ParsedItem(input, None),
     }
 }
+
+/// Consume one or more instances of the provided parser, discarding the values.
+pub(crate) fn one_or_more<'a, T>(
+    parser: impl Fn(&'a [u8]) -> Option<ParsedItem<'a, T>>,
+) -> impl Fn(&'a [u8]) -> Option<ParsedItem<'a, ()>> {
+    move |input| {
+        let mut input = parser(input)?.0;
+        while let Some(ParsedItem(new_input, _)) = parser(input) {
+            input = new_input;
+        }
+        Some(ParsedItem(input, ()))
+    }
+}
+
+// region: RFC 2822
+/// Consume exactly one space or tab.
+const fn wsp(input: &[u8]) -> Option<ParsedItem<'_, ()>> {
+    match input {
+        [b' ', remaining @ ..] | [b'\t', remaining @ ..] => Some(ParsedItem(remaining, ())),
+        _ => None,
+    }
+}
+
+/// Consume a CRLF sequence.
+const fn crlf(input: &[u8]) -> Option<ParsedItem<'_, ()>> {
+    match input {
+        [b'\r', b'\n', remaining @ ..] => Some(ParsedItem(remaining, ())),
+        _ => None,
+    }
+}
+
+/// Consume the `FWS` (folding whitespace) rule of RFC 2822.
+///
+/// This is equivalent to `([*WSP CRLF] 1*WSP) / obs-FWS`, which simplifies to at least one space
+/// or tab, with any CRLF required to be immediately followed by a space or tab.
+pub(crate) fn fws(input: &[u8]) -> Option<ParsedItem<'_, ()>> {
+    let mut input = match crlf(input) {
+        Some(ParsedItem(input, ())) => one_or_more(wsp)(input)?.0,
+        None => one_or_more(wsp)(input)?.0,
+    };
+    while let Some(ParsedItem(new_input, ())) = crlf(input) {
+        input = one_or_more(wsp)(new_input)?.0;
+    }
+    Some(ParsedItem(input, ()))
+}
+
+/// Consume a single piece of `ccontent` within a comment other than a nested comment: a printable
+/// character other than parentheses and backslash, or a quoted pair.
+const fn ccontent(input: &[u8]) -> Option<ParsedItem<'_, ()>> {
+    match input {
+        [b'\\', c, remaining @ ..] if c.is_ascii() => Some(ParsedItem(remaining, ())),
+        [b'\x21'..=b'\x27', remaining @ ..]
+        | [b'\x2A'..=b'\x5B', remaining @ ..]
+        | [b'\x5D'..=b'\x7E', remaining @ ..] => Some(ParsedItem(remaining, ())),
+        _ => None,
+    }
+}
+
+/// Consume a (possibly nested) comment as defined by RFC 2822.
+///
+/// Nested comments are tracked with a counter rather than by recursion, so that deeply nested
+/// input cannot overflow the stack.
+fn comment(input: &[u8]) -> Option<ParsedItem<'_, ()>> {
+    let mut input = ascii_char(b'(')(input)?.unwrap();
+    let mut depth = 1_usize;
+    loop {
+        input = opt(fws)(input).0;
+        input = match input {
+            [b'(', remaining @ ..] => {
+                depth += 1;
+                remaining
+            }
+            [b')', remaining @ ..] => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(ParsedItem(remaining, ()));
+                }
+                remaining
+            }
+            _ => ccontent(input)?.0,
+        };
+    }
+}
+
+/// Consume the `CFWS` rule of RFC 2822: any non-empty combination of folding whitespace and
+/// comments.
+pub(crate) fn cfws(input: &[u8]) -> Option<ParsedItem<'_, ()>> {
+    one_or_more(|input| match fws(input) {
+        Some(ParsedItem(input, ())) => Some(opt(comment)(input).map(|_| ())),
+        None => comment(input),
+    })(input)
+}
+// endregion RFC 2822
@@ -2,19 +2,53 @@
 
 use core::num::{NonZeroU16, NonZeroU8};
 
+use crate::format_description::locale::Locale;
 use crate::format_description::modifier;
-#[cfg(feature = "large-dates")]
-use crate::parsing::combinator::n_to_m_digits_padded;
 use crate::parsing::combinator::{
-    any_digit, exactly_n_digits, exactly_n_digits_padded, first_match, opt, sign,
+    any_digit, ascii_char, ascii_char_ignore_case, exactly_n_digits, exactly_n_digits_padded,
+    first_match, longest_match, longest_match_index, n_to_m_digits, n_to_m_digits_padded,
+    only_match_index, opt, sign,
 };
 use crate::parsing::ParsedItem;
-use crate::Weekday;
+use crate::{UtcOffset, Weekday};
+
+/// The days of the week, in the order used by [`Locale`].
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+    Weekday::Saturday,
+    Weekday::Sunday,
+];
 
 // region: date components
 /// Parse the "year" component of a `Date`.
 pub(crate) fn parse_year(input: &[u8], modifiers: modifier::Year) -> Option<ParsedItem<'_, i32>> {
     match modifiers.repr {
+        modifier::YearRepr::Full if modifiers.digits > 4 => {
+            // The expanded representation always has a sign and at least the given number of
+            // digits.
+            let ParsedItem(input, sign) = sign(input)?;
+            let max_digits = if cfg!(feature = "large-dates") {
+                modifiers.digits.max(6)
+            } else {
+                modifiers.digits
+            };
+            let ParsedItem(input, year) =
+                n_to_m_digits_padded::<u32>(modifiers.digits, max_digits, modifiers.padding)(
+                    input,
+                )?;
+            Some(ParsedItem(
+                input,
+                if sign == b'-' {
+                    -(year as i32)
+                } else {
+                    year as i32
+                },
+            ))
+        }
         modifier::YearRepr::Full => {
             let ParsedItem(input, sign) = opt(sign)(input);
             #[cfg(not(feature = "large-dates"))]
@@ -32,143 +66,260 @@ pub(crate) fn parse_year(input: &[u8], modifiers: modifier::Year) -> Option<Pars
         modifier::YearRepr::LastTwo => {
             Some(exactly_n_digits_padded::<u32>(2, modifiers.padding)(input)?.map(|v| v as i32))
         }
+        // The year before 1 in the full representation is 0, which is the first year of the
+        // earlier era. As a result, there is one more digit than the full representation allows.
+        modifier::YearRepr::Historical => {
+            #[cfg(not(feature = "large-dates"))]
+            let max_digits = 5;
+            #[cfg(feature = "large-dates")]
+            let max_digits = 7;
+            Some(
+                n_to_m_digits_padded::<u32>(4, max_digits, modifiers.padding)(input)?
+                    .map(|v| v as i32),
+            )
+        }
     }
 }
 
 /// Parse the "month" component of a `Date`.
-pub(crate) fn parse_month(
-    input: &[u8],
+pub(crate) fn parse_month<'a>(
+    input: &'a [u8],
     modifiers: modifier::Month,
-) -> Option<ParsedItem<'_, NonZeroU8>> {
-    let ParsedItem(remaining, value) = first_match(match modifiers.repr {
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, NonZeroU8>> {
+    let ParsedItem(remaining, index) = match modifiers.repr {
         modifier::MonthRepr::Numerical => {
             return exactly_n_digits_padded(2, modifiers.padding)(input);
         }
-        modifier::MonthRepr::Long => [
-            ("January", 1),
-            ("February", 2),
-            ("March", 3),
-            ("April", 4),
-            ("May", 5),
-            ("June", 6),
-            ("July", 7),
-            ("August", 8),
-            ("September", 9),
-            ("October", 10),
-            ("November", 11),
-            ("December", 12),
-        ]
-        .iter(),
-        modifier::MonthRepr::Short => [
-            ("Jan", 1),
-            ("Feb", 2),
-            ("Mar", 3),
-            ("Apr", 4),
-            ("May", 5),
-            ("Jun", 6),
-            ("Jul", 7),
-            ("Aug", 8),
-            ("Sep", 9),
-            ("Oct", 10),
-            ("Nov", 11),
-            ("Dec", 12),
-        ]
-        .iter(),
-    })(input)?;
-    Some(ParsedItem(remaining, NonZeroU8::new(value)?))
+        modifier::MonthRepr::Long => {
+            longest_match_index(locale.long_month_names(), input, modifiers.case_sensitive)?
+        }
+        modifier::MonthRepr::Short => {
+            longest_match_index(locale.short_month_names(), input, modifiers.case_sensitive)?
+        }
+        modifier::MonthRepr::Narrow => {
+            only_match_index(locale.narrow_month_names(), input, modifiers.case_sensitive)?
+        }
+    };
+    Some(ParsedItem(remaining, NonZeroU8::new(index as u8 + 1)?))
 }
 
 /// Parse the "week number" component of a `Date`.
-pub(crate) fn parse_week_number(
-    input: &[u8],
+pub(crate) fn parse_week_number<'a>(
+    input: &'a [u8],
     modifiers: modifier::WeekNumber,
-) -> Option<ParsedItem<'_, u8>> {
-    exactly_n_digits_padded(2, modifiers.padding)(input)
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, u8>> {
+    let ParsedItem(input, week): ParsedItem<'_, u8> =
+        exactly_n_digits_padded(2, modifiers.padding)(input)?;
+    let input = parse_suffix(input, week.into(), modifiers.suffix, locale)?;
+    Some(ParsedItem(input, week))
 }
 
 /// Parse the "weekday" component of a `Date`.
-pub(crate) fn parse_weekday(
-    input: &[u8],
+pub(crate) fn parse_weekday<'a>(
+    input: &'a [u8],
     modifiers: modifier::Weekday,
-) -> Option<ParsedItem<'_, Weekday>> {
-    first_match(match (modifiers.repr, modifiers.one_indexed) {
-        (modifier::WeekdayRepr::Short, _) => [
-            ("Mon", Weekday::Monday),
-            ("Tue", Weekday::Tuesday),
-            ("Wed", Weekday::Wednesday),
-            ("Thu", Weekday::Thursday),
-            ("Fri", Weekday::Friday),
-            ("Sat", Weekday::Saturday),
-            ("Sun", Weekday::Sunday),
-        ]
-        .iter(),
-        (modifier::WeekdayRepr::Long, _) => [
-            ("Monday", Weekday::Monday),
-            ("Tuesday", Weekday::Tuesday),
-            ("Wednesday", Weekday::Wednesday),
-            ("Thursday", Weekday::Thursday),
-            ("Friday", Weekday::Friday),
-            ("Saturday", Weekday::Saturday),
-            ("Sunday", Weekday::Sunday),
-        ]
-        .iter(),
-        (modifier::WeekdayRepr::Sunday, false) => [
-            ("1", Weekday::Monday),
-            ("2", Weekday::Tuesday),
-            ("3", Weekday::Wednesday),
-            ("4", Weekday::Thursday),
-            ("5", Weekday::Friday),
-            ("6", Weekday::Saturday),
-            ("0", Weekday::Sunday),
-        ]
-        .iter(),
-        (modifier::WeekdayRepr::Sunday, true) => [
-            ("2", Weekday::Monday),
-            ("3", Weekday::Tuesday),
-            ("4", Weekday::Wednesday),
-            ("5", Weekday::Thursday),
-            ("6", Weekday::Friday),
-            ("7", Weekday::Saturday),
-            ("1", Weekday::Sunday),
-        ]
-        .iter(),
-        (modifier::WeekdayRepr::Monday, false) => [
-            ("0", Weekday::Monday),
-            ("1", Weekday::Tuesday),
-            ("2", Weekday::Wednesday),
-            ("3", Weekday::Thursday),
-            ("4", Weekday::Friday),
-            ("5", Weekday::Saturday),
-            ("6", Weekday::Sunday),
-        ]
-        .iter(),
-        (modifier::WeekdayRepr::Monday, true) => [
-            ("1", Weekday::Monday),
-            ("2", Weekday::Tuesday),
-            ("3", Weekday::Wednesday),
-            ("4", Weekday::Thursday),
-            ("5", Weekday::Friday),
-            ("6", Weekday::Saturday),
-            ("7", Weekday::Sunday),
-        ]
-        .iter(),
-    })(input)
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, Weekday>> {
+    let ParsedItem(remaining, index) = match (modifiers.repr, modifiers.one_indexed) {
+        (modifier::WeekdayRepr::Short, _) => longest_match_index(
+            locale.short_weekday_names(),
+            input,
+            modifiers.case_sensitive,
+        )?,
+        (modifier::WeekdayRepr::Long, _) => {
+            longest_match_index(locale.long_weekday_names(), input, modifiers.case_sensitive)?
+        }
+        (modifier::WeekdayRepr::Narrow, _) => only_match_index(
+            locale.narrow_weekday_names(),
+            input,
+            modifiers.case_sensitive,
+        )?,
+        (modifier::WeekdayRepr::Sunday, false) => {
+            return first_match(
+                [
+                    ("1", Weekday::Monday),
+                    ("2", Weekday::Tuesday),
+                    ("3", Weekday::Wednesday),
+                    ("4", Weekday::Thursday),
+                    ("5", Weekday::Friday),
+                    ("6", Weekday::Saturday),
+                    ("0", Weekday::Sunday),
+                ]
+                .iter(),
+            )(input)
+        }
+        (modifier::WeekdayRepr::Sunday, true) => {
+            return first_match(
+                [
+                    ("2", Weekday::Monday),
+                    ("3", Weekday::Tuesday),
+                    ("4", Weekday::Wednesday),
+                    ("5", Weekday::Thursday),
+                    ("6", Weekday::Friday),
+                    ("7", Weekday::Saturday),
+                    ("1", Weekday::Sunday),
+                ]
+                .iter(),
+            )(input)
+        }
+        (modifier::WeekdayRepr::Monday, false) => {
+            return first_match(
+                [
+                    ("0", Weekday::Monday),
+                    ("1", Weekday::Tuesday),
+                    ("2", Weekday::Wednesday),
+                    ("3", Weekday::Thursday),
+                    ("4", Weekday::Friday),
+                    ("5", Weekday::Saturday),
+                    ("6", Weekday::Sunday),
+                ]
+                .iter(),
+            )(input)
+        }
+        (modifier::WeekdayRepr::Monday, true) => {
+            return first_match(
+                [
+                    ("1", Weekday::Monday),
+                    ("2", Weekday::Tuesday),
+                    ("3", Weekday::Wednesday),
+                    ("4", Weekday::Thursday),
+                    ("5", Weekday::Friday),
+                    ("6", Weekday::Saturday),
+                    ("7", Weekday::Sunday),
+                ]
+                .iter(),
+            )(input)
+        }
+    };
+    Some(ParsedItem(remaining, WEEKDAYS[index]))
 }
 
 /// Parse the "ordinal" component of a `Date`.
-pub(crate) fn parse_ordinal(
-    input: &[u8],
+pub(crate) fn parse_ordinal<'a>(
+    input: &'a [u8],
     modifiers: modifier::Ordinal,
-) -> Option<ParsedItem<'_, NonZeroU16>> {
-    exactly_n_digits_padded(3, modifiers.padding)(input)
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, NonZeroU16>> {
+    let ParsedItem(input, ordinal) =
+        exactly_n_digits_padded::<NonZeroU16>(3, modifiers.padding)(input)?;
+    let input = parse_suffix(input, ordinal.get(), modifiers.suffix, locale)?;
+    Some(ParsedItem(input, ordinal))
 }
 
 /// Parse the "day" component of a `Date`.
-pub(crate) fn parse_day(
-    input: &[u8],
+pub(crate) fn parse_day<'a>(
+    input: &'a [u8],
     modifiers: modifier::Day,
-) -> Option<ParsedItem<'_, NonZeroU8>> {
-    exactly_n_digits_padded(2, modifiers.padding)(input)
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, NonZeroU8>> {
+    let ParsedItem(input, day) = exactly_n_digits_padded::<NonZeroU8>(2, modifiers.padding)(input)?;
+    let input = parse_suffix(input, day.get().into(), modifiers.suffix, locale)?;
+    Some(ParsedItem(input, day))
+}
+
+/// Parse the "quarter" component of a `Date`.
+pub(crate) fn parse_quarter<'a>(
+    input: &'a [u8],
+    modifiers: modifier::Quarter,
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, NonZeroU8>> {
+    parse_single_digit(input, 4, modifiers.padding, modifiers.suffix, locale)
+}
+
+/// Parse the "week of month" component of a `Date`.
+pub(crate) fn parse_week_of_month<'a>(
+    input: &'a [u8],
+    modifiers: modifier::WeekOfMonth,
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, NonZeroU8>> {
+    parse_single_digit(input, 6, modifiers.padding, modifiers.suffix, locale)
+}
+
+/// Parse the "week of quarter" component of a `Date`.
+pub(crate) fn parse_week_of_quarter<'a>(
+    input: &'a [u8],
+    modifiers: modifier::WeekOfQuarter,
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, NonZeroU8>> {
+    let ParsedItem(input, week) =
+        exactly_n_digits_padded::<NonZeroU8>(2, modifiers.padding)(input)?;
+    if week.get() > 14 {
+        return None;
+    }
+    let input = parse_suffix(input, week.get().into(), modifiers.suffix, locale)?;
+    Some(ParsedItem(input, week))
+}
+
+/// Parse the "weekday in month" component of a `Date`.
+pub(crate) fn parse_weekday_in_month<'a>(
+    input: &'a [u8],
+    modifiers: modifier::WeekdayInMonth,
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, NonZeroU8>> {
+    parse_single_digit(input, 5, modifiers.padding, modifiers.suffix, locale)
+}
+
+/// Parse a single-digit number between one and the maximum, followed by its suffix.
+fn parse_single_digit<'a>(
+    input: &'a [u8],
+    max: u8,
+    padding: modifier::Padding,
+    suffix: modifier::Suffix,
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, NonZeroU8>> {
+    let ParsedItem(input, value) = exactly_n_digits_padded::<NonZeroU8>(1, padding)(input)?;
+    if value.get() > max {
+        return None;
+    }
+    let input = parse_suffix(input, value.get().into(), suffix, locale)?;
+    Some(ParsedItem(input, value))
+}
+
+/// Parse the "century" component of a `Date`. The returned boolean indicates whether the year is
+/// negative, which is needed when the century is zero.
+pub(crate) fn parse_century<'a>(
+    input: &'a [u8],
+    modifiers: modifier::Century,
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, (i16, bool)>> {
+    let ParsedItem(input, sign) = opt(sign)(input);
+    // As with the year, more than two digits are only permitted when the sign is present. As a
+    // sign is also present on all negative years, the century is only wider than two digits when
+    // it does not begin with padding, matching what is formatted.
+    let max_digits = if cfg!(feature = "large-dates")
+        && sign.is_some()
+        && matches!(input.first(), Some(b'1'..=b'9'))
+    {
+        4
+    } else {
+        2
+    };
+    let ParsedItem(input, century) =
+        n_to_m_digits_padded::<u16>(2, max_digits, modifiers.padding)(input)?;
+    let input = parse_suffix(input, century, modifiers.suffix, locale)?;
+    match sign {
+        Some(b'-') => Some(ParsedItem(input, (-(century as i16), true))),
+        _ => Some(ParsedItem(input, (century as i16, false))),
+    }
+}
+
+/// Consume the suffix following a number, as provided by the locale for the parsed value. ASCII
+/// characters in the suffix are matched case-insensitively.
+fn parse_suffix<'a>(
+    input: &'a [u8],
+    value: u16,
+    suffix: modifier::Suffix,
+    locale: &dyn Locale,
+) -> Option<&'a [u8]> {
+    match suffix {
+        modifier::Suffix::None => Some(input),
+        modifier::Suffix::Ordinal => {
+            longest_match(&[(locale.ordinal_suffix(value), ())], input, false)
+                .map(ParsedItem::unwrap)
+        }
+    }
 }
 // endregion date components
 
@@ -204,15 +355,37 @@ pub(crate) fn parse_second(
 }
 
 /// Parse the "period" component of a `Time`. Required if the hour is on a 12-hour clock.
-pub(crate) fn parse_period(
-    input: &[u8],
+pub(crate) fn parse_period<'a>(
+    input: &'a [u8],
     modifiers: modifier::Period,
-) -> Option<ParsedItem<'_, Period>> {
-    first_match(if modifiers.is_uppercase {
-        [("AM", Period::Am), ("PM", Period::Pm)].iter()
-    } else {
-        [("am", Period::Am), ("pm", Period::Pm)].iter()
-    })(input)
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, Period>> {
+    let markers = locale.period_markers(modifiers.is_uppercase);
+    Some(
+        longest_match_index(markers, input, modifiers.case_sensitive)?.map(|index| {
+            if index == 0 {
+                Period::Am
+            } else {
+                Period::Pm
+            }
+        }),
+    )
+}
+
+/// Parse the "era" component of a `Date`. The value is whether the era is before year 1.
+pub(crate) fn parse_era<'a>(
+    input: &'a [u8],
+    modifiers: modifier::Era,
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, bool>> {
+    Some(
+        longest_match_index(
+            locale.era_names(modifiers.repr),
+            input,
+            modifiers.case_sensitive,
+        )?
+        .map(|index| index == 0),
+    )
 }
 
 /// Parse the "subsecond" component of a `Time`.
@@ -243,6 +416,13 @@ pub(crate) fn parse_subsecond(
 
             ParsedItem(input, value)
         }
+        modifier::SubsecondDigits::Range { .. } => {
+            let (min_digits, max_digits) = modifiers.digits.bounds();
+            let ParsedItem(remaining, value) =
+                n_to_m_digits::<u32>(min_digits, max_digits)(input)?;
+            let digits = (input.len() - remaining.len()) as u32;
+            ParsedItem(remaining, value * 10_u32.pow(9 - digits))
+        }
     })
 }
 // endregion time components
@@ -277,4 +457,180 @@ pub(crate) fn parse_offset_second(
 ) -> Option<ParsedItem<'_, u8>> {
     exactly_n_digits_padded(2, modifiers.padding)(input)
 }
+
+/// Parse the "abbreviation" component of a `UtcOffset`.
+pub(crate) fn parse_offset_abbreviation<'a>(
+    input: &'a [u8],
+    modifiers: modifier::OffsetAbbreviation,
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, UtcOffset>> {
+    longest_match(
+        locale.offset_abbreviations(),
+        input,
+        modifiers.case_sensitive,
+    )
+}
+
+/// Parse the "offset" component, which is the `UtcOffset` as a whole.
+pub(crate) fn parse_offset(
+    input: &[u8],
+    modifiers: modifier::Offset,
+) -> Option<ParsedItem<'_, UtcOffset>> {
+    if modifiers.zulu {
+        if let Some(ParsedItem(input, ())) = ascii_char_ignore_case(b'Z')(input) {
+            return Some(ParsedItem(input, UtcOffset::UTC));
+        }
+    }
+
+    let ParsedItem(input, sign) = sign(input)?;
+    let ParsedItem(input, hour) = exactly_n_digits::<u8>(2)(input)?;
+    if hour > 23 {
+        return None;
+    }
+
+    let minute = match modifiers.separator {
+        modifier::OffsetSeparator::Colon => ascii_char(b':')(input).map(|item| item.0),
+        modifier::OffsetSeparator::None => Some(input),
+        modifier::OffsetSeparator::Optional => Some(opt(ascii_char(b':'))(input).0),
+    }
+    .and_then(|input| exactly_n_digits::<u8>(2)(input))
+    .and_then(|item| item.flat_map(|minute| if minute < 60 { Some(minute) } else { None }));
+    let ParsedItem(input, minute) = match minute {
+        Some(item) => item,
+        None if modifiers.minute_is_optional => ParsedItem(input, 0),
+        None => return None,
+    };
+
+    let (hour, minute) = if sign == b'-' {
+        (-(hour as i8), -(minute as i8))
+    } else {
+        (hour as i8, minute as i8)
+    };
+    UtcOffset::from_hms(hour, minute, 0)
+        .ok()
+        .map(|offset| ParsedItem(input, offset))
+}
 // endregion offset components
+
+// region: duration components
+/// Parse a component of a `Duration` with the given number of units in the next larger unit. The
+/// value is only bounded if the remainder is requested.
+fn parse_duration_unit(
+    input: &[u8],
+    padding: modifier::Padding,
+    repr: modifier::DurationRepr,
+    units_in_next: u64,
+) -> Option<ParsedItem<'_, u64>> {
+    match repr {
+        modifier::DurationRepr::Total => n_to_m_digits_padded(2, 20, padding)(input),
+        modifier::DurationRepr::Remainder => {
+            exactly_n_digits_padded(2, padding)(input)?.flat_map(|value| {
+                if value < units_in_next {
+                    Some(value)
+                } else {
+                    None
+                }
+            })
+        }
+    }
+}
+
+/// Parse the "day" component of a `Duration`.
+pub(crate) fn parse_duration_day(
+    input: &[u8],
+    _: modifier::DurationDay,
+) -> Option<ParsedItem<'_, u64>> {
+    n_to_m_digits(1, 20)(input)
+}
+
+/// Parse the "hour" component of a `Duration`.
+pub(crate) fn parse_duration_hour(
+    input: &[u8],
+    modifiers: modifier::DurationHour,
+) -> Option<ParsedItem<'_, u64>> {
+    parse_duration_unit(input, modifiers.padding, modifiers.repr, 24)
+}
+
+/// Parse the "minute" component of a `Duration`.
+pub(crate) fn parse_duration_minute(
+    input: &[u8],
+    modifiers: modifier::DurationMinute,
+) -> Option<ParsedItem<'_, u64>> {
+    parse_duration_unit(input, modifiers.padding, modifiers.repr, 60)
+}
+
+/// Parse the "second" component of a `Duration`.
+pub(crate) fn parse_duration_second(
+    input: &[u8],
+    modifiers: modifier::DurationSecond,
+) -> Option<ParsedItem<'_, u64>> {
+    parse_duration_unit(input, modifiers.padding, modifiers.repr, 60)
+}
+
+/// Parse the "subsecond" component of a `Duration`.
+pub(crate) fn parse_duration_subsecond(
+    input: &[u8],
+    modifiers: modifier::DurationSubsecond,
+) -> Option<ParsedItem<'_, u32>> {
+    parse_subsecond(
+        input,
+        modifier::Subsecond {
+            digits: modifiers.digits,
+            rounding: modifier::SubsecondRounding::Truncate,
+        },
+    )
+}
+
+/// Parse the "sign" component of a `Duration`, returning whether the duration is negative.
+pub(crate) fn parse_duration_sign(
+    input: &[u8],
+    modifiers: modifier::DurationSign,
+) -> Option<ParsedItem<'_, bool>> {
+    match opt(sign)(input) {
+        ParsedItem(input, Some(sign)) => Some(ParsedItem(input, sign == b'-')),
+        ParsedItem(_, None) if modifiers.sign_is_mandatory => None,
+        ParsedItem(input, None) => Some(ParsedItem(input, false)),
+    }
+}
+// endregion duration components
+
+// region: other components
+/// Parse the "unix timestamp" component, returning the number of nanoseconds since the Unix
+/// epoch.
+pub(crate) fn parse_unix_timestamp(
+    input: &[u8],
+    modifiers: modifier::UnixTimestamp,
+) -> Option<ParsedItem<'_, i128>> {
+    let ParsedItem(input, sign) = opt(sign)(input);
+    let ParsedItem(input, value) = n_to_m_digits::<i128>(1, u8::MAX)(input)?;
+    let nanoseconds = match modifiers.precision {
+        modifier::UnixTimestampPrecision::Second => value.checked_mul(1_000_000_000)?,
+        modifier::UnixTimestampPrecision::Millisecond => value.checked_mul(1_000_000)?,
+        modifier::UnixTimestampPrecision::Microsecond => value.checked_mul(1_000)?,
+        modifier::UnixTimestampPrecision::Nanosecond => value,
+    };
+    match sign {
+        Some(b'-') => Some(ParsedItem(input, -nanoseconds)),
+        None if modifiers.sign_is_mandatory => None,
+        _ => Some(ParsedItem(input, nanoseconds)),
+    }
+}
+
+/// Consume the number of bytes indicated by the "ignore" component.
+pub(crate) fn parse_ignore(
+    input: &[u8],
+    modifiers: modifier::Ignore,
+) -> Option<ParsedItem<'_, ()>> {
+    Some(ParsedItem(input.get(modifiers.count as usize..)?, ()))
+}
+
+/// Parse the "end" component, which only matches when there is no remaining input.
+#[allow(clippy::missing_const_for_fn)] // const fn from 1.47
+pub(crate) fn parse_end(input: &[u8], _: modifier::End) -> Option<ParsedItem<'_, ()>> {
+    if input.is_empty() {
+        Some(ParsedItem(input, ()))
+    } else {
+        None
+    }
+}
+// endregion other components
@@ -0,0 +1,85 @@
+//! Parsing for the unit-suffixed representation of a [`Duration`](crate::Duration), such as
+//! `2d 3h 4m 5.006s` or `1h2m3.5s`.
+
+use crate::error::ParseFromDescription::{self, InvalidComponent};
+use crate::format_description::modifier;
+use crate::parsing::combinator::{
+    ascii_char, longest_match, n_to_m_digits, one_or_more, opt, sign,
+};
+use crate::parsing::component::parse_subsecond;
+use crate::parsing::{Parsed, ParsedItem};
+
+/// Nanoseconds per second.
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// The recognized unit suffixes, along with the number of nanoseconds in each unit.
+const UNITS: [(&str, u128); 9] = [
+    ("d", 86_400 * NANOS_PER_SECOND),
+    ("h", 3_600 * NANOS_PER_SECOND),
+    ("m", 60 * NANOS_PER_SECOND),
+    ("s", NANOS_PER_SECOND),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("\u{b5}s", 1_000),
+    ("\u{3bc}s", 1_000),
+    ("ns", 1),
+];
+
+/// Parse a number followed by a unit, returning the value in nanoseconds. The number may have a
+/// decimal fraction.
+fn unit(input: &[u8]) -> Option<ParsedItem<'_, u128>> {
+    let ParsedItem(input, value) = n_to_m_digits::<u64>(1, 20)(input)?;
+    let ParsedItem(input, fraction) = opt(|input| {
+        let ParsedItem(input, ()) = ascii_char(b'.')(input)?;
+        parse_subsecond(
+            input,
+            modifier::Subsecond {
+                digits: modifier::SubsecondDigits::OneOrMore,
+                rounding: modifier::SubsecondRounding::Truncate,
+            },
+        )
+    })(input);
+    let ParsedItem(input, nanos_per_unit) = longest_match(&UNITS, input, true)?;
+    let fraction = fraction.unwrap_or(0) as u128 * nanos_per_unit / NANOS_PER_SECOND;
+    Some(ParsedItem(input, value as u128 * nanos_per_unit + fraction))
+}
+
+/// Parse a duration as a sequence of numbers with unit suffixes, optionally separated by spaces
+/// and preceded by a sign. A lone `0` is also accepted.
+pub(crate) fn parse_duration<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<&'a [u8], ParseFromDescription> {
+    let ParsedItem(input, duration_sign) = opt(sign)(input);
+
+    let (input, nanoseconds) = match unit(input) {
+        Some(ParsedItem(mut input, mut nanoseconds)) => {
+            loop {
+                let remaining = opt(one_or_more(ascii_char(b' ')))(input).0;
+                match unit(remaining) {
+                    Some(ParsedItem(remaining, value)) => {
+                        input = remaining;
+                        nanoseconds = nanoseconds
+                            .checked_add(value)
+                            .ok_or(InvalidComponent("duration"))?;
+                    }
+                    None => break,
+                }
+            }
+            (input, nanoseconds)
+        }
+        None => match ascii_char(b'0')(input) {
+            Some(ParsedItem(input, ())) => (input, 0),
+            None => return Err(InvalidComponent("duration")),
+        },
+    };
+
+    let seconds = nanoseconds / NANOS_PER_SECOND;
+    if seconds > u64::MAX as u128 {
+        return Err(InvalidComponent("duration"));
+    }
+    parsed.duration_is_negative = Some(duration_sign == Some(b'-'));
+    parsed.duration_seconds = Some(seconds as u64);
+    parsed.duration_subsecond = Some((nanoseconds % NANOS_PER_SECOND) as u32);
+    Ok(input)
+}
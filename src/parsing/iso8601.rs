@@ -0,0 +1,389 @@
+//! Helpers for implementing parsing for ISO 8601.
+
+use core::num::{NonZeroU16, NonZeroU8};
+
+use crate::error::ParseFromDescription::{self, InvalidComponent, InvalidLiteral};
+use crate::format_description::locale::English;
+use crate::format_description::modifier;
+use crate::parsing::combinator::{ascii_char, exactly_n_digits, n_to_m_digits, opt, sign};
+use crate::parsing::component::{parse_subsecond, parse_weekday};
+use crate::parsing::{Parsed, ParsedItem};
+
+/// Whether the basic or extended format is being parsed. ISO 8601 requires that all parts of a
+/// value use the same format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ExtendedKind {
+    /// The format has not yet been determined.
+    Unknown,
+    /// The basic format, without separators.
+    Basic,
+    /// The extended format, with separators.
+    Extended,
+}
+
+impl ExtendedKind {
+    /// Require the basic format, returning `None` if the extended format was already determined.
+    fn coerce_basic(&mut self) -> Option<()> {
+        match self {
+            Self::Unknown | Self::Basic => {
+                *self = Self::Basic;
+                Some(())
+            }
+            Self::Extended => None,
+        }
+    }
+
+    /// Require the extended format, returning `None` if the basic format was already determined.
+    fn coerce_extended(&mut self) -> Option<()> {
+        match self {
+            Self::Unknown | Self::Extended => {
+                *self = Self::Extended;
+                Some(())
+            }
+            Self::Basic => None,
+        }
+    }
+}
+
+/// Nanoseconds per second.
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// Parse a decimal fraction, including the leading comma or full stop. The value is returned in
+/// billionths.
+fn fraction(input: &[u8]) -> Option<ParsedItem<'_, u32>> {
+    let input = match input {
+        [b'.', remaining @ ..] | [b',', remaining @ ..] => remaining,
+        _ => return None,
+    };
+    parse_subsecond(
+        input,
+        modifier::Subsecond {
+            digits: modifier::SubsecondDigits::OneOrMore,
+            rounding: modifier::SubsecondRounding::Truncate,
+        },
+    )
+}
+
+/// Parse exactly two digits, ensuring the value is within the provided range.
+fn two_digits_in(input: &[u8], min: u8, max: u8) -> Option<ParsedItem<'_, u8>> {
+    exactly_n_digits::<u8>(2)(input)?.flat_map(|value| {
+        if value >= min && value <= max {
+            Some(value)
+        } else {
+            None
+        }
+    })
+}
+
+/// Assign the minute, second, and subsecond from the fraction of a larger unit. `nanos_per_unit`
+/// is the number of nanoseconds in that unit, and `fraction` is in billionths.
+fn assign_fraction(parsed: &mut Parsed, fraction: u32, nanos_per_unit: u64) {
+    let nanos = (fraction as u128 * nanos_per_unit as u128 / NANOS_PER_SECOND as u128) as u64;
+    let seconds = nanos / NANOS_PER_SECOND;
+    if parsed.minute.is_none() {
+        parsed.minute = Some((seconds / 60) as u8);
+    }
+    parsed.second = Some((seconds % 60) as u8);
+    parsed.subsecond = Some((nanos % NANOS_PER_SECOND) as u32);
+}
+
+/// Parse a year. Four digits are required unless a sign is present, in which case the year may be
+/// in the expanded representation of up to six digits. Years of more than four significant digits
+/// are only accepted when the `large-dates` feature is enabled.
+fn year(input: &[u8]) -> Option<ParsedItem<'_, i32>> {
+    match sign(input) {
+        Some(ParsedItem(input, sign)) => {
+            let ParsedItem(input, year) = match n_to_m_digits::<u32>(4, 6)(input) {
+                Some(item) if cfg!(feature = "large-dates") || item.1 < 10_000 => item,
+                // In the basic format, the digits may belong to the month or week.
+                _ => exactly_n_digits::<u32>(4)(input)?,
+            };
+            Some(ParsedItem(
+                input,
+                if sign == b'-' {
+                    -(year as i32)
+                } else {
+                    year as i32
+                },
+            ))
+        }
+        None => Some(exactly_n_digits::<u32>(4)(input)?.map(|year| year as i32)),
+    }
+}
+
+/// Parse the month and day of a calendar date.
+fn calendar_date(
+    input: &[u8],
+    extended_kind: ExtendedKind,
+) -> Result<ParsedItem<'_, (NonZeroU8, NonZeroU8)>, ParseFromDescription> {
+    let ParsedItem(mut input, month) =
+        two_digits_in(input, 1, 12).ok_or(InvalidComponent("month"))?;
+    if extended_kind == ExtendedKind::Extended {
+        input = ascii_char(b'-')(input).ok_or(InvalidLiteral)?.unwrap();
+    }
+    let ParsedItem(input, day) = two_digits_in(input, 1, 31).ok_or(InvalidComponent("day"))?;
+    Ok(ParsedItem(
+        input,
+        (
+            NonZeroU8::new(month).ok_or(InvalidComponent("month"))?,
+            NonZeroU8::new(day).ok_or(InvalidComponent("day"))?,
+        ),
+    ))
+}
+
+/// Parse a date in the basic or extended format. This may be a calendar date, an ISO week date,
+/// or an ordinal date.
+pub(crate) fn parse_date<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+    extended_kind: &mut ExtendedKind,
+) -> Result<&'a [u8], ParseFromDescription> {
+    let ParsedItem(input, year) = year(input).ok_or(InvalidComponent("year"))?;
+    let input = match ascii_char(b'-')(input) {
+        Some(ParsedItem(input, ())) => {
+            extended_kind.coerce_extended().ok_or(InvalidLiteral)?;
+            input
+        }
+        None => {
+            extended_kind.coerce_basic().ok_or(InvalidLiteral)?;
+            input
+        }
+    };
+
+    if let Some(ParsedItem(input, ())) = ascii_char(b'W')(input) {
+        let ParsedItem(mut input, week) =
+            two_digits_in(input, 1, 53).ok_or(InvalidComponent("week number"))?;
+        if *extended_kind == ExtendedKind::Extended {
+            input = ascii_char(b'-')(input).ok_or(InvalidLiteral)?.unwrap();
+        }
+        let input = parse_weekday(
+            input,
+            modifier::Weekday {
+                repr: modifier::WeekdayRepr::Monday,
+                one_indexed: true,
+                case_sensitive: true,
+            },
+            &English,
+        )
+        .ok_or(InvalidComponent("weekday"))?
+        .assign_value_to(&mut parsed.weekday);
+        parsed.iso_year = Some(year);
+        parsed.iso_week_number = NonZeroU8::new(week);
+        return Ok(input);
+    }
+
+    let calendar_error = match calendar_date(input, *extended_kind) {
+        Ok(ParsedItem(input, (month, day))) => {
+            parsed.year = Some(year);
+            parsed.month = Some(month);
+            parsed.day = Some(day);
+            return Ok(input);
+        }
+        Err(err) => err,
+    };
+
+    match exactly_n_digits::<NonZeroU16>(3)(input) {
+        Some(ParsedItem(input, ordinal)) if ordinal.get() <= 366 => {
+            parsed.year = Some(year);
+            parsed.ordinal = Some(ordinal);
+            Ok(input)
+        }
+        _ => Err(calendar_error),
+    }
+}
+
+/// Parse a time in the basic or extended format, including the leading `T`. The `T` may be omitted
+/// if there is no date present. Components after the hour may be omitted, and the smallest unit
+/// present may have a decimal fraction.
+pub(crate) fn parse_time<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+    extended_kind: &mut ExtendedKind,
+    date_is_present: bool,
+) -> Result<&'a [u8], ParseFromDescription> {
+    let input = match ascii_char(b'T')(input) {
+        Some(ParsedItem(input, ())) => input,
+        None if date_is_present => return Err(InvalidLiteral),
+        None => input,
+    };
+
+    let input = two_digits_in(input, 0, 23)
+        .ok_or(InvalidComponent("hour"))?
+        .assign_value_to(&mut parsed.hour_24);
+    if let Some(ParsedItem(input, fraction)) = fraction(input) {
+        assign_fraction(parsed, fraction, 3_600 * NANOS_PER_SECOND);
+        return Ok(input);
+    }
+
+    let input = match parse_separated(input, extended_kind, 0, 59, "minute")? {
+        Some(ParsedItem(input, minute)) => {
+            parsed.minute = Some(minute);
+            input
+        }
+        None => {
+            parsed.minute = Some(0);
+            return Ok(input);
+        }
+    };
+    if let Some(ParsedItem(input, fraction)) = fraction(input) {
+        assign_fraction(parsed, fraction, 60 * NANOS_PER_SECOND);
+        return Ok(input);
+    }
+
+    let input = match parse_separated(input, extended_kind, 0, 60, "second")? {
+        // Leap seconds are permitted by ISO 8601.
+        Some(ParsedItem(input, second)) => {
+            parsed.assign_second_permitting_leap(second);
+            input
+        }
+        None => return Ok(input),
+    };
+    match fraction(input) {
+        Some(item) => Ok(item.assign_value_to(&mut parsed.subsecond)),
+        None => Ok(input),
+    }
+}
+
+/// Parse an optional two-digit component that is preceded by a colon in the extended format.
+/// `Ok(None)` is returned if the component is not present.
+fn parse_separated<'a>(
+    input: &'a [u8],
+    extended_kind: &mut ExtendedKind,
+    min: u8,
+    max: u8,
+    component: &'static str,
+) -> Result<Option<ParsedItem<'a, u8>>, ParseFromDescription> {
+    if let Some(ParsedItem(input, ())) = ascii_char(b':')(input) {
+        extended_kind.coerce_extended().ok_or(InvalidLiteral)?;
+        return Ok(Some(
+            two_digits_in(input, min, max).ok_or(InvalidComponent(component))?,
+        ));
+    }
+    if *extended_kind == ExtendedKind::Extended {
+        return Ok(None);
+    }
+    match two_digits_in(input, min, max) {
+        Some(item) => {
+            extended_kind.coerce_basic().ok_or(InvalidLiteral)?;
+            Ok(Some(item))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Parse a UTC offset in the basic or extended format. The minute may be omitted.
+pub(crate) fn parse_offset<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+    extended_kind: &mut ExtendedKind,
+) -> Result<&'a [u8], ParseFromDescription> {
+    if let Some(ParsedItem(input, ())) = ascii_char(b'Z')(input) {
+        parsed.offset_hour = Some(0);
+        parsed.offset_minute = Some(0);
+        parsed.offset_second = Some(0);
+        parsed.offset_is_negative = Some(false);
+        return Ok(input);
+    }
+
+    let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset_hour"))?;
+    let input = two_digits_in(input, 0, 23)
+        .ok_or(InvalidComponent("offset_hour"))?
+        .assign_value_to_with(&mut parsed.offset_hour, |offset_hour| {
+            if offset_sign == b'-' {
+                -(offset_hour as i8)
+            } else {
+                offset_hour as _
+            }
+        });
+    let input = match parse_separated(input, extended_kind, 0, 59, "offset_minute")? {
+        Some(item) => item.assign_value_to(&mut parsed.offset_minute),
+        None => {
+            parsed.offset_minute = Some(0);
+            input
+        }
+    };
+    parsed.offset_second = Some(0);
+    parsed.offset_is_negative = Some(offset_sign == b'-');
+
+    Ok(input)
+}
+
+/// The units of a duration in the order they must appear, along with whether they follow the
+/// time designator, the name of the component, and the number of seconds in the unit. Years and
+/// months do not have a fixed length.
+const DURATION_UNITS: [(bool, u8, &str, Option<u64>); 7] = [
+    (false, b'Y', "duration year", None),
+    (false, b'M', "duration month", None),
+    (false, b'W', "duration week", Some(604_800)),
+    (false, b'D', "duration day", Some(86_400)),
+    (true, b'H', "duration hour", Some(3_600)),
+    (true, b'M', "duration minute", Some(60)),
+    (true, b'S', "duration second", Some(1)),
+];
+
+/// Parse a duration, such as `P3DT4H` or `-PT1.5S`. The smallest unit present may have a decimal
+/// fraction. Years and months are rejected, as their length is not fixed.
+pub(crate) fn parse_duration<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<&'a [u8], ParseFromDescription> {
+    let ParsedItem(input, duration_sign) = opt(sign)(input);
+    let mut input = ascii_char(b'P')(input).ok_or(InvalidLiteral)?.unwrap();
+
+    let mut seconds = 0_u64;
+    let mut nanoseconds = 0;
+    let mut next_unit = 0;
+    let mut is_time = false;
+    let mut unit_is_present = false;
+    loop {
+        if !is_time {
+            if let Some(ParsedItem(remaining, ())) = ascii_char(b'T')(input) {
+                input = remaining;
+                is_time = true;
+                unit_is_present = false;
+            }
+        }
+
+        let ParsedItem(remaining, value) = match n_to_m_digits::<u64>(1, 20)(input) {
+            Some(item) => item,
+            None => break,
+        };
+        let ParsedItem(remaining, fraction) = opt(fraction)(remaining);
+        let (index, &(_, _, name, unit_seconds)) = DURATION_UNITS
+            .iter()
+            .enumerate()
+            .skip(next_unit)
+            .find(|(_, &(unit_is_time, designator, ..))| {
+                unit_is_time == is_time && remaining.first() == Some(&designator)
+            })
+            .ok_or(InvalidComponent("duration"))?;
+        let unit_seconds = unit_seconds.ok_or(InvalidComponent(name))?;
+
+        seconds = value
+            .checked_mul(unit_seconds)
+            .and_then(|value| value.checked_add(seconds))
+            .ok_or(InvalidComponent(name))?;
+        input = &remaining[1..];
+        next_unit = index + 1;
+        unit_is_present = true;
+
+        // Only the smallest unit may have a fraction, so nothing can follow it.
+        if let Some(fraction) = fraction {
+            let nanos = fraction as u64 * unit_seconds;
+            seconds = seconds
+                .checked_add(nanos / NANOS_PER_SECOND)
+                .ok_or(InvalidComponent(name))?;
+            nanoseconds = (nanos % NANOS_PER_SECOND) as u32;
+            break;
+        }
+    }
+
+    if !unit_is_present {
+        return Err(InvalidComponent("duration"));
+    }
+
+    parsed.duration_is_negative = Some(duration_sign == Some(b'-'));
+    parsed.duration_seconds = Some(seconds);
+    parsed.duration_subsecond = Some(nanoseconds);
+    Ok(input)
+}
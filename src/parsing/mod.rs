@@ -2,11 +2,13 @@
 
 pub(crate) mod combinator;
 mod component;
+pub(crate) mod duration;
+mod iso8601;
 pub(crate) mod parsable;
 mod parsed;
 mod shim;
 
-pub use parsed::Parsed;
+pub use parsed::{LeapSecondPolicy, Parsed};
 
 /// An item that has been parsed. Represented as a `(remaining, value)` pair.
 #[derive(Debug, Clone)]
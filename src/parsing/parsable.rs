@@ -3,12 +3,29 @@
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 use core::convert::TryInto;
+#[cfg(feature = "std")]
+use std::io::{self, BufRead};
 
 use crate::error::TryFromParsed;
+use crate::format_description::locale::{English, Locale};
+#[cfg(feature = "alloc")]
+use crate::format_description::OwnedFormatItem;
 use crate::format_description::{well_known, FormatItem};
-use crate::parsing::shim::SliceStripPrefix;
+use crate::parsing::parsed::check_weekday;
 use crate::parsing::{Parsed, ParsedItem};
-use crate::{error, Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+use crate::{error, Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+/// The number of bytes that are read from a reader before giving up on finding the end of a
+/// value. This is far longer than any value formatted by the crate.
+#[cfg(feature = "std")]
+const READER_LOOKAHEAD: usize = 1024;
+
+/// The number of bytes that must be buffered after a value before it is accepted, unless the end
+/// of the reader has been reached. With fewer, an optional section, alternative or variable-width
+/// component may have been cut off at the end of the buffer, so the value could continue past it.
+/// This is longer than any single component.
+#[cfg(feature = "std")]
+const READER_MARGIN: usize = 16;
 
 /// Seal the trait to prevent downstream users from implementing it, while still allowing it to
 /// exist in generic bounds.
@@ -27,6 +44,19 @@ pub(crate) mod sealed {
             &self,
             input: &'a [u8],
             parsed: &mut Parsed,
+        ) -> Result<&'a [u8], error::Parse> {
+            self.parse_into_with_locale(input, parsed, &English)
+        }
+
+        /// Parse the item into the provided [`Parsed`] struct using the names provided by the
+        /// locale.
+        ///
+        /// Items that do not contain any localized components ignore the locale.
+        fn parse_into_with_locale<'a>(
+            &self,
+            input: &'a [u8],
+            parsed: &mut Parsed,
+            locale: &dyn Locale,
         ) -> Result<&'a [u8], error::Parse>;
 
         /// Parse the item into a new [`Parsed`] struct.
@@ -34,14 +64,83 @@ pub(crate) mod sealed {
         /// This method can only be used to parse a complete value of a type. If any characters
         /// remain after parsing, an error will be returned.
         fn parse(&self, input: &[u8]) -> Result<Parsed, error::Parse> {
+            self.parse_with_locale(input, &English)
+        }
+
+        /// Parse the item into a new [`Parsed`] struct using the names provided by the locale.
+        ///
+        /// This method can only be used to parse a complete value of a type. If any characters
+        /// remain after parsing, an error will be returned.
+        fn parse_with_locale(
+            &self,
+            input: &[u8],
+            locale: &dyn Locale,
+        ) -> Result<Parsed, error::Parse> {
             let mut parsed = Parsed::new();
-            if self.parse_into(input, &mut parsed)?.is_empty() {
+            if self
+                .parse_into_with_locale(input, &mut parsed, locale)?
+                .is_empty()
+            {
                 Ok(parsed)
             } else {
                 Err(error::Parse::UnexpectedTrailingCharacters)
             }
         }
 
+        /// Parse the item from the start of the input into a new [`Parsed`] struct. The remaining
+        /// input is returned alongside it.
+        fn parse_prefix<'a>(&self, input: &'a str) -> Result<(Parsed, &'a str), error::Parse> {
+            let mut parsed = Parsed::new();
+            let remaining = self.parse_into(input.as_bytes(), &mut parsed)?;
+            let remaining = input
+                .get(input.len() - remaining.len()..)
+                .ok_or(error::Parse::UnexpectedPartialCharacter)?;
+            Ok((parsed, remaining))
+        }
+
+        /// Parse the item from the start of the reader into the provided [`Parsed`] struct. Only
+        /// the bytes that were parsed are consumed from the reader.
+        ///
+        /// `parsed` is only mutated if parsing succeeds. If an error is returned, it is
+        /// unspecified how many bytes were consumed from the reader.
+        #[cfg(feature = "std")]
+        fn parse_into_from_reader(
+            &self,
+            reader: &mut dyn BufRead,
+            parsed: &mut Parsed,
+        ) -> io::Result<()> {
+            // Bytes that have been consumed from the reader, followed by its current buffer.
+            let mut input = Vec::new();
+            loop {
+                let buffer = reader.fill_buf()?;
+                let (consumed_len, buffer_len) = (input.len(), buffer.len());
+                input.extend_from_slice(buffer);
+
+                // A value that ends close to the end of the buffer may continue past it, so more
+                // input is needed to know where it ends.
+                let is_final = buffer_len == 0 || input.len() >= READER_LOOKAHEAD;
+                let mut attempt = *parsed;
+                match self.parse_into(&input, &mut attempt) {
+                    Ok(remaining) if is_final || remaining.len() >= READER_MARGIN => {
+                        let value_len = input.len() - remaining.len();
+                        if value_len < consumed_len {
+                            return Err(io::Error::new(
+                                io::ErrorKind::InvalidData,
+                                "the value ended in input that was already consumed",
+                            ));
+                        }
+                        reader.consume(value_len - consumed_len);
+                        *parsed = attempt;
+                        return Ok(());
+                    }
+                    Err(err) if is_final => {
+                        return Err(io::Error::new(io::ErrorKind::InvalidData, err));
+                    }
+                    _ => reader.consume(buffer_len),
+                }
+            }
+        }
+
         /// Parse a [`Date`] from the format description.
         fn parse_date(&self, input: &[u8]) -> Result<Date, error::Parse> {
             Ok(self.parse(input)?.try_into()?)
@@ -66,61 +165,319 @@ pub(crate) mod sealed {
         fn parse_offset_date_time(&self, input: &[u8]) -> Result<OffsetDateTime, error::Parse> {
             Ok(self.parse(input)?.try_into()?)
         }
+
+        /// Parse a [`Duration`] from the format description.
+        fn parse_duration(&self, input: &[u8]) -> Result<Duration, error::Parse> {
+            Ok(self.parse(input)?.try_into()?)
+        }
     }
 }
 
 // region: custom formats
 impl sealed::Parsable for FormatItem<'_> {
-    fn parse_into<'a>(
+    fn parse_into_with_locale<'a>(
         &self,
-        mut input: &'a [u8],
+        input: &'a [u8],
         parsed: &mut Parsed,
+        locale: &dyn Locale,
     ) -> Result<&'a [u8], error::Parse> {
-        match self {
-            Self::Literal(literal) => {
-                input = input
-                    .strip_prefix_(literal)
-                    .ok_or(error::ParseFromDescription::InvalidLiteral)?;
-            }
-            Self::Component(component) => input = parsed.parse_component(input, *component)?,
-            Self::Compound(compound) => input = compound.parse_into(input, parsed)?,
-        }
-        Ok(input)
+        Ok(parsed.parse_item_with_locale(input, self, locale)?)
     }
 }
 
 impl sealed::Parsable for &[FormatItem<'_>] {
-    fn parse_into<'a>(
+    fn parse_into_with_locale<'a>(
         &self,
-        mut input: &'a [u8],
+        input: &'a [u8],
         parsed: &mut Parsed,
+        locale: &dyn Locale,
     ) -> Result<&'a [u8], error::Parse> {
-        for item in self.iter() {
-            input = item.parse_into(input, parsed)?;
-        }
-        Ok(input)
+        Ok(parsed.parse_items_with_locale(input, self, locale)?)
     }
 }
 
 #[cfg(feature = "alloc")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
 impl sealed::Parsable for Vec<FormatItem<'_>> {
-    fn parse_into<'a>(
+    fn parse_into_with_locale<'a>(
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
+        locale: &dyn Locale,
     ) -> Result<&'a [u8], error::Parse> {
-        self.as_slice().parse_into(input, parsed)
+        self.as_slice()
+            .parse_into_with_locale(input, parsed, locale)
+    }
+}
+#[cfg(feature = "alloc")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl sealed::Parsable for OwnedFormatItem {
+    fn parse_into_with_locale<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::Parse> {
+        Ok(parsed.parse_item_with_locale(input, self, locale)?)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl sealed::Parsable for &[OwnedFormatItem] {
+    fn parse_into_with_locale<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::Parse> {
+        Ok(parsed.parse_items_with_locale(input, self, locale)?)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl sealed::Parsable for Vec<OwnedFormatItem> {
+    fn parse_into_with_locale<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::Parse> {
+        self.as_slice()
+            .parse_into_with_locale(input, parsed, locale)
     }
 }
 // endregion custom formats
 
 // region: well-known formats
+/// Short weekday names as used in RFC 2822.
+const RFC_2822_WEEKDAYS: [(&str, Weekday); 7] = [
+    ("Mon", Weekday::Monday),
+    ("Tue", Weekday::Tuesday),
+    ("Wed", Weekday::Wednesday),
+    ("Thu", Weekday::Thursday),
+    ("Fri", Weekday::Friday),
+    ("Sat", Weekday::Saturday),
+    ("Sun", Weekday::Sunday),
+];
+
+/// Short month names as used in RFC 2822.
+const RFC_2822_MONTHS: [(&str, u8); 12] = [
+    ("Jan", 1),
+    ("Feb", 2),
+    ("Mar", 3),
+    ("Apr", 4),
+    ("May", 5),
+    ("Jun", 6),
+    ("Jul", 7),
+    ("Aug", 8),
+    ("Sep", 9),
+    ("Oct", 10),
+    ("Nov", 11),
+    ("Dec", 12),
+];
+
+/// Named zones permitted by the obsolete syntax of RFC 2822, along with their offset in hours.
+const RFC_2822_ZONES: [(&str, i8); 10] = [
+    ("UT", 0),
+    ("GMT", 0),
+    ("EST", -5),
+    ("EDT", -4),
+    ("CST", -6),
+    ("CDT", -5),
+    ("MST", -7),
+    ("MDT", -6),
+    ("PST", -8),
+    ("PDT", -7),
+];
+
+/// Parse the year of an RFC 2822 date, including the obsolete two and three-digit forms.
+fn rfc_2822_year(input: &[u8]) -> Option<ParsedItem<'_, i32>> {
+    use crate::parsing::combinator::{any_digit, n_to_m};
+    use crate::parsing::shim::IntegerParseBytes;
+
+    let ParsedItem(input, digits) = n_to_m(2, 4, any_digit)(input)?;
+    let year: i32 = digits.parse_bytes()?;
+    match digits.len() {
+        4 if year >= 1900 => Some(ParsedItem(input, year)),
+        4 => None,
+        2 if year < 50 => Some(ParsedItem(input, year + 2000)),
+        _ => Some(ParsedItem(input, year + 1900)),
+    }
+}
+
+/// Parse the zone of an RFC 2822 date-time, returning the offset in hours and minutes. Both values
+/// carry the sign, so that offsets of less than an hour (e.g. `-0030`) remain negative.
+fn rfc_2822_zone(input: &[u8]) -> Option<ParsedItem<'_, (i8, i8)>> {
+    use crate::parsing::combinator::{exactly_n_digits, first_match, sign};
+
+    if let Some(ParsedItem(input, offset_sign)) = sign(input) {
+        let ParsedItem(input, offset_hour) = exactly_n_digits::<i8>(2)(input)?;
+        let ParsedItem(input, offset_minute) = exactly_n_digits::<i8>(2)(input)?;
+        return Some(ParsedItem(
+            input,
+            if offset_sign == b'-' {
+                (-offset_hour, -offset_minute)
+            } else {
+                (offset_hour, offset_minute)
+            },
+        ));
+    }
+
+    if let Some(zone) = first_match(RFC_2822_ZONES.iter())(input) {
+        return Some(zone.map(|offset_hour| (offset_hour, 0)));
+    }
+
+    // Military zones are ambiguous due to an error in RFC 822. The RFC requires that they be
+    // treated as `-0000`, meaning UTC with no information about the local offset.
+    match input {
+        [b'a'..=b'i', remaining @ ..]
+        | [b'k'..=b'z', remaining @ ..]
+        | [b'A'..=b'I', remaining @ ..]
+        | [b'K'..=b'Z', remaining @ ..] => Some(ParsedItem(remaining, (0, 0))),
+        _ => None,
+    }
+}
+
+impl sealed::Parsable for well_known::Rfc2822 {
+    fn parse_into_with_locale<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        _locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::Parse> {
+        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+        use crate::parsing::combinator::{
+            ascii_char, cfws, exactly_n_digits, first_match, fws, n_to_m_digits, opt,
+        };
+
+        let colon = ascii_char(b':');
+        let comma = ascii_char(b',');
+
+        let input = opt(cfws)(input).0;
+        let input = if let Some(item) = first_match(RFC_2822_WEEKDAYS.iter())(input) {
+            let input = item.assign_value_to(&mut parsed.weekday);
+            let input = opt(cfws)(input).0;
+            let input = comma(input).ok_or(InvalidLiteral)?.unwrap();
+            opt(cfws)(input).0
+        } else {
+            input
+        };
+        let input = n_to_m_digits(1, 2)(input)
+            .ok_or(InvalidComponent("day"))?
+            .assign_value_to(&mut parsed.day);
+        let input = cfws(input).ok_or(InvalidLiteral)?.unwrap();
+        let input = first_match(RFC_2822_MONTHS.iter())(input)
+            .and_then(|item| item.flat_map(core::num::NonZeroU8::new))
+            .ok_or(InvalidComponent("month"))?
+            .assign_value_to(&mut parsed.month);
+        let input = cfws(input).ok_or(InvalidLiteral)?.unwrap();
+        let input = rfc_2822_year(input)
+            .ok_or(InvalidComponent("year"))?
+            .assign_value_to(&mut parsed.year);
+        let input = cfws(input).ok_or(InvalidLiteral)?.unwrap();
+        let input = exactly_n_digits(2)(input)
+            .ok_or(InvalidComponent("hour"))?
+            .assign_value_to(&mut parsed.hour_24);
+        let input = opt(cfws)(input).0;
+        let input = colon(input).ok_or(InvalidLiteral)?.unwrap();
+        let input = opt(cfws)(input).0;
+        let input = exactly_n_digits(2)(input)
+            .ok_or(InvalidComponent("minute"))?
+            .assign_value_to(&mut parsed.minute);
+        let input = opt(cfws)(input).0;
+        let input = if let Some(ParsedItem(input, ())) = colon(input) {
+            let input = opt(cfws)(input).0;
+            let ParsedItem(input, second) =
+                exactly_n_digits(2)(input).ok_or(InvalidComponent("second"))?;
+            // The RFC explicitly allows leap seconds.
+            parsed.assign_second_permitting_leap(second);
+            input
+        } else {
+            input
+        };
+        let input = opt(fws)(input).0;
+        let ParsedItem(input, (offset_hour, offset_minute)) =
+            rfc_2822_zone(input).ok_or(InvalidComponent("offset_hour"))?;
+        parsed.offset_hour = Some(offset_hour);
+        parsed.offset_minute = Some(offset_minute.abs() as u8);
+        parsed.offset_second = Some(0);
+        parsed.offset_is_negative = Some(offset_hour < 0 || offset_minute < 0);
+
+        Ok(opt(cfws)(input).0)
+    }
+
+    fn parse_offset_date_time(&self, input: &[u8]) -> Result<OffsetDateTime, error::Parse> {
+        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+        use crate::parsing::combinator::{
+            ascii_char, cfws, exactly_n_digits, first_match, fws, n_to_m_digits, opt,
+        };
+
+        let colon = ascii_char(b':');
+        let comma = ascii_char(b',');
+
+        let input = opt(cfws)(input).0;
+        let ParsedItem(input, weekday) = if let Some(ParsedItem(input, weekday)) =
+            first_match(RFC_2822_WEEKDAYS.iter())(input)
+        {
+            let input = opt(cfws)(input).0;
+            let input = comma(input).ok_or(InvalidLiteral)?.unwrap();
+            ParsedItem(opt(cfws)(input).0, Some(weekday))
+        } else {
+            ParsedItem(input, None)
+        };
+        let ParsedItem(input, day) = n_to_m_digits(1, 2)(input).ok_or(InvalidComponent("day"))?;
+        let input = cfws(input).ok_or(InvalidLiteral)?.unwrap();
+        let ParsedItem(input, month) =
+            first_match(RFC_2822_MONTHS.iter())(input).ok_or(InvalidComponent("month"))?;
+        let input = cfws(input).ok_or(InvalidLiteral)?.unwrap();
+        let ParsedItem(input, year) = rfc_2822_year(input).ok_or(InvalidComponent("year"))?;
+        let input = cfws(input).ok_or(InvalidLiteral)?.unwrap();
+        let ParsedItem(input, hour) = exactly_n_digits(2)(input).ok_or(InvalidComponent("hour"))?;
+        let input = opt(cfws)(input).0;
+        let input = colon(input).ok_or(InvalidLiteral)?.unwrap();
+        let input = opt(cfws)(input).0;
+        let ParsedItem(input, minute) =
+            exactly_n_digits(2)(input).ok_or(InvalidComponent("minute"))?;
+        let input = opt(cfws)(input).0;
+        let ParsedItem(input, second) = if let Some(ParsedItem(input, ())) = colon(input) {
+            let input = opt(cfws)(input).0;
+            exactly_n_digits(2)(input)
+                .ok_or(InvalidComponent("second"))?
+                .map(|second| if second == 60 { 59 } else { second })
+        } else {
+            ParsedItem(input, 0)
+        };
+        let input = opt(fws)(input).0;
+        let ParsedItem(input, (offset_hour, offset_minute)) =
+            rfc_2822_zone(input).ok_or(InvalidComponent("offset_hour"))?;
+        let input = opt(cfws)(input).0;
+
+        if !input.is_empty() {
+            return Err(error::Parse::UnexpectedTrailingCharacters);
+        }
+
+        let date =
+            Date::from_calendar_date(year, month, day).map_err(TryFromParsed::ComponentRange)?;
+        check_weekday(date, weekday)?;
+
+        Ok(date
+            .with_hms(hour, minute, second)
+            .map_err(TryFromParsed::ComponentRange)?
+            .assume_offset(
+                UtcOffset::from_hms(offset_hour, offset_minute, 0)
+                    .map_err(TryFromParsed::ComponentRange)?,
+            ))
+    }
+}
+
 impl sealed::Parsable for well_known::Rfc3339 {
-    fn parse_into<'a>(
+    fn parse_into_with_locale<'a>(
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
+        _locale: &dyn Locale,
     ) -> Result<&'a [u8], error::Parse> {
         use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
         use crate::parsing::combinator::{
@@ -152,13 +509,10 @@ impl sealed::Parsable for well_known::Rfc3339 {
             .ok_or(InvalidComponent("minute"))?
             .assign_value_to(&mut parsed.minute);
         let input = colon(input).ok_or(InvalidLiteral)?.unwrap();
-        let input = exactly_n_digits(2)(input)
-            .ok_or(InvalidComponent("second"))?
-            .assign_value_to_with(&mut parsed.second, |second| {
-                // The RFC explicitly allows leap seconds. We don't support them, so treat it as
-                // the previous second.
-                if second == 60 { 59 } else { second }
-            });
+        let ParsedItem(input, second) =
+            exactly_n_digits(2)(input).ok_or(InvalidComponent("second"))?;
+        // The RFC explicitly allows leap seconds.
+        parsed.assign_second_permitting_leap(second);
         let input = if let Some(ParsedItem(input, ())) = ascii_char(b'.')(input) {
             let ParsedItem(mut input, mut value) = any_digit(input)
                 .ok_or(InvalidComponent("subsecond"))?
@@ -282,4 +636,74 @@ impl sealed::Parsable for well_known::Rfc3339 {
             .assume_offset(offset))
     }
 }
+
+impl sealed::Parsable for well_known::Iso8601 {
+    fn parse_into_with_locale<'a>(
+        &self,
+        mut input: &'a [u8],
+        parsed: &mut Parsed,
+        _locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::Parse> {
+        use crate::parsing::iso8601::{parse_date, parse_offset, parse_time, ExtendedKind};
+
+        let mut extended_kind = ExtendedKind::Unknown;
+        let mut first_error = None;
+        let mut date_is_present = false;
+        let mut time_is_present = false;
+        let mut offset_is_present = false;
+
+        match parse_date(input, parsed, &mut extended_kind) {
+            Ok(remaining) => {
+                input = remaining;
+                date_is_present = true;
+            }
+            // A separator after the year can only be part of a date, so the error is final.
+            Err(err) if extended_kind == ExtendedKind::Extended => return Err(err.into()),
+            Err(err) => {
+                // Parsing the date may have determined the format before failing.
+                extended_kind = ExtendedKind::Unknown;
+                first_error = Some(err);
+            }
+        }
+
+        match parse_time(input, parsed, &mut extended_kind, date_is_present) {
+            Ok(remaining) => {
+                input = remaining;
+                time_is_present = true;
+            }
+            Err(err) => {
+                first_error.get_or_insert(err);
+            }
+        }
+
+        // An offset may only follow a date if a time is also present.
+        if !date_is_present || time_is_present {
+            match parse_offset(input, parsed, &mut extended_kind) {
+                Ok(remaining) => {
+                    input = remaining;
+                    offset_is_present = true;
+                }
+                Err(err) => {
+                    first_error.get_or_insert(err);
+                }
+            }
+        }
+
+        match first_error {
+            Some(err) if !date_is_present && !time_is_present && !offset_is_present => {
+                Err(err.into())
+            }
+            _ => Ok(input),
+        }
+    }
+
+    // Durations are only parsed here, so that they are never mixed with other components.
+    fn parse_duration(&self, input: &[u8]) -> Result<Duration, error::Parse> {
+        let mut parsed = Parsed::new();
+        if !crate::parsing::iso8601::parse_duration(input, &mut parsed)?.is_empty() {
+            return Err(error::Parse::UnexpectedTrailingCharacters);
+        }
+        Ok(parsed.try_into()?)
+    }
+}
 // endregion well-known formats
@@ -1,18 +1,107 @@
 //! Information parsed from an input and format description.
 
 use core::convert::{TryFrom, TryInto};
-use core::num::{NonZeroU16, NonZeroU8};
+use core::num::{NonZeroU16, NonZeroU32, NonZeroU8};
+#[cfg(feature = "std")]
+use std::io::{self, BufRead};
 
+use crate::date::{MAX_YEAR, MIN_YEAR};
 use crate::error::TryFromParsed::InsufficientInformation;
+use crate::format_description::locale::{English, Locale};
 use crate::format_description::modifier::{WeekNumberRepr, YearRepr};
-use crate::format_description::Component;
+#[cfg(feature = "alloc")]
+use crate::format_description::OwnedFormatItem;
+use crate::format_description::{Component, FormatItem};
 use crate::parsing::component::{
-    parse_day, parse_hour, parse_minute, parse_month, parse_offset_hour, parse_offset_minute,
-    parse_offset_second, parse_ordinal, parse_period, parse_second, parse_subsecond,
-    parse_week_number, parse_weekday, parse_year, Period,
+    parse_century, parse_day, parse_duration_day, parse_duration_hour, parse_duration_minute,
+    parse_duration_second, parse_duration_sign, parse_duration_subsecond, parse_end, parse_era,
+    parse_hour, parse_ignore, parse_minute, parse_month, parse_offset, parse_offset_abbreviation,
+    parse_offset_hour, parse_offset_minute, parse_offset_second, parse_ordinal, parse_period,
+    parse_quarter, parse_second, parse_subsecond, parse_unix_timestamp, parse_week_number,
+    parse_week_of_month, parse_week_of_quarter, parse_weekday, parse_weekday_in_month, parse_year,
+    Period,
 };
+use crate::parsing::parsable::sealed::Parsable;
+use crate::parsing::shim::SliceStripPrefix;
 use crate::parsing::ParsedItem;
-use crate::{error, Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+use crate::util::DateAdjustment;
+use crate::{error, Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+/// Seal the trait to prevent downstream users from implementing it, while still allowing it to
+/// exist in generic bounds.
+pub(crate) mod sealed {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// A format item that can be parsed into a [`Parsed`] struct.
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+    pub trait AnyFormatItem {
+        /// Parse the item using the names provided by the locale, mutating the struct. The
+        /// remaining input is returned as the `Ok` value.
+        fn parse_item<'a>(
+            &self,
+            parsed: &mut Parsed,
+            input: &'a [u8],
+            locale: &dyn Locale,
+        ) -> Result<&'a [u8], error::ParseFromDescription>;
+    }
+}
+
+impl sealed::AnyFormatItem for FormatItem<'_> {
+    fn parse_item<'a>(
+        &self,
+        parsed: &mut Parsed,
+        input: &'a [u8],
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        match self {
+            Self::Literal(literal) => input
+                .strip_prefix_(literal)
+                .ok_or(error::ParseFromDescription::InvalidLiteral),
+            Self::Component(component) => {
+                parsed.parse_component_with_locale(input, *component, locale)
+            }
+            Self::Compound(items) => parsed.parse_items_with_locale(input, items, locale),
+            Self::Optional(item) => Ok(parsed.parse_optional(input, *item, locale)),
+            Self::First(items) => parsed.parse_first(input, items, locale),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl sealed::AnyFormatItem for OwnedFormatItem {
+    fn parse_item<'a>(
+        &self,
+        parsed: &mut Parsed,
+        input: &'a [u8],
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        match self {
+            Self::Literal(literal) => input
+                .strip_prefix_(literal)
+                .ok_or(error::ParseFromDescription::InvalidLiteral),
+            Self::Component(component) => {
+                parsed.parse_component_with_locale(input, *component, locale)
+            }
+            Self::Compound(items) => parsed.parse_items_with_locale(input, items, locale),
+            Self::Optional(item) => Ok(parsed.parse_optional(input, item.as_ref(), locale)),
+            Self::First(items) => parsed.parse_first(input, items, locale),
+        }
+    }
+}
+
+/// How a leap second (a second of 60) is resolved when constructing a [`Time`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeapSecondPolicy {
+    /// The leap second is an error, as the second is out of range.
+    Reject,
+    /// The leap second is treated as the last instant of the previous second (`59.999999999`).
+    Clamp,
+    /// The leap second is treated as the first second of the next minute, retaining the
+    /// subsecond. This may carry into the next day, which a `Time` alone wraps around.
+    RollOver,
+}
 
 /// All information parsed.
 ///
@@ -27,20 +116,42 @@ pub struct Parsed {
     pub year: Option<i32>,
     /// The last two digits of the calendar year.
     pub year_last_two: Option<u8>,
+    /// Century of the calendar year, rounded towards zero. Together with `year_last_two`, this
+    /// determines the calendar year.
+    pub century: Option<i16>,
+    /// Whether the calendar year is negative. This is only needed when the century is zero (e.g.
+    /// `-00` for years -1 through -99), as the sign is otherwise present on `century`.
+    pub century_is_negative: Option<bool>,
+    /// Number of the calendar year within its era, where there is no year zero. Together with
+    /// `is_before_common_era`, this determines the calendar year.
+    pub year_historical: Option<NonZeroU32>,
+    /// Whether the calendar year is before year 1 (e.g. "BC"). If absent, a historical year is
+    /// assumed to be in the common era.
+    pub is_before_common_era: Option<bool>,
     /// Year of the [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date).
     pub iso_year: Option<i32>,
     /// The last two digits of the ISO week year.
     pub iso_year_last_two: Option<u8>,
     /// Month of the year.
     pub month: Option<NonZeroU8>,
+    /// Quarter of the year.
+    pub quarter: Option<NonZeroU8>,
     /// Week of the year, where week one begins on the first Sunday of the calendar year.
     pub sunday_week_number: Option<u8>,
     /// Week of the year, where week one begins on the first Monday of the calendar year.
     pub monday_week_number: Option<u8>,
     /// Week of the year, where week one is the Monday-to-Sunday period containing January 4.
     pub iso_week_number: Option<NonZeroU8>,
+    /// Week of the month, where weeks begin on Monday and week one contains the first day of the
+    /// month.
+    pub week_of_month: Option<NonZeroU8>,
+    /// Week of the quarter, where weeks begin on Monday and week one contains the first day of
+    /// the quarter.
+    pub week_of_quarter: Option<NonZeroU8>,
     /// Day of the week.
     pub weekday: Option<Weekday>,
+    /// Occurrence of the weekday within the month.
+    pub weekday_in_month: Option<NonZeroU8>,
     /// Day of the year.
     pub ordinal: Option<NonZeroU16>,
     /// Day of the month.
@@ -58,12 +169,38 @@ pub struct Parsed {
     pub second: Option<u8>,
     /// Nanosecond within the second.
     pub subsecond: Option<u32>,
+    /// Whether the second was a leap second. This is recorded regardless of how the leap second
+    /// is resolved.
+    pub is_leap_second: Option<bool>,
+    /// How a leap second is resolved. If absent, well-known formats that permit leap seconds treat
+    /// them as the previous second, and all other leap seconds are rejected.
+    pub leap_second_policy: Option<LeapSecondPolicy>,
     /// Whole hours of the UTC offset.
     pub offset_hour: Option<i8>,
     /// Minutes within the hour of the UTC offset.
     pub offset_minute: Option<u8>,
     /// Seconds within the minute of the UTC offset.
     pub offset_second: Option<u8>,
+    /// Whether the UTC offset is negative. This is only needed when the whole hours are zero
+    /// (e.g. `-00:30`), as the sign is otherwise present on `offset_hour`.
+    pub offset_is_negative: Option<bool>,
+    /// Nanoseconds since the Unix epoch. If present, this takes precedence over the date and
+    /// time when constructing an [`OffsetDateTime`]. When the timestamp is on a whole second,
+    /// [`subsecond`](Self::subsecond) is added to it; otherwise the two must agree.
+    pub unix_timestamp_nanos: Option<i128>,
+    /// Whether the duration is negative.
+    pub duration_is_negative: Option<bool>,
+    /// Whole days of the duration.
+    pub duration_days: Option<u64>,
+    /// Hours of the duration. This may be either the total or the remainder after whole days.
+    pub duration_hours: Option<u64>,
+    /// Minutes of the duration. This may be either the total or the remainder after whole hours.
+    pub duration_minutes: Option<u64>,
+    /// Seconds of the duration. This may be either the total or the remainder after whole
+    /// minutes.
+    pub duration_seconds: Option<u64>,
+    /// Nanoseconds within the second of the duration.
+    pub duration_subsecond: Option<u32>,
 }
 
 impl Parsed {
@@ -72,13 +209,21 @@ impl Parsed {
         Self {
             year: None,
             year_last_two: None,
+            century: None,
+            century_is_negative: None,
+            year_historical: None,
+            is_before_common_era: None,
             iso_year: None,
             iso_year_last_two: None,
             month: None,
+            quarter: None,
             sunday_week_number: None,
             monday_week_number: None,
             iso_week_number: None,
+            week_of_month: None,
+            week_of_quarter: None,
             weekday: None,
+            weekday_in_month: None,
             ordinal: None,
             day: None,
             hour_24: None,
@@ -87,9 +232,164 @@ impl Parsed {
             minute: None,
             second: None,
             subsecond: None,
+            is_leap_second: None,
+            leap_second_policy: None,
             offset_hour: None,
             offset_minute: None,
             offset_second: None,
+            offset_is_negative: None,
+            unix_timestamp_nanos: None,
+            duration_is_negative: None,
+            duration_days: None,
+            duration_hours: None,
+            duration_minutes: None,
+            duration_seconds: None,
+            duration_subsecond: None,
+        }
+    }
+
+    /// Parse a single [`FormatItem`] or `OwnedFormatItem`, mutating the struct. The remaining
+    /// input is returned as the `Ok` value.
+    ///
+    /// If a [`FormatItem::Optional`] is passed, parsing will not fail; the input will be returned
+    /// as-is if the expected format is not present.
+    pub fn parse_item<'a>(
+        &mut self,
+        input: &'a [u8],
+        item: &impl sealed::AnyFormatItem,
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        self.parse_item_with_locale(input, item, &English)
+    }
+
+    /// Parse a single [`FormatItem`] or `OwnedFormatItem` using the names provided by the locale,
+    /// mutating the struct. The remaining input is returned as the `Ok` value.
+    pub fn parse_item_with_locale<'a>(
+        &mut self,
+        input: &'a [u8],
+        item: &impl sealed::AnyFormatItem,
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        item.parse_item(self, input, locale)
+    }
+
+    /// Parse a sequence of [`FormatItem`]s or `OwnedFormatItem`s, mutating the struct. The
+    /// remaining input is returned as the `Ok` value.
+    ///
+    /// This method will fail if any of the contained [`FormatItem`]s fail to parse. `self` will
+    /// not be mutated in this instance.
+    pub fn parse_items<'a>(
+        &mut self,
+        input: &'a [u8],
+        items: &[impl sealed::AnyFormatItem],
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        self.parse_items_with_locale(input, items, &English)
+    }
+
+    /// Parse a sequence of [`FormatItem`]s or `OwnedFormatItem`s using the names provided by the
+    /// locale, mutating the struct. The remaining input is returned as the `Ok` value.
+    ///
+    /// This method will fail if any of the contained [`FormatItem`]s fail to parse. `self` will
+    /// not be mutated in this instance.
+    pub fn parse_items_with_locale<'a>(
+        &mut self,
+        mut input: &'a [u8],
+        items: &[impl sealed::AnyFormatItem],
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        // Parse into a copy so that a failure has no effect.
+        let mut parsed = *self;
+        for item in items {
+            input = parsed.parse_item_with_locale(input, item, locale)?;
+        }
+        *self = parsed;
+        Ok(input)
+    }
+
+    /// Parse the format description from the start of the input, mutating the struct. The
+    /// remaining input is returned as the `Ok` value.
+    ///
+    /// Unlike [`parse_items`](Self::parse_items), any format description can be used, including
+    /// well-known formats. `self` will not be mutated if parsing fails.
+    pub fn parse_prefix<'a>(
+        &mut self,
+        input: &'a [u8],
+        description: &impl Parsable,
+    ) -> Result<&'a [u8], error::Parse> {
+        // Parse into a copy so that a failure has no effect.
+        let mut parsed = *self;
+        let remaining = description.parse_into(input, &mut parsed)?;
+        *self = parsed;
+        Ok(remaining)
+    }
+
+    /// Parse the format description from the start of the reader, mutating the struct. Only the
+    /// bytes that were parsed are consumed, so the remainder can be read afterwards. Unless the
+    /// value is at the end of the reader, it must be followed by at least 16 bytes in the reader's
+    /// buffer, as it could otherwise continue past the end of the buffer.
+    ///
+    /// Errors that occur while parsing are returned with [`io::ErrorKind::InvalidData`], wrapping
+    /// an [`error::Parse`]. `self` will not be mutated if parsing fails, but it is unspecified how
+    /// many bytes were consumed from the reader.
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+    pub fn parse_from_reader(
+        &mut self,
+        reader: &mut impl BufRead,
+        description: &impl Parsable,
+    ) -> io::Result<()> {
+        description.parse_into_from_reader(reader, self)
+    }
+
+    /// Parse the item if possible, mutating the struct. If the item does not match, the struct is
+    /// not mutated and the input is returned as-is.
+    fn parse_optional<'a>(
+        &mut self,
+        input: &'a [u8],
+        item: &impl sealed::AnyFormatItem,
+        locale: &dyn Locale,
+    ) -> &'a [u8] {
+        // Parse into a copy so that a partial match has no effect.
+        let mut parsed = *self;
+        match parsed.parse_item_with_locale(input, item, locale) {
+            Ok(remaining) => {
+                *self = parsed;
+                remaining
+            }
+            Err(_) => input,
+        }
+    }
+
+    /// Assign the second for a format that explicitly permits leap seconds. Unless a
+    /// [`LeapSecondPolicy`] has been set, a leap second is treated as the previous second.
+    pub(crate) fn assign_second_permitting_leap(&mut self, second: u8) {
+        self.is_leap_second = Some(second == 60);
+        self.second = Some(if second == 60 && self.leap_second_policy.is_none() {
+            59
+        } else {
+            second
+        });
+    }
+
+    /// Parse the first of the provided items that matches, mutating the struct. Failed
+    /// alternatives have no effect. If no items are provided, the input is returned as-is.
+    fn parse_first<'a>(
+        &mut self,
+        input: &'a [u8],
+        items: &[impl sealed::AnyFormatItem],
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        for item in items {
+            let mut parsed = *self;
+            if let Ok(remaining) = parsed.parse_item_with_locale(input, item, locale) {
+                *self = parsed;
+                return Ok(remaining);
+            }
+        }
+
+        if items.is_empty() {
+            Ok(input)
+        } else {
+            Err(error::ParseFromDescription::NoMatchingAlternative { count: items.len() })
         }
     }
 
@@ -99,25 +399,37 @@ impl Parsed {
         &mut self,
         input: &'a [u8],
         component: Component,
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        self.parse_component_with_locale(input, component, &English)
+    }
+
+    /// Parse a single component using the names provided by the locale, mutating the struct. The
+    /// remaining input is returned as the `Ok` value.
+    #[allow(clippy::too_many_lines)]
+    pub fn parse_component_with_locale<'a>(
+        &mut self,
+        input: &'a [u8],
+        component: Component,
+        locale: &dyn Locale,
     ) -> Result<&'a [u8], error::ParseFromDescription> {
         use error::ParseFromDescription::InvalidComponent;
 
         match component {
-            Component::Day(modifiers) => Ok(parse_day(input, modifiers)
+            Component::Day(modifiers) => Ok(parse_day(input, modifiers, locale)
                 .ok_or(InvalidComponent("day"))?
                 .assign_value_to(&mut self.day)),
-            Component::Month(modifiers) => Ok(parse_month(input, modifiers)
+            Component::Month(modifiers) => Ok(parse_month(input, modifiers, locale)
                 .ok_or(InvalidComponent("month"))?
                 .assign_value_to(&mut self.month)),
-            Component::Ordinal(modifiers) => Ok(parse_ordinal(input, modifiers)
+            Component::Ordinal(modifiers) => Ok(parse_ordinal(input, modifiers, locale)
                 .ok_or(InvalidComponent("ordinal"))?
                 .assign_value_to(&mut self.ordinal)),
-            Component::Weekday(modifiers) => Ok(parse_weekday(input, modifiers)
+            Component::Weekday(modifiers) => Ok(parse_weekday(input, modifiers, locale)
                 .ok_or(InvalidComponent("weekday"))?
                 .assign_value_to(&mut self.weekday)),
             Component::WeekNumber(modifiers) => {
-                let ParsedItem(remaining, value) =
-                    parse_week_number(input, modifiers).ok_or(InvalidComponent("week number"))?;
+                let ParsedItem(remaining, value) = parse_week_number(input, modifiers, locale)
+                    .ok_or(InvalidComponent("week number"))?;
                 match modifiers.repr {
                     WeekNumberRepr::Iso => {
                         self.iso_week_number =
@@ -136,9 +448,40 @@ impl Parsed {
                     (false, YearRepr::LastTwo) => self.year_last_two = Some(value as u8),
                     (true, YearRepr::Full) => self.iso_year = Some(value),
                     (true, YearRepr::LastTwo) => self.iso_year_last_two = Some(value as u8),
+                    (false, YearRepr::Historical) => {
+                        self.year_historical =
+                            Some(NonZeroU32::new(value as u32).ok_or(InvalidComponent("year"))?);
+                    }
+                    (true, YearRepr::Historical) => return Err(InvalidComponent("year")),
                 }
                 Ok(remaining)
             }
+            Component::Quarter(modifiers) => Ok(parse_quarter(input, modifiers, locale)
+                .ok_or(InvalidComponent("quarter"))?
+                .assign_value_to(&mut self.quarter)),
+            Component::WeekOfMonth(modifiers) => Ok(parse_week_of_month(input, modifiers, locale)
+                .ok_or(InvalidComponent("week of month"))?
+                .assign_value_to(&mut self.week_of_month)),
+            Component::WeekOfQuarter(modifiers) => {
+                Ok(parse_week_of_quarter(input, modifiers, locale)
+                    .ok_or(InvalidComponent("week of quarter"))?
+                    .assign_value_to(&mut self.week_of_quarter))
+            }
+            Component::WeekdayInMonth(modifiers) => {
+                Ok(parse_weekday_in_month(input, modifiers, locale)
+                    .ok_or(InvalidComponent("weekday in month"))?
+                    .assign_value_to(&mut self.weekday_in_month))
+            }
+            Component::Century(modifiers) => {
+                let ParsedItem(remaining, (century, is_negative)) =
+                    parse_century(input, modifiers, locale).ok_or(InvalidComponent("century"))?;
+                self.century = Some(century);
+                self.century_is_negative = Some(is_negative);
+                Ok(remaining)
+            }
+            Component::Era(modifiers) => Ok(parse_era(input, modifiers, locale)
+                .ok_or(InvalidComponent("era"))?
+                .assign_value_to(&mut self.is_before_common_era)),
             Component::Hour(modifiers) => {
                 let ParsedItem(remaining, value) =
                     parse_hour(input, modifiers).ok_or(InvalidComponent("hour"))?;
@@ -152,13 +495,17 @@ impl Parsed {
             Component::Minute(modifiers) => Ok(parse_minute(input, modifiers)
                 .ok_or(InvalidComponent("minute"))?
                 .assign_value_to(&mut self.minute)),
-            Component::Period(modifiers) => Ok(parse_period(input, modifiers)
+            Component::Period(modifiers) => Ok(parse_period(input, modifiers, locale)
                 .ok_or(InvalidComponent("period"))?
                 .map(|period| period == Period::Pm)
                 .assign_value_to(&mut self.hour_12_is_pm)),
-            Component::Second(modifiers) => Ok(parse_second(input, modifiers)
-                .ok_or(InvalidComponent("second"))?
-                .assign_value_to(&mut self.second)),
+            Component::Second(modifiers) => {
+                let ParsedItem(remaining, second) =
+                    parse_second(input, modifiers).ok_or(InvalidComponent("second"))?;
+                self.second = Some(second);
+                self.is_leap_second = Some(second == 60);
+                Ok(remaining)
+            }
             Component::Subsecond(modifiers) => Ok(parse_subsecond(input, modifiers)
                 .ok_or(InvalidComponent("subsecond"))?
                 .assign_value_to(&mut self.subsecond)),
@@ -171,6 +518,56 @@ impl Parsed {
             Component::OffsetSecond(modifiers) => Ok(parse_offset_second(input, modifiers)
                 .ok_or(InvalidComponent("offset second"))?
                 .assign_value_to(&mut self.offset_second)),
+            Component::OffsetAbbreviation(modifiers) => {
+                let ParsedItem(remaining, offset) =
+                    parse_offset_abbreviation(input, modifiers, locale)
+                        .ok_or(InvalidComponent("offset abbreviation"))?;
+                let (hours, minutes, seconds) = offset.as_hms();
+                self.offset_hour = Some(hours);
+                self.offset_minute = Some(minutes.abs() as u8);
+                self.offset_second = Some(seconds.abs() as u8);
+                self.offset_is_negative = Some(offset.is_negative());
+                Ok(remaining)
+            }
+            Component::Offset(modifiers) => {
+                let ParsedItem(remaining, offset) =
+                    parse_offset(input, modifiers).ok_or(InvalidComponent("offset"))?;
+                let (hours, minutes, seconds) = offset.as_hms();
+                self.offset_hour = Some(hours);
+                self.offset_minute = Some(minutes.abs() as u8);
+                self.offset_second = Some(seconds.abs() as u8);
+                self.offset_is_negative = Some(offset.is_negative());
+                Ok(remaining)
+            }
+            Component::DurationDay(modifiers) => Ok(parse_duration_day(input, modifiers)
+                .ok_or(InvalidComponent("duration day"))?
+                .assign_value_to(&mut self.duration_days)),
+            Component::DurationHour(modifiers) => Ok(parse_duration_hour(input, modifiers)
+                .ok_or(InvalidComponent("duration hour"))?
+                .assign_value_to(&mut self.duration_hours)),
+            Component::DurationMinute(modifiers) => Ok(parse_duration_minute(input, modifiers)
+                .ok_or(InvalidComponent("duration minute"))?
+                .assign_value_to(&mut self.duration_minutes)),
+            Component::DurationSecond(modifiers) => Ok(parse_duration_second(input, modifiers)
+                .ok_or(InvalidComponent("duration second"))?
+                .assign_value_to(&mut self.duration_seconds)),
+            Component::DurationSubsecond(modifiers) => {
+                Ok(parse_duration_subsecond(input, modifiers)
+                    .ok_or(InvalidComponent("duration subsecond"))?
+                    .assign_value_to(&mut self.duration_subsecond))
+            }
+            Component::DurationSign(modifiers) => Ok(parse_duration_sign(input, modifiers)
+                .ok_or(InvalidComponent("duration sign"))?
+                .assign_value_to(&mut self.duration_is_negative)),
+            Component::UnixTimestamp(modifiers) => Ok(parse_unix_timestamp(input, modifiers)
+                .ok_or(InvalidComponent("unix timestamp"))?
+                .assign_value_to(&mut self.unix_timestamp_nanos)),
+            Component::Ignore(modifiers) => Ok(parse_ignore(input, modifiers)
+                .ok_or(InvalidComponent("ignore"))?
+                .0),
+            Component::End(modifiers) => Ok(parse_end(input, modifiers)
+                .ok_or(InvalidComponent("end"))?
+                .0),
         }
     }
 }
@@ -178,15 +575,15 @@ impl Parsed {
 impl TryFrom<Parsed> for Date {
     type Error = error::TryFromParsed;
 
-    fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
+    #[allow(clippy::too_many_lines)]
+    fn try_from(mut parsed: Parsed) -> Result<Self, Self::Error> {
         macro_rules! items {
             ($($item:ident),+ $(,)?) => {
                 Parsed { $($item: Some($item)),*, .. }
             };
         }
 
-        /// Get the value needed to adjust the ordinal day for Sunday and Monday-based week
-        /// numbering.
+        /// Get the value needed to adjust the ordinal day for Monday-based week numbering.
         const fn adjustment(year: i32) -> i16 {
             match Date::__from_ordinal_date_unchecked(year, 1).weekday() {
                 Weekday::Monday => 7,
@@ -199,10 +596,41 @@ impl TryFrom<Parsed> for Date {
             }
         }
 
+        /// Get the value needed to adjust the ordinal day for Sunday-based week numbering.
+        const fn sunday_adjustment(year: i32) -> i16 {
+            match Date::__from_ordinal_date_unchecked(year, 1).weekday() {
+                Weekday::Sunday => 7,
+                Weekday::Monday => 1,
+                Weekday::Tuesday => 2,
+                Weekday::Wednesday => 3,
+                Weekday::Thursday => 4,
+                Weekday::Friday => 5,
+                Weekday::Saturday => 6,
+            }
+        }
+
         // TODO Only the basics have been covered. There are many other valid values that are not
         // currently constructed from the information known.
 
-        match parsed {
+        if let (None, items!(century, year_last_two)) = (parsed.year, parsed) {
+            let century = century as i32 * 100;
+            parsed.year = Some(if century < 0 || parsed.century_is_negative == Some(true) {
+                century - year_last_two as i32
+            } else {
+                century + year_last_two as i32
+            });
+        }
+
+        if let (None, Some(year_historical)) = (parsed.year, parsed.year_historical) {
+            let year_historical = year_historical.get().min(i32::MAX as u32) as i32;
+            parsed.year = Some(if parsed.is_before_common_era == Some(true) {
+                1 - year_historical
+            } else {
+                year_historical
+            });
+        }
+
+        let date = match parsed {
             items!(year, ordinal) => Ok(Self::from_ordinal_date(year, ordinal.get())?),
             items!(year, month, day) => Ok(Self::from_calendar_date(year, month.get(), day.get())?),
             items!(iso_year, iso_week_number, weekday) => Ok(Self::from_iso_week_date(
@@ -213,7 +641,7 @@ impl TryFrom<Parsed> for Date {
             items!(year, sunday_week_number, weekday) => Ok(Self::from_ordinal_date(
                 year,
                 (sunday_week_number as i16 * 7 + weekday.number_days_from_sunday() as i16
-                    - adjustment(year)
+                    - sunday_adjustment(year)
                     + 1) as u16,
             )?),
             items!(year, monday_week_number, weekday) => Ok(Self::from_ordinal_date(
@@ -222,8 +650,108 @@ impl TryFrom<Parsed> for Date {
                     - adjustment(year)
                     + 1) as u16,
             )?),
+            items!(year, month, weekday_in_month, weekday) => {
+                let first_weekday = Self::from_calendar_date(year, month.get(), 1)?.weekday();
+                let day = (weekday_in_month.get() as u16 - 1) * 7
+                    + (weekday.number_days_from_monday() + 7
+                        - first_weekday.number_days_from_monday()) as u16
+                        % 7
+                    + 1;
+                Ok(Self::from_calendar_date(
+                    year,
+                    month.get(),
+                    u8::try_from(day).unwrap_or(0),
+                )?)
+            }
+            items!(year, month, week_of_month, weekday) => {
+                let first_weekday = Self::from_calendar_date(year, month.get(), 1)?.weekday();
+                let day = (week_of_month.get() as i16 - 1) * 7
+                    + weekday.number_days_from_monday() as i16
+                    - first_weekday.number_days_from_monday() as i16
+                    + 1;
+                Ok(Self::from_calendar_date(
+                    year,
+                    month.get(),
+                    u8::try_from(day).unwrap_or(0),
+                )?)
+            }
+            items!(year, quarter, week_of_quarter, weekday) => {
+                let first_day = Self::from_calendar_date(year, (quarter.get() - 1) * 3 + 1, 1)?;
+                let ordinal = first_day.ordinal() as i16
+                    + (week_of_quarter.get() as i16 - 1) * 7
+                    + weekday.number_days_from_monday() as i16
+                    - first_day.weekday().number_days_from_monday() as i16;
+                Ok(Self::from_ordinal_date(
+                    year,
+                    u16::try_from(ordinal).unwrap_or(0),
+                )?)
+            }
             _ => Err(InsufficientInformation),
-        }
+        }?;
+
+        check_weekday(date, parsed.weekday)?;
+        check_quarter(date, parsed.quarter)?;
+        check_era(date, parsed.is_before_common_era)?;
+        Ok(date)
+    }
+}
+
+/// Ensure that the quarter, if known, agrees with the date. The quarter is not always needed to
+/// construct the date, so it would otherwise be silently ignored.
+const fn check_quarter(date: Date, quarter: Option<NonZeroU8>) -> Result<(), error::TryFromParsed> {
+    let expected = (date.month() - 1) / 3 + 1;
+    match quarter {
+        Some(quarter) if quarter.get() != expected => Err(error::TryFromParsed::ComponentRange(
+            error::ComponentRange {
+                name: "quarter",
+                minimum: expected as _,
+                maximum: expected as _,
+                value: quarter.get() as _,
+                conditional_range: true,
+            },
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Ensure that the era, if known, agrees with the date. The era is only used to construct the date
+/// from a historical year, so it would otherwise be silently ignored.
+const fn check_era(
+    date: Date,
+    is_before_common_era: Option<bool>,
+) -> Result<(), error::TryFromParsed> {
+    let expected = date.year() < 1;
+    match is_before_common_era {
+        Some(is_before_common_era) if is_before_common_era != expected => Err(
+            error::TryFromParsed::ComponentRange(error::ComponentRange {
+                name: "era",
+                minimum: expected as _,
+                maximum: expected as _,
+                value: is_before_common_era as _,
+                conditional_range: true,
+            }),
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// Ensure that the weekday, if known, agrees with the date. The weekday is not always needed to
+/// construct the date, so it would otherwise be silently ignored.
+pub(crate) fn check_weekday(
+    date: Date,
+    weekday: Option<Weekday>,
+) -> Result<(), error::TryFromParsed> {
+    match weekday {
+        Some(weekday) if weekday != date.weekday() => Err(error::TryFromParsed::ComponentRange(
+            error::ComponentRange {
+                name: "weekday",
+                minimum: date.weekday().number_from_monday() as _,
+                maximum: date.weekday().number_from_monday() as _,
+                value: weekday.number_from_monday() as _,
+                conditional_range: true,
+            },
+        )),
+        _ => Ok(()),
     }
 }
 
@@ -231,29 +759,58 @@ impl TryFrom<Parsed> for Time {
     type Error = error::TryFromParsed;
 
     fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
-        let hour = match (parsed.hour_24, parsed.hour_12, parsed.hour_12_is_pm) {
-            (Some(hour), _, _) => hour,
-            (_, Some(hour), Some(false)) if hour.get() == 12 => 0,
-            (_, Some(hour), Some(true)) if hour.get() == 12 => 12,
-            (_, Some(hour), Some(false)) => hour.get(),
-            (_, Some(hour), Some(true)) => hour.get() + 12,
-            _ => return Err(InsufficientInformation),
-        };
-        let minute = parsed.minute.ok_or(InsufficientInformation)?;
-        let second = parsed.second.unwrap_or(0);
-        let subsecond = parsed.subsecond.unwrap_or(0);
-        Ok(Self::from_hms_nano(hour, minute, second, subsecond)?)
+        Ok(resolve_time(parsed)?.1)
     }
 }
 
+/// Construct the [`Time`], applying the [`LeapSecondPolicy`] if necessary. The returned
+/// [`DateAdjustment`] indicates whether a leap second rolled over into the next day.
+fn resolve_time(parsed: Parsed) -> Result<(DateAdjustment, Time), error::TryFromParsed> {
+    let hour = match (parsed.hour_24, parsed.hour_12, parsed.hour_12_is_pm) {
+        (Some(hour), _, _) => hour,
+        (_, Some(hour), Some(false)) if hour.get() == 12 => 0,
+        (_, Some(hour), Some(true)) if hour.get() == 12 => 12,
+        (_, Some(hour), Some(false)) => hour.get(),
+        (_, Some(hour), Some(true)) => hour.get() + 12,
+        _ => return Err(InsufficientInformation),
+    };
+    let minute = parsed.minute.ok_or(InsufficientInformation)?;
+    let second = parsed.second.unwrap_or(0);
+    let subsecond = parsed.subsecond.unwrap_or(0);
+
+    if second == 60 {
+        match parsed.leap_second_policy {
+            Some(LeapSecondPolicy::Clamp) => {
+                let time = Time::from_hms_nano(hour, minute, 59, 999_999_999)?;
+                return Ok((DateAdjustment::None, time));
+            }
+            Some(LeapSecondPolicy::RollOver) => {
+                let time = Time::from_hms_nano(hour, minute, 59, subsecond)?;
+                return Ok(time.adjusting_add(Duration::SECOND));
+            }
+            // The second is out of range, so constructing the time below fails.
+            Some(LeapSecondPolicy::Reject) | None => {}
+        }
+    }
+
+    Ok((
+        DateAdjustment::None,
+        Time::from_hms_nano(hour, minute, second, subsecond)?,
+    ))
+}
+
 impl TryFrom<Parsed> for UtcOffset {
     type Error = error::TryFromParsed;
 
     fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
         let hour = parsed.offset_hour.ok_or(InsufficientInformation)?;
-        let minute = parsed.offset_minute.unwrap_or(0);
-        let second = parsed.offset_second.unwrap_or(0);
-        Ok(Self::from_hms(hour, minute as i8, second as i8)?)
+        let mut minute = parsed.offset_minute.unwrap_or(0) as i8;
+        let mut second = parsed.offset_second.unwrap_or(0) as i8;
+        if parsed.offset_is_negative == Some(true) {
+            minute = -minute;
+            second = -second;
+        }
+        Ok(Self::from_hms(hour, minute, second)?)
     }
 }
 
@@ -261,7 +818,21 @@ impl TryFrom<Parsed> for PrimitiveDateTime {
     type Error = error::TryFromParsed;
 
     fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
-        Ok(Self::new(parsed.try_into()?, parsed.try_into()?))
+        let date: Date = parsed.try_into()?;
+        let (adjustment, time) = resolve_time(parsed)?;
+        let date = match adjustment {
+            DateAdjustment::Next => date.next_day().ok_or_else(|| {
+                error::TryFromParsed::ComponentRange(error::ComponentRange {
+                    name: "year",
+                    minimum: MIN_YEAR as _,
+                    maximum: MAX_YEAR as _,
+                    value: date.year() as i64 + 1,
+                    conditional_range: false,
+                })
+            })?,
+            DateAdjustment::Previous | DateAdjustment::None => date,
+        };
+        Ok(Self::new(date, time))
     }
 }
 
@@ -269,6 +840,77 @@ impl TryFrom<Parsed> for OffsetDateTime {
     type Error = error::TryFromParsed;
 
     fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
+        if let Some(mut timestamp) = parsed.unix_timestamp_nanos {
+            // A timestamp on a whole second is commonly followed by the subsecond, which is
+            // added to it. A more precise timestamp must agree with the subsecond, if present.
+            if let Some(subsecond) = parsed.subsecond {
+                let timestamp_subsecond = timestamp.rem_euclid(1_000_000_000) as u32;
+                if timestamp_subsecond == 0 {
+                    timestamp += subsecond as i128;
+                } else if timestamp_subsecond != subsecond {
+                    return Err(error::TryFromParsed::ComponentRange(
+                        error::ComponentRange {
+                            name: "subsecond",
+                            minimum: timestamp_subsecond as _,
+                            maximum: timestamp_subsecond as _,
+                            value: subsecond as _,
+                            conditional_range: true,
+                        },
+                    ));
+                }
+            }
+            let date_time = Self::from_unix_timestamp_nanos(timestamp)?;
+            // The offset is optional, as the timestamp alone identifies the instant.
+            if parsed.offset_hour.is_none() {
+                return Ok(date_time);
+            }
+            return Ok(date_time.to_offset(parsed.try_into()?));
+        }
+
         Ok(PrimitiveDateTime::try_from(parsed)?.assume_offset(parsed.try_into()?))
     }
 }
+
+impl TryFrom<Parsed> for Duration {
+    type Error = error::TryFromParsed;
+
+    fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
+        if parsed.duration_days.is_none()
+            && parsed.duration_hours.is_none()
+            && parsed.duration_minutes.is_none()
+            && parsed.duration_seconds.is_none()
+            && parsed.duration_subsecond.is_none()
+        {
+            return Err(InsufficientInformation);
+        }
+
+        // The components are summed in a wider type so that overflow can be detected afterwards.
+        let seconds = parsed.duration_days.unwrap_or(0) as i128 * 86_400
+            + parsed.duration_hours.unwrap_or(0) as i128 * 3_600
+            + parsed.duration_minutes.unwrap_or(0) as i128 * 60
+            + parsed.duration_seconds.unwrap_or(0) as i128;
+        let seconds = if parsed.duration_is_negative == Some(true) {
+            -seconds
+        } else {
+            seconds
+        };
+        if seconds < i64::MIN as i128 || seconds > i64::MAX as i128 {
+            return Err(error::TryFromParsed::ComponentRange(
+                error::ComponentRange {
+                    name: "duration",
+                    minimum: i64::MIN,
+                    maximum: i64::MAX,
+                    value: if seconds < 0 { i64::MIN } else { i64::MAX },
+                    conditional_range: false,
+                },
+            ));
+        }
+
+        let nanoseconds = parsed.duration_subsecond.unwrap_or(0) as i32;
+        Ok(if parsed.duration_is_negative == Some(true) {
+            Self::new(seconds as i64, -nanoseconds)
+        } else {
+            Self::new(seconds as i64, nanoseconds)
+        })
+    }
+}
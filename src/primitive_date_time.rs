@@ -1,16 +1,26 @@
+#[cfg(all(feature = "formatting", feature = "alloc"))]
+use alloc::string::String;
+#[cfg(feature = "parsing")]
+use core::convert::TryInto;
 #[cfg(feature = "formatting")]
 use core::fmt;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 use core::time::Duration as StdDuration;
-#[cfg(feature = "formatting")]
-use std::io;
+#[cfg(all(feature = "parsing", feature = "std"))]
+use std::io::{self, BufRead};
 
 #[cfg(feature = "parsing")]
 use crate::error;
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+use crate::format_description::locale::Locale;
 #[cfg(feature = "formatting")]
 use crate::formatting::formattable::sealed::Formattable;
+#[cfg(feature = "formatting")]
+use crate::formatting::{self, Output, SliceWriter};
 #[cfg(feature = "parsing")]
 use crate::parsing::parsable::sealed::Parsable;
+#[cfg(all(feature = "parsing", feature = "std"))]
+use crate::parsing::Parsed;
 use crate::{util, Date, Duration, OffsetDateTime, Time, UtcOffset, Weekday};
 
 /// Combined date and time.
@@ -510,7 +520,7 @@ impl PrimitiveDateTime {
     /// using [`format_description::parse`](crate::format_description::parse()).
     pub fn format_into<F: Formattable>(
         self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         format: &F,
     ) -> Result<usize, F::Error> {
         format.format_into(output, Some(self.date), Some(self.time), None)
@@ -529,9 +539,34 @@ impl PrimitiveDateTime {
     /// );
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
     pub fn format<F: Formattable>(self, format: &F) -> Result<String, F::Error> {
         format.format(Some(self.date), Some(self.time), None)
     }
+
+    /// Format the `PrimitiveDateTime` using the provided format description and the names provided
+    /// by the locale. The formatted value will be output to the provided writer.
+    pub fn format_into_with_locale<F: Formattable>(
+        self,
+        output: &mut impl Output,
+        format: &F,
+        locale: &dyn Locale,
+    ) -> Result<usize, F::Error> {
+        format.format_into_with_locale(output, Some(self.date), Some(self.time), None, locale)
+    }
+
+    /// Format the `PrimitiveDateTime` using the provided format description and the names provided
+    /// by the locale.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    pub fn format_with_locale<F: Formattable>(
+        self,
+        format: &F,
+        locale: &dyn Locale,
+    ) -> Result<String, F::Error> {
+        format.format_with_locale(Some(self.date), Some(self.time), None, locale)
+    }
 }
 
 #[cfg(feature = "parsing")]
@@ -553,13 +588,71 @@ impl PrimitiveDateTime {
     pub fn parse(input: &str, description: &impl Parsable) -> Result<Self, error::Parse> {
         description.parse_date_time(input.as_bytes())
     }
+
+    /// Parse a `PrimitiveDateTime` from the input using the provided format description and the
+    /// names provided by the locale.
+    pub fn parse_with_locale(
+        input: &str,
+        description: &impl Parsable,
+        locale: &dyn Locale,
+    ) -> Result<Self, error::Parse> {
+        Ok(description
+            .parse_with_locale(input.as_bytes(), locale)?
+            .try_into()?)
+    }
+
+    /// Parse a `PrimitiveDateTime` from the start of the input using the provided format description. The
+    /// remaining input is returned alongside the value.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime, PrimitiveDateTime};
+    /// let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]:[second]")?;
+    /// assert_eq!(
+    ///     PrimitiveDateTime::parse_prefix("2020-01-02 03:04:05 rest", &format)?,
+    ///     (datetime!("2020-01-02 03:04:05"), " rest")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &impl Parsable,
+    ) -> Result<(Self, &'a str), error::Parse> {
+        let (parsed, remaining) = description.parse_prefix(input)?;
+        Ok((parsed.try_into()?, remaining))
+    }
+
+    /// Parse a `PrimitiveDateTime` from the start of the reader using the provided format description. Only the
+    /// bytes that were parsed are consumed, so the remainder can be read afterwards. Unless the
+    /// value is at the end of the reader, it must be followed by at least 16 bytes in the reader's
+    /// buffer, as it could otherwise continue past the end of the buffer.
+    ///
+    /// Errors that occur while parsing are returned with [`io::ErrorKind::InvalidData`],
+    /// wrapping an [`error::Parse`]. If an error is returned, it is unspecified how many bytes
+    /// were consumed from the reader.
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+    pub fn parse_from_reader(
+        reader: &mut impl BufRead,
+        description: &impl Parsable,
+    ) -> io::Result<Self> {
+        let mut parsed = Parsed::new();
+        description.parse_into_from_reader(reader, &mut parsed)?;
+        parsed.try_into().map_err(|err| {
+            io::Error::new(io::ErrorKind::InvalidData, error::Parse::TryFromParsed(err))
+        })
+    }
 }
 
 #[cfg(feature = "formatting")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
 impl fmt::Display for PrimitiveDateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", self.date, self.time)
+        let mut buf = [0; 32];
+        let mut output = SliceWriter::new(&mut buf);
+        self.date.format_display_into(&mut output)?;
+        output.write_bytes(&[b' ']).map_err(|_| fmt::Error)?;
+        self.time.format_display_into(&mut output, f.precision())?;
+        formatting::pad(f, output.written())
     }
 }
 // endregion formatting & parsing
@@ -0,0 +1,77 @@
+//! Treat a [`Duration`] as an [ISO 8601 duration] for the purposes of serde.
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! Durations are serialized as strings such as `PT1H30M5.5S` or `P3DT4H`. When deserializing,
+//! years and months are rejected, as their length is not fixed.
+//!
+//! [ISO 8601 duration]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::format_description::well_known::Iso8601;
+use crate::Duration;
+
+/// The value expected when deserializing.
+const EXPECTED: &str = "an ISO 8601 duration without years or months";
+
+/// Serialize a `Duration` as an ISO 8601 duration
+pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    match duration.format(&Iso8601::DEFAULT) {
+        Ok(s) => serializer.serialize_str(&s),
+        Err(_) => Err(S::Error::custom("failed formatting `Duration`")),
+    }
+}
+
+/// Deserialize a `Duration` from an ISO 8601 duration
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<Duration, D::Error> {
+    let s = <&str>::deserialize(deserializer)?;
+    Duration::parse(s, &Iso8601::DEFAULT)
+        .map_err(|_| D::Error::invalid_value(serde::de::Unexpected::Str(s), &EXPECTED))
+}
+
+/// Treat an `Option<Duration>` as an [ISO 8601 duration] for the purposes of serde.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// [ISO 8601 duration]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// Serialize an `Option<Duration>` as an ISO 8601 duration
+    pub fn serialize<S: Serializer>(
+        option: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match option {
+            Some(duration) => serializer.serialize_some(&Wrapper(*duration)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize an `Option<Duration>` from an ISO 8601 duration
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Option::<&str>::deserialize(deserializer)?
+            .map(|s| {
+                Duration::parse(s, &Iso8601::DEFAULT)
+                    .map_err(|_| D::Error::invalid_value(serde::de::Unexpected::Str(s), &EXPECTED))
+            })
+            .transpose()
+    }
+
+    /// A wrapper to serialize the contained `Duration` as an ISO 8601 duration.
+    struct Wrapper(Duration);
+
+    impl serde::Serialize for Wrapper {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::serialize(&self.0, serializer)
+        }
+    }
+}
@@ -5,6 +5,9 @@
 // Types with guaranteed stable serde representations. Strings are avoided to allow for optimal
 // representations in various binary forms.
 
+#[cfg(feature = "serde-human-readable")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "serde-human-readable")))]
+pub mod iso8601_duration;
 pub mod timestamp;
 
 use serde::de::Error as _;
@@ -28,16 +31,19 @@ const DATE_FORMAT: &[FormatItem<'_>] = &[
         repr: modifier::YearRepr::Full,
         iso_week_based: false,
         sign_is_mandatory: false,
+        digits: 4,
         padding: modifier::Padding::Zero,
     })),
     FormatItem::Literal(b"-"),
     FormatItem::Component(Component::Month(modifier::Month {
         repr: modifier::MonthRepr::Numerical,
         padding: modifier::Padding::Zero,
+        case_sensitive: true,
     })),
     FormatItem::Literal(b"-"),
     FormatItem::Component(Component::Day(modifier::Day {
         padding: modifier::Padding::Zero,
+        suffix: modifier::Suffix::None,
     })),
 ];
 
@@ -258,6 +264,7 @@ const TIME_FORMAT: &[FormatItem<'_>] = &[
     FormatItem::Literal(b"."),
     FormatItem::Component(Component::Subsecond(modifier::Subsecond {
         digits: modifier::SubsecondDigits::OneOrMore,
+        rounding: modifier::SubsecondRounding::Truncate,
     })),
 ];
 
@@ -1,15 +1,25 @@
+#[cfg(all(feature = "formatting", feature = "alloc"))]
+use alloc::string::String;
+#[cfg(feature = "parsing")]
+use core::convert::TryInto;
 use core::fmt;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 use core::time::Duration as StdDuration;
-#[cfg(feature = "formatting")]
-use std::io;
+#[cfg(all(feature = "parsing", feature = "std"))]
+use std::io::{self, BufRead};
 
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+use crate::format_description::locale::Locale;
 #[cfg(feature = "formatting")]
 use crate::format_description::{modifier, Component, FormatItem};
 #[cfg(feature = "formatting")]
 use crate::formatting::formattable::sealed::Formattable;
+#[cfg(feature = "formatting")]
+use crate::formatting::{self, Output, SliceWriter};
 #[cfg(feature = "parsing")]
 use crate::parsing::parsable::sealed::Parsable;
+#[cfg(all(feature = "parsing", feature = "std"))]
+use crate::parsing::Parsed;
 use crate::util::DateAdjustment;
 use crate::{error, Duration};
 
@@ -436,7 +446,7 @@ impl Time {
     /// [`format_description::parse`](crate::format_description::parse()).
     pub fn format_into<F: Formattable>(
         self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         format: &F,
     ) -> Result<usize, F::Error> {
         format.format_into(output, None, Some(self), None)
@@ -452,9 +462,34 @@ impl Time {
     /// assert_eq!(time!("12:00").format(&format)?, "12:00:00");
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
     pub fn format<F: Formattable>(self, format: &F) -> Result<String, F::Error> {
         format.format(None, Some(self), None)
     }
+
+    /// Format the `Time` using the provided format description and the names provided by the
+    /// locale. The formatted value will be output to the provided writer.
+    pub fn format_into_with_locale<F: Formattable>(
+        self,
+        output: &mut impl Output,
+        format: &F,
+        locale: &dyn Locale,
+    ) -> Result<usize, F::Error> {
+        format.format_into_with_locale(output, None, Some(self), None, locale)
+    }
+
+    /// Format the `Time` using the provided format description and the names provided by the
+    /// locale.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    pub fn format_with_locale<F: Formattable>(
+        self,
+        format: &F,
+        locale: &dyn Locale,
+    ) -> Result<String, F::Error> {
+        format.format_with_locale(None, Some(self), None, locale)
+    }
 }
 
 #[cfg(feature = "parsing")]
@@ -473,14 +508,74 @@ impl Time {
     pub fn parse(input: &str, description: &impl Parsable) -> Result<Self, error::Parse> {
         description.parse_time(input.as_bytes())
     }
+
+    /// Parse a `Time` from the input using the provided format description and the names
+    /// provided by the locale.
+    pub fn parse_with_locale(
+        input: &str,
+        description: &impl Parsable,
+        locale: &dyn Locale,
+    ) -> Result<Self, error::Parse> {
+        Ok(description
+            .parse_with_locale(input.as_bytes(), locale)?
+            .try_into()?)
+    }
+
+    /// Parse a `Time` from the start of the input using the provided format description. The
+    /// remaining input is returned alongside the value.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::time, Time};
+    /// let format = format_description::parse("[hour]:[minute]:[second]")?;
+    /// assert_eq!(
+    ///     Time::parse_prefix("12:00:00 rest", &format)?,
+    ///     (time!("12:00"), " rest")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &impl Parsable,
+    ) -> Result<(Self, &'a str), error::Parse> {
+        let (parsed, remaining) = description.parse_prefix(input)?;
+        Ok((parsed.try_into()?, remaining))
+    }
+
+    /// Parse a `Time` from the start of the reader using the provided format description. Only the
+    /// bytes that were parsed are consumed, so the remainder can be read afterwards. Unless the
+    /// value is at the end of the reader, it must be followed by at least 16 bytes in the reader's
+    /// buffer, as it could otherwise continue past the end of the buffer.
+    ///
+    /// Errors that occur while parsing are returned with [`io::ErrorKind::InvalidData`],
+    /// wrapping an [`error::Parse`]. If an error is returned, it is unspecified how many bytes
+    /// were consumed from the reader.
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+    pub fn parse_from_reader(
+        reader: &mut impl BufRead,
+        description: &impl Parsable,
+    ) -> io::Result<Self> {
+        let mut parsed = Parsed::new();
+        description.parse_into_from_reader(reader, &mut parsed)?;
+        parsed.try_into().map_err(|err| {
+            io::Error::new(io::ErrorKind::InvalidData, error::Parse::TryFromParsed(err))
+        })
+    }
 }
 
 #[cfg(feature = "formatting")]
-#[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
-impl fmt::Display for Time {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        /// [hour]:[minute]:[second].[subsecond]
-        const FORMAT: &[FormatItem<'_>] = &[
+impl Time {
+    /// Format the `Time` into the output as done by its [`Display`](fmt::Display) implementation.
+    /// The precision is the number of subsecond digits, which are omitted entirely if it is zero.
+    /// Without a precision, as many digits as necessary are used.
+    pub(crate) fn format_display_into(
+        self,
+        output: &mut impl Output,
+        precision: Option<usize>,
+    ) -> fmt::Result {
+        let digits = formatting::subsecond_digits(precision);
+        // [hour]:[minute]:[second].[subsecond]
+        let format = [
             FormatItem::Component(Component::Hour(modifier::Hour {
                 padding: modifier::Padding::None,
                 is_12_hour_clock: false,
@@ -495,21 +590,38 @@ impl fmt::Display for Time {
             })),
             FormatItem::Literal(b"."),
             FormatItem::Component(Component::Subsecond(modifier::Subsecond {
-                digits: modifier::SubsecondDigits::OneOrMore,
+                digits,
+                rounding: modifier::SubsecondRounding::Truncate,
             })),
         ];
-        match self.format(&FORMAT) {
-            Ok(ref s) => f.write_str(s),
+        let format = if precision == Some(0) {
+            &format[..5]
+        } else {
+            &format[..]
+        };
+        match self.format_into(output, &format) {
+            Ok(_) => Ok(()),
             Err(error::Format::InvalidComponent(_)) => {
                 unreachable!("A well-known format is not used")
             }
             Err(error::Format::InsufficientTypeInformation) => {
                 unreachable!("All components used only require a `Time`")
             }
-            Err(error::Format::StdIo(_)) => Err(fmt::Error),
+            Err(_) => Err(fmt::Error),
         }
     }
 }
+
+#[cfg(feature = "formatting")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; 18];
+        let mut output = SliceWriter::new(&mut buf);
+        self.format_display_into(&mut output, f.precision())?;
+        formatting::pad(f, output.written())
+    }
+}
 // endregion formatting & parsing
 
 // region: trait impls
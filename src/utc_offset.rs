@@ -1,24 +1,35 @@
-#[cfg(all(
-    any(
-        all(target_family = "unix", unsound_local_offset),
-        target_family = "windows"
-    ),
-    feature = "local-offset"
+#[cfg(all(feature = "formatting", feature = "alloc"))]
+use alloc::string::String;
+#[cfg(any(
+    feature = "parsing",
+    all(
+        any(
+            all(target_family = "unix", unsound_local_offset),
+            target_family = "windows"
+        ),
+        feature = "local-offset"
+    )
 ))]
 use core::convert::TryInto;
 #[cfg(feature = "formatting")]
 use core::fmt;
 use core::ops::Neg;
-#[cfg(feature = "formatting")]
-use std::io;
+#[cfg(all(feature = "parsing", feature = "std"))]
+use std::io::{self, BufRead};
 
 use crate::error;
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+use crate::format_description::locale::Locale;
 #[cfg(feature = "formatting")]
 use crate::format_description::{modifier, Component, FormatItem};
 #[cfg(feature = "formatting")]
 use crate::formatting::formattable::sealed::Formattable;
+#[cfg(feature = "formatting")]
+use crate::formatting::{self, Output, SliceWriter};
 #[cfg(feature = "parsing")]
 use crate::parsing::parsable::sealed::Parsable;
+#[cfg(all(feature = "parsing", feature = "std"))]
+use crate::parsing::Parsed;
 #[cfg(feature = "local-offset")]
 use crate::OffsetDateTime;
 
@@ -259,7 +270,7 @@ impl UtcOffset {
     /// [`format_description::parse`](crate::format_description::parse()).
     pub fn format_into<F: Formattable>(
         self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         format: &F,
     ) -> Result<usize, F::Error> {
         format.format_into(output, None, None, Some(self))
@@ -275,9 +286,34 @@ impl UtcOffset {
     /// assert_eq!(offset!("+1").format(&format)?, "+01:00");
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
     pub fn format<F: Formattable>(self, format: &F) -> Result<String, F::Error> {
         format.format(None, None, Some(self))
     }
+
+    /// Format the `UtcOffset` using the provided format description and the names provided by
+    /// the locale. The formatted value will be output to the provided writer.
+    pub fn format_into_with_locale<F: Formattable>(
+        self,
+        output: &mut impl Output,
+        format: &F,
+        locale: &dyn Locale,
+    ) -> Result<usize, F::Error> {
+        format.format_into_with_locale(output, None, None, Some(self), locale)
+    }
+
+    /// Format the `UtcOffset` using the provided format description and the names provided by
+    /// the locale.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    pub fn format_with_locale<F: Formattable>(
+        self,
+        format: &F,
+        locale: &dyn Locale,
+    ) -> Result<String, F::Error> {
+        format.format_with_locale(None, None, Some(self), locale)
+    }
 }
 
 #[cfg(feature = "parsing")]
@@ -296,12 +332,66 @@ impl UtcOffset {
     pub fn parse(input: &str, description: &impl Parsable) -> Result<Self, error::Parse> {
         description.parse_offset(input.as_bytes())
     }
+
+    /// Parse a `UtcOffset` from the input using the provided format description and the names
+    /// provided by the locale.
+    pub fn parse_with_locale(
+        input: &str,
+        description: &impl Parsable,
+        locale: &dyn Locale,
+    ) -> Result<Self, error::Parse> {
+        Ok(description
+            .parse_with_locale(input.as_bytes(), locale)?
+            .try_into()?)
+    }
+
+    /// Parse a `UtcOffset` from the start of the input using the provided format description. The
+    /// remaining input is returned alongside the value.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::offset, UtcOffset};
+    /// let format = format_description::parse("[offset_hour]:[offset_minute]")?;
+    /// assert_eq!(
+    ///     UtcOffset::parse_prefix("-03:42 rest", &format)?,
+    ///     (offset!("-3:42"), " rest")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &impl Parsable,
+    ) -> Result<(Self, &'a str), error::Parse> {
+        let (parsed, remaining) = description.parse_prefix(input)?;
+        Ok((parsed.try_into()?, remaining))
+    }
+
+    /// Parse a `UtcOffset` from the start of the reader using the provided format description. Only the
+    /// bytes that were parsed are consumed, so the remainder can be read afterwards. Unless the
+    /// value is at the end of the reader, it must be followed by at least 16 bytes in the reader's
+    /// buffer, as it could otherwise continue past the end of the buffer.
+    ///
+    /// Errors that occur while parsing are returned with [`io::ErrorKind::InvalidData`],
+    /// wrapping an [`error::Parse`]. If an error is returned, it is unspecified how many bytes
+    /// were consumed from the reader.
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+    pub fn parse_from_reader(
+        reader: &mut impl BufRead,
+        description: &impl Parsable,
+    ) -> io::Result<Self> {
+        let mut parsed = Parsed::new();
+        description.parse_into_from_reader(reader, &mut parsed)?;
+        parsed.try_into().map_err(|err| {
+            io::Error::new(io::ErrorKind::InvalidData, error::Parse::TryFromParsed(err))
+        })
+    }
 }
 
 #[cfg(feature = "formatting")]
-#[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
-impl fmt::Display for UtcOffset {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl UtcOffset {
+    /// Format the `UtcOffset` into the output as done by its [`Display`](fmt::Display)
+    /// implementation.
+    pub(crate) fn format_display_into(self, output: &mut impl Output) -> fmt::Result {
         /// [offset_hour sign:mandatory]:[offset_minute]:[offset_second]
         const FORMAT: &[FormatItem<'_>] = &[
             FormatItem::Component(Component::OffsetHour(modifier::OffsetHour {
@@ -317,18 +407,29 @@ impl fmt::Display for UtcOffset {
                 padding: modifier::Padding::Zero,
             })),
         ];
-        match self.format(&FORMAT) {
-            Ok(ref s) => f.write_str(s),
+        match self.format_into(output, &FORMAT) {
+            Ok(_) => Ok(()),
             Err(error::Format::InvalidComponent(_)) => {
                 unreachable!("A well-known format is not used")
             }
             Err(error::Format::InsufficientTypeInformation) => {
                 unreachable!("All components used only require a `UtcOffset`")
             }
-            Err(error::Format::StdIo(_)) => Err(fmt::Error),
+            Err(_) => Err(fmt::Error),
         }
     }
 }
+
+#[cfg(feature = "formatting")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
+impl fmt::Display for UtcOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; 9];
+        let mut output = SliceWriter::new(&mut buf);
+        self.format_display_into(&mut output)?;
+        formatting::pad(f, output.written())
+    }
+}
 // endregion formatting & parsing
 
 impl Neg for UtcOffset {
@@ -1,10 +1,10 @@
 use std::error::Error as _;
 
 use time::error::{
-    ComponentRange, ConversionRange, Error, Format, IndeterminateOffset, InvalidFormatDescription,
-    Parse, ParseFromDescription, TryFromParsed,
+    BufferOverflow, ComponentRange, ConversionRange, Error, Format, IndeterminateOffset,
+    InvalidFormatDescription, Parse, ParseFromDescription, TryFromParsed,
 };
-use time::format_description::modifier::{self, Padding};
+use time::format_description::modifier::{self, Padding, Suffix};
 use time::format_description::{Component, FormatItem};
 use time::macros::format_description;
 use time::{Date, Time};
@@ -38,6 +38,10 @@ fn unexpected_trailing_characters() -> Parse {
     Time::parse("a", &format_description!("")).unwrap_err()
 }
 
+fn unexpected_partial_character() -> Parse {
+    Time::parse_prefix("é", &format_description!("[ignore count:1]")).unwrap_err()
+}
+
 #[test]
 fn debug() {
     assert_eq!(format!("{:?}", FormatItem::Literal(b"abcdef")), "abcdef");
@@ -45,13 +49,15 @@ fn debug() {
         format!(
             "{:?}",
             FormatItem::Compound(&[FormatItem::Component(Component::Day(modifier::Day {
-                padding: Padding::Zero
+                padding: Padding::Zero,
+                suffix: Suffix::None
             }))])
         ),
         format!(
             "{:?}",
             FormatItem::Compound(&[FormatItem::Component(Component::Day(modifier::Day {
-                padding: Padding::Zero
+                padding: Padding::Zero,
+                suffix: Suffix::None
             }))])
         )
     );
@@ -75,6 +81,9 @@ fn display() {
         Format::InvalidComponent("a"),
         Error::from(Format::InvalidComponent("a"))
     );
+    assert_display_eq!(BufferOverflow, Format::from(BufferOverflow));
+    assert_display_eq!(BufferOverflow, Error::from(BufferOverflow));
+    assert_display_eq!(std::fmt::Error, Format::from(std::fmt::Error));
     assert_display_eq!(
         ParseFromDescription::InvalidComponent("a"),
         Error::from(Parse::from(ParseFromDescription::InvalidComponent("a")))
@@ -95,10 +104,18 @@ fn display() {
         unexpected_trailing_characters(),
         Error::from(unexpected_trailing_characters()),
     );
+    assert_display_eq!(
+        unexpected_partial_character(),
+        Error::from(unexpected_partial_character()),
+    );
     assert_display_eq!(
         InvalidFormatDescription::UnclosedOpeningBracket { index: 0 },
         Error::from(InvalidFormatDescription::UnclosedOpeningBracket { index: 0 })
     );
+    assert_eq!(
+        ParseFromDescription::NoMatchingAlternative { count: 2 }.to_string(),
+        "none of the 2 alternatives could be parsed"
+    );
 }
 
 #[test]
@@ -109,6 +126,12 @@ fn source() {
     assert_source!(TryFromParsed::InsufficientInformation, None);
     assert_source!(insufficient_type_information(), None);
     assert_source!(Format::InvalidComponent("a"), None);
+    assert_source!(Format::from(BufferOverflow), BufferOverflow);
+    assert_source!(Format::from(std::fmt::Error), std::fmt::Error);
+    assert_source!(
+        Format::from(std::io::Error::from(std::io::ErrorKind::Other)),
+        std::io::Error
+    );
     assert_source!(Error::from(insufficient_type_information()), Format);
     assert_source!(Error::from(IndeterminateOffset), IndeterminateOffset);
     assert_source!(
@@ -129,6 +152,8 @@ fn source() {
     );
     assert_source!(unexpected_trailing_characters(), None);
     assert_source!(Error::from(unexpected_trailing_characters()), None);
+    assert_source!(unexpected_partial_character(), None);
+    assert_source!(Error::from(unexpected_partial_character()), None);
     assert_source!(
         Error::from(InvalidFormatDescription::UnclosedOpeningBracket { index: 0 }),
         InvalidFormatDescription
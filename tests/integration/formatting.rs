@@ -1,8 +1,44 @@
 use std::io;
 
-use time::format_description::well_known::Rfc3339;
+use time::format_description::well_known::iso8601::{
+    Config, DateKind, FormattedComponents, OffsetPrecision, TimePrecision,
+};
+use time::format_description::locale::{English, Locale};
+use time::format_description::modifier::EraRepr;
+use time::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
 use time::macros::{date, datetime, format_description as fd, offset, time};
-use time::{format_description, Time};
+use time::error::BufferOverflow;
+use time::formatting::relative::{Relative, Rounding, Unit};
+use time::formatting::{FmtWrite, SliceWriter};
+use time::ext::NumericalDuration;
+use time::{format_description, Duration, Time};
+
+#[test]
+fn rfc_2822() -> time::Result<()> {
+    assert_eq!(
+        datetime!("2021-01-02 03:04:05 UTC").format(&Rfc2822)?,
+        "Sat, 02 Jan 2021 03:04:05 +0000"
+    );
+    assert_eq!(
+        datetime!("2003-07-01 10:52:37 +02:00").format(&Rfc2822)?,
+        "Tue, 01 Jul 2003 10:52:37 +0200"
+    );
+    assert_eq!(
+        datetime!("1969-02-13 23:32 -03:30").format(&Rfc2822)?,
+        "Thu, 13 Feb 1969 23:32:00 -0330"
+    );
+
+    assert!(matches!(
+        datetime!("1899-12-31 0:00 UTC").format(&Rfc2822),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!("2021-01-02 0:00 +00:00:01").format(&Rfc2822),
+        Err(time::error::Format::InvalidComponent("offset_second"))
+    ));
+
+    Ok(())
+}
 
 #[test]
 fn rfc_3339() -> time::Result<()> {
@@ -67,6 +103,148 @@ fn rfc_3339() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn iso_8601_duration() -> time::Result<()> {
+    let iso = Iso8601::DEFAULT;
+    assert_eq!(Duration::ZERO.format(&iso)?, "PT0S");
+    assert_eq!(
+        Duration::new(5_405, 500_000_000).format(&iso)?,
+        "PT1H30M5.5S"
+    );
+    assert_eq!(Duration::hours(76).format(&iso)?, "P3DT4H");
+    assert_eq!(Duration::days(3).format(&iso)?, "P3D");
+    assert_eq!(Duration::minutes(-90).format(&iso)?, "-PT1H30M");
+    assert_eq!(Duration::nanoseconds(-1).format(&iso)?, "-PT0.000000001S");
+    assert_eq!(
+        Duration::MAX.format(&iso)?,
+        "P106751991167300DT15H30M7.999999999S"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn iso_8601() -> time::Result<()> {
+    macro_rules! iso {
+        ($($method:ident($value:expr)).*) => {
+            Iso8601::with_config(Config::DEFAULT$(.$method($value))*)
+        };
+    }
+
+    assert_eq!(
+        datetime!("2021-03-15 10:15:30.123_456_789 +01:30").format(&Iso8601::DEFAULT)?,
+        "2021-03-15T10:15:30.123456789+01:30"
+    );
+    assert_eq!(
+        datetime!("2021-03-15 10:15 UTC").format(&Iso8601::DEFAULT)?,
+        "2021-03-15T10:15:00.000000000Z"
+    );
+    assert_eq!(
+        datetime!("2021-03-15 10:15 UTC").format(&iso!(set_use_separators(false)
+            .set_time_precision(TimePrecision::Second { decimal_digits: 0 })))?,
+        "20210315T101500Z"
+    );
+    assert_eq!(
+        datetime!("2021-03-15 10:15 -05:00").format(&iso!(
+            set_date_kind(DateKind::Ordinal).set_offset_precision(OffsetPrecision::Hour)
+        ))?,
+        "2021-074T10:15:00.000000000-05"
+    );
+    assert_eq!(
+        datetime!("2021-03-15 10:15 -05:00").format(&iso!(
+            set_date_kind(DateKind::Week).set_use_separators(false)
+        ))?,
+        "2021W111T101500.000000000-0500"
+    );
+    assert_eq!(
+        datetime!("2021-01-01 0:00 UTC").format(&iso!(
+            set_date_kind(DateKind::Week).set_formatted_components(FormattedComponents::Date)
+        ))?,
+        "2020-W53-5"
+    );
+    assert_eq!(
+        time!("10:15:30").format(&iso!(set_formatted_components(FormattedComponents::Time)
+            .set_time_precision(TimePrecision::Minute { decimal_digits: 0 })))?,
+        "T10:15"
+    );
+    assert_eq!(
+        time!("10:15:30").format(&iso!(set_formatted_components(FormattedComponents::Time)
+            .set_time_precision(TimePrecision::Minute { decimal_digits: 1 })))?,
+        "T10:15.5"
+    );
+    assert_eq!(
+        time!("10:15:30").format(&iso!(set_formatted_components(FormattedComponents::Time)
+            .set_time_precision(TimePrecision::Hour { decimal_digits: 4 })))?,
+        "T10.2583"
+    );
+    assert_eq!(
+        time!("10:15:30.5").format(&iso!(set_formatted_components(FormattedComponents::Time)
+            .set_time_precision(TimePrecision::Second { decimal_digits: 3 })))?,
+        "T10:15:30.500"
+    );
+    assert_eq!(
+        offset!("-01:30").format(&iso!(set_formatted_components(FormattedComponents::Offset)))?,
+        "-01:30"
+    );
+    assert_eq!(
+        datetime!("2021-03-15 10:15").format(&iso!(set_formatted_components(
+            FormattedComponents::DateTime
+        )))?,
+        "2021-03-15T10:15:00.000000000"
+    );
+    assert_eq!(
+        date!("2021-03-15").format(&iso!(
+            set_formatted_components(FormattedComponents::Date).set_year_digits(6)
+        ))?,
+        "+002021-03-15"
+    );
+    assert_eq!(
+        date!("-0001-01-01").format(&iso!(set_formatted_components(FormattedComponents::Date)
+            .set_year_digits(5)
+            .set_use_separators(false)))?,
+        "-000010101"
+    );
+    assert_eq!(
+        date!("2021-03-15").format(&iso!(
+            set_formatted_components(FormattedComponents::Date).set_year_digits(10)
+        ))?,
+        "+002021-03-15"
+    );
+    assert_eq!(
+        date!("2021-03-15").format(&iso!(
+            set_formatted_components(FormattedComponents::Date).set_year_digits(0)
+        ))?,
+        "2021-03-15"
+    );
+
+    assert!(matches!(
+        datetime!("-0001-01-01 0:00 UTC").format(&Iso8601::DEFAULT),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!("2021-01-01 0:00 +01:30")
+            .format(&iso!(set_offset_precision(OffsetPrecision::Hour))),
+        Err(time::error::Format::InvalidComponent("offset_minute"))
+    ));
+    assert!(matches!(
+        datetime!("2021-01-01 0:00 +00:00:01").format(&Iso8601::DEFAULT),
+        Err(time::error::Format::InvalidComponent("offset_second"))
+    ));
+    assert!(matches!(
+        datetime!("2021-01-01 0:00").format(&Iso8601::DEFAULT),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    #[cfg(feature = "large-dates")]
+    assert!(matches!(
+        date!("+123456-01-01").format(&iso!(
+            set_formatted_components(FormattedComponents::Date).set_year_digits(5)
+        )),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn format_time() -> time::Result<()> {
     let format_output = [
@@ -100,6 +278,10 @@ fn format_time() -> time::Result<()> {
         (fd!("[subsecond digits:8]"), "45678901"),
         (fd!("[subsecond digits:9]"), "456789012"),
         (fd!("[subsecond digits:1+]"), "456789012"),
+        (fd!("[subsecond digits:2-6]"), "456789"),
+        (fd!("[subsecond digits:3 rounding:half_up]"), "457"),
+        (fd!("[subsecond digits:3 rounding:half_even]"), "457"),
+        (fd!("[subsecond digits:8 rounding:truncate]"), "45678901"),
     ];
 
     for &(format_description, output) in &format_output {
@@ -119,6 +301,96 @@ fn format_time() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn subsecond_rounding() -> time::Result<()> {
+    assert_eq!(
+        time!("10:00:00.9996").format(&fd!("[second].[subsecond digits:3]"))?,
+        "00.999"
+    );
+    assert_eq!(
+        time!("10:00:00.9996").format(&fd!("[second].[subsecond digits:3 rounding:half_up]"))?,
+        "01.000"
+    );
+    assert_eq!(
+        time!("10:00:00.0125").format(&fd!("[subsecond digits:3 rounding:half_up]"))?,
+        "013"
+    );
+    assert_eq!(
+        time!("10:00:00.0125").format(&fd!("[subsecond digits:3 rounding:half_even]"))?,
+        "012"
+    );
+    assert_eq!(
+        time!("10:00:00.0135").format(&fd!("[subsecond digits:3 rounding:half_even]"))?,
+        "014"
+    );
+    assert_eq!(
+        time!("10:00:00.01251").format(&fd!("[subsecond digits:3 rounding:half_even]"))?,
+        "013"
+    );
+    assert_eq!(
+        time!("10:00:00.5").format(&fd!("[subsecond digits:3-9]"))?,
+        "500"
+    );
+    assert_eq!(
+        time!("10:00:00.5").format(&fd!("[subsecond digits:1-9]"))?,
+        "5"
+    );
+    assert_eq!(
+        time!("10:00:00.123_456_7").format(&fd!("[subsecond digits:1-6 rounding:half_even]"))?,
+        "123457"
+    );
+    assert_eq!(
+        time!("10:00:00.999_999_9")
+            .format(&fd!("[second].[subsecond digits:1-6 rounding:half_up]"))?,
+        "01.0"
+    );
+
+    // Rounding carries into every larger component.
+    let format =
+        fd!("[year]-[month]-[day] [hour]:[minute]:[second].[subsecond digits:3 rounding:half_up]");
+    assert_eq!(
+        datetime!("2021-12-31 23:59:59.9996").format(&format)?,
+        "2022-01-01 00:00:00.000"
+    );
+    assert_eq!(
+        datetime!("2021-12-31 23:59:59.9996 +01:00").format(&format)?,
+        "2022-01-01 00:00:00.000"
+    );
+    assert_eq!(
+        time!("23:59:59.9996").format(&fd!(
+            "[hour]:[minute]:[second].[subsecond digits:3 rounding:half_up]"
+        ))?,
+        "00:00:00.000"
+    );
+    // Only the first rounding component is honored; later ones truncate the rounded value.
+    assert_eq!(
+        time!("10:00:00.15").format(&fd!(
+            "[subsecond digits:2 rounding:half_up] [subsecond digits:1 rounding:half_up]"
+        ))?,
+        "15 1"
+    );
+    assert_eq!(
+        time!("10:00:00.15").format(&format_description::parse_owned(
+            "[subsecond digits:1 rounding:half_up] [subsecond digits:2 rounding:half_up]"
+        )?)?,
+        "2 20"
+    );
+    assert_eq!(
+        time!("10:00:59.96").format(&format_description::parse_owned(
+            "[hour]:[minute]:[second][optional [.[subsecond digits:1 rounding:half_up]]]"
+        )?)?,
+        "10:01:00.0"
+    );
+    assert!(matches!(
+        time::Date::MAX
+            .with_hms_nano(23, 59, 59, 999_999_999)?
+            .format(&format),
+        Err(time::error::Format::InvalidComponent("subsecond"))
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn display_time() {
     assert_eq!(time!("0:00").to_string(), "0:00:00.0");
@@ -131,6 +403,19 @@ fn display_time() {
         time!("0:00:00.000_000_001").to_string(),
         "0:00:00.000000001"
     );
+
+    assert_eq!(format!("{:>12}", time!("1:02:03")), "   1:02:03.0");
+    assert_eq!(format!("{:*<12}", time!("1:02:03")), "1:02:03.0***");
+    assert_eq!(format!("{:^12}", time!("1:02:03")), " 1:02:03.0  ");
+    assert_eq!(format!("{:4}", time!("1:02:03")), "1:02:03.0");
+    assert_eq!(format!("{:.0}", time!("1:02:03.456")), "1:02:03");
+    assert_eq!(format!("{:.3}", time!("1:02:03.456_789")), "1:02:03.456");
+    assert_eq!(format!("{:.3}", time!("1:02:03")), "1:02:03.000");
+    assert_eq!(
+        format!("{:.12}", time!("1:02:03.456_789")),
+        "1:02:03.456789000"
+    );
+    assert_eq!(format!("{:>10.1}", time!("1:02:03.456")), " 1:02:03.4");
 }
 
 #[test]
@@ -140,9 +425,11 @@ fn format_date() -> time::Result<()> {
         (fd!("[month]"), "12"),
         (fd!("[month repr:short]"), "Dec"),
         (fd!("[month repr:long]"), "December"),
+        (fd!("[month repr:narrow]"), "D"),
         (fd!("[ordinal]"), "365"),
         (fd!("[weekday]"), "Tuesday"),
         (fd!("[weekday repr:short]"), "Tue"),
+        (fd!("[weekday repr:narrow]"), "T"),
         (fd!("[weekday repr:sunday]"), "3"),
         (fd!("[weekday repr:sunday one_indexed:false]"), "2"),
         (fd!("[weekday repr:monday]"), "2"),
@@ -158,6 +445,11 @@ fn format_date() -> time::Result<()> {
         (fd!("[year base:iso_week sign:mandatory]"), "+2020"),
         (fd!("[year repr:last_two]"), "19"),
         (fd!("[year base:iso_week repr:last_two]"), "20"),
+        (fd!("[quarter]"), "4"),
+        (fd!("[week_of_month]"), "6"),
+        (fd!("[week_of_quarter]"), "14"),
+        (fd!("[weekday_in_month]"), "5"),
+        (fd!("[century]"), "20"),
     ];
 
     for &(format_description, output) in &format_output {
@@ -172,6 +464,153 @@ fn format_date() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn ordinal_suffix() -> time::Result<()> {
+    let day = fd!("[day padding:none suffix:ordinal]");
+    let day_output = [
+        (date!("2021-03-01"), "1st"),
+        (date!("2021-03-02"), "2nd"),
+        (date!("2021-03-03"), "3rd"),
+        (date!("2021-03-04"), "4th"),
+        (date!("2021-03-11"), "11th"),
+        (date!("2021-03-12"), "12th"),
+        (date!("2021-03-13"), "13th"),
+        (date!("2021-03-21"), "21st"),
+        (date!("2021-03-22"), "22nd"),
+        (date!("2021-03-23"), "23rd"),
+        (date!("2021-03-30"), "30th"),
+    ];
+    for &(date, output) in &day_output {
+        assert_eq!(date.format(&day)?, output);
+    }
+
+    assert_eq!(
+        date!("2021-03-03").format(&fd!(
+            "[month repr:long] [day padding:none suffix:ordinal], [year]"
+        ))?,
+        "March 3rd, 2021"
+    );
+    assert_eq!(
+        date!("2021-03-02").format(&fd!("[day suffix:ordinal]"))?,
+        "02nd"
+    );
+    assert_eq!(date!("2021-03-02").format(&fd!("[day suffix:none]"))?, "02");
+    assert_eq!(
+        date!("2021-101").format(&fd!("[ordinal padding:none suffix:ordinal]"))?,
+        "101st"
+    );
+    assert_eq!(
+        date!("2021-111").format(&fd!("[ordinal padding:none suffix:ordinal]"))?,
+        "111th"
+    );
+    assert_eq!(
+        date!("2021-112").format(&fd!("[ordinal suffix:ordinal] day"))?,
+        "112th day"
+    );
+    assert_eq!(
+        date!("2021-01-14").format(&fd!("[week_number padding:none suffix:ordinal] week"))?,
+        "2nd week"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn calendar_components() -> time::Result<()> {
+    assert_eq!(
+        date!("2021-08-15").format(&fd!("Q[quarter] [year]"))?,
+        "Q3 2021"
+    );
+    assert_eq!(
+        date!("2021-03-09").format(&fd!("week [week_of_month] of [month repr:long]"))?,
+        "week 2 of March"
+    );
+    assert_eq!(
+        date!("2021-03-09").format(&fd!(
+            "[weekday_in_month suffix:ordinal] [weekday] of [month repr:long]"
+        ))?,
+        "2nd Tuesday of March"
+    );
+    assert_eq!(
+        date!("2021-03-01").format(&fd!("[week_of_month] [weekday_in_month]"))?,
+        "1 1"
+    );
+    assert_eq!(
+        date!("2021-07-06").format(&fd!("Q[quarter] week [week_of_quarter padding:none]"))?,
+        "Q3 week 2"
+    );
+    assert_eq!(
+        date!("2021-03-31").format(&fd!("Q[quarter] week [week_of_quarter]"))?,
+        "Q1 week 14"
+    );
+    assert_eq!(
+        date!("2021-05-01").format(&fd!("[week_of_month] [weekday_in_month]"))?,
+        "1 1"
+    );
+    assert_eq!(
+        date!("2021-05-03").format(&fd!("[week_of_month] [weekday_in_month]"))?,
+        "2 1"
+    );
+    assert_eq!(
+        date!("2021-05-31").format(&fd!("[week_of_month] [weekday_in_month]"))?,
+        "6 5"
+    );
+    assert_eq!(
+        date!("2021-01-01").format(&fd!("[century suffix:ordinal] century"))?,
+        "20th century"
+    );
+    assert_eq!(
+        date!("0101-01-01").format(&fd!("[century padding:none suffix:ordinal]"))?,
+        "1st"
+    );
+    assert_eq!(date!("0999-01-01").format(&fd!("[century]"))?, "09");
+    assert_eq!(date!("-0150-01-01").format(&fd!("[century]"))?, "-01");
+    assert_eq!(
+        date!("-0050-01-01").format(&fd!("[century][year repr:last_two]"))?,
+        "-0050"
+    );
+    assert_eq!(
+        date!("2021-01-01").format(&fd!("[quarter padding:space suffix:ordinal]"))?,
+        "1st"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn historical_years() -> time::Result<()> {
+    assert_eq!(
+        date!("-0043-03-15").format(&fd!("[year repr:historical padding:none] [era]"))?,
+        "44 BC"
+    );
+    assert_eq!(
+        date!("0000-12-31").format(&fd!("[year repr:historical] [era repr:ce]"))?,
+        "0001 BCE"
+    );
+    assert_eq!(
+        date!("0001-01-01").format(&fd!("[year repr:historical] [era repr:ce]"))?,
+        "0001 CE"
+    );
+    assert_eq!(
+        date!("2021-01-01").format(&fd!("[era] [year repr:historical]"))?,
+        "AD 2021"
+    );
+    assert_eq!(
+        date!("2021-01-01").format(&fd!("[year digits:6]"))?,
+        "+002021"
+    );
+    assert_eq!(
+        date!("-0044-01-01").format(&fd!("[year digits:5]"))?,
+        "-00044"
+    );
+    assert_eq!(
+        date!("2021-01-01").format(&fd!("[year repr:last_two digits:6]"))?,
+        "21"
+    );
+
+    Ok(())
+}
+
 #[test]
 fn display_date() {
     assert_eq!(date!("2019-01-01").to_string(), "2019-01-01");
@@ -183,6 +622,10 @@ fn display_date() {
     assert_eq!(date!("+100_000-01-01").to_string(), "+100000-01-01");
     assert_eq!(date!("-10_000-01-01").to_string(), "-10000-01-01");
     assert_eq!(date!("-100_000-01-01").to_string(), "-100000-01-01");
+
+    assert_eq!(format!("{:>12}", date!("2019-01-01")), "  2019-01-01");
+    assert_eq!(format!("{:-<12}", date!("2019-01-01")), "2019-01-01--");
+    assert_eq!(format!("{:.3}", date!("2019-01-01")), "2019-01-01");
 }
 
 #[test]
@@ -235,6 +678,162 @@ fn display_offset() {
     assert_eq!(offset!("-23:59").to_string(), "-23:59:00");
     assert_eq!(offset!("+23:59:59").to_string(), "+23:59:59");
     assert_eq!(offset!("-23:59:59").to_string(), "-23:59:59");
+
+    assert_eq!(format!("{:>10}", offset!("+1")), " +01:00:00");
+    assert_eq!(format!("{:_^11}", offset!("+1")), "_+01:00:00_");
+}
+
+#[test]
+fn offset_abbreviation() -> time::Result<()> {
+    let format_description = fd!("[offset_abbreviation]");
+    assert_eq!(offset!("UTC").format(&format_description)?, "UTC");
+    assert_eq!(offset!("-5").format(&format_description)?, "EST");
+    assert_eq!(offset!("-4").format(&format_description)?, "EDT");
+    assert_eq!(offset!("+2").format(&format_description)?, "EET");
+    assert_eq!(offset!("+13").format(&format_description)?, "NZDT");
+    assert_eq!(offset!("+5:30").format(&format_description)?, "IST");
+    assert_eq!(
+        datetime!("2021-07-04 12:00 -7").format(&fd!("[hour]:[minute] [offset_abbreviation]"))?,
+        "12:00 MST"
+    );
+    assert_eq!(
+        offset!("+1").format_with_locale(&format_description, &English)?,
+        "CET"
+    );
+    assert!(matches!(
+        offset!("+1:23").format(&format_description),
+        Err(time::error::Format::InvalidComponent("offset_abbreviation"))
+    ));
+    assert!(matches!(
+        date!("2021-01-01").format(&format_description),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn offset() -> time::Result<()> {
+    let format_description = fd!("[offset]");
+    assert_eq!(offset!("UTC").format(&format_description)?, "Z");
+    assert_eq!(offset!("+5:30").format(&format_description)?, "+05:30");
+    assert_eq!(offset!("-0:30").format(&format_description)?, "-00:30");
+    assert_eq!(offset!("-8").format(&format_description)?, "-08:00");
+    assert_eq!(
+        offset!("UTC").format(&fd!("[offset zulu:false]"))?,
+        "+00:00"
+    );
+    assert_eq!(
+        offset!("+5:30").format(&fd!("[offset separator:none]"))?,
+        "+0530"
+    );
+    assert_eq!(
+        offset!("+5:30").format(&fd!("[offset separator:optional]"))?,
+        "+05:30"
+    );
+    assert_eq!(
+        offset!("-8").format(&fd!("[offset minute:optional]"))?,
+        "-08"
+    );
+    assert_eq!(
+        offset!("-8:30").format(&fd!("[offset minute:optional]"))?,
+        "-08:30"
+    );
+    assert_eq!(
+        datetime!("2021-07-04 12:00 UTC")
+            .format(&fd!("[year]-[month]-[day]T[hour]:[minute]:[second][offset]"))?,
+        "2021-07-04T12:00:00Z"
+    );
+    assert!(matches!(
+        offset!("+1:02:03").format(&format_description),
+        Err(time::error::Format::InvalidComponent("offset_second"))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn format_duration() -> time::Result<()> {
+    let stopwatch = fd!("[duration_sign][duration_hour \
+                         repr:total]:[duration_minute]:[duration_second].[duration_subsecond \
+                         digits:3]");
+    assert_eq!(Duration::ZERO.format(&stopwatch)?, "00:00:00.000");
+    assert_eq!(
+        Duration::new(5_025, 123_456_789).format(&stopwatch)?,
+        "01:23:45.123"
+    );
+    assert_eq!(
+        Duration::new(-5_025, -123_456_789).format(&stopwatch)?,
+        "-01:23:45.123"
+    );
+    assert_eq!(Duration::hours(100).format(&stopwatch)?, "100:00:00.000");
+
+    let days =
+        fd!("[duration_day]d [duration_hour padding:none]h [duration_minute padding:space]m");
+    assert_eq!(Duration::minutes(1_505).format(&days)?, "1d 1h  5m");
+    assert_eq!(Duration::days(365).format(&days)?, "365d 0h  0m");
+    assert_eq!(
+        Duration::MIN.format(&fd!("[duration_second repr:total]"))?,
+        "9223372036854775808"
+    );
+    assert_eq!(
+        Duration::seconds(5).format(&fd!("[duration_sign sign:mandatory][duration_second]"))?,
+        "+05"
+    );
+    assert_eq!(
+        Duration::milliseconds(1_500).format(&fd!("[duration_second].[duration_subsecond]"))?,
+        "01.5"
+    );
+    assert!(
+        Duration::ZERO
+            .format_into(&mut io::sink(), &stopwatch)
+            .is_ok()
+    );
+
+    assert!(matches!(
+        Duration::ZERO.format(&fd!("[hour]")),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    assert!(matches!(
+        Duration::ZERO.format(&Rfc3339),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    assert!(matches!(
+        time!("0:00").format(&fd!("[duration_hour]")),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn display_duration() {
+    assert_eq!(Duration::ZERO.to_string(), "0s");
+    assert_eq!(
+        Duration::new(183_845, 6_000_000).to_string(),
+        "2d 3h 4m 5.006s"
+    );
+    assert_eq!(Duration::hours(-51).to_string(), "-2d 3h");
+    assert_eq!(Duration::milliseconds(250).to_string(), "0.25s");
+    assert_eq!(Duration::minutes(61).to_string(), "1h 1m");
+    assert_eq!(
+        Duration::MIN.to_string(),
+        "-106751991167300d 15h 30m 8.999999999s"
+    );
+    assert_eq!(
+        Duration::MAX.to_string(),
+        "106751991167300d 15h 30m 7.999999999s"
+    );
+
+    assert_eq!(format!("{:.0}", Duration::new(5, 999_999_999)), "5s");
+    assert_eq!(format!("{:.3}", Duration::seconds(5)), "5.000s");
+    assert_eq!(
+        format!("{:.2}", Duration::new(90, 123_456_789)),
+        "1m 30.12s"
+    );
+    assert_eq!(format!("{:.12}", Duration::NANOSECOND), "0.000000001s");
+    assert_eq!(format!("{:>8}", Duration::minutes(90)), "  1h 30m");
+    assert_eq!(format!("{:*<8}", Duration::SECOND), "1s******");
 }
 
 #[test]
@@ -264,6 +863,14 @@ fn display_pdt() {
         datetime!("1970-01-01 0:00:01").to_string(),
         String::from("1970-01-01 0:00:01.0")
     );
+    assert_eq!(
+        format!("{:>24.3}", datetime!("1970-01-01 0:00:01.5")),
+        "  1970-01-01 0:00:01.500"
+    );
+    assert_eq!(
+        format!("{:<20.0}|", datetime!("1970-01-01 0:00:01.5")),
+        "1970-01-01 0:00:01  |"
+    );
 }
 
 #[test]
@@ -294,6 +901,110 @@ fn display_odt() {
         datetime!("1970-01-01 0:00 UTC").to_string(),
         "1970-01-01 0:00:00.0 +00:00:00"
     );
+    assert_eq!(
+        format!("{:>32.2}", datetime!("1970-01-01 0:00:01.5 +1")),
+        " 1970-01-01 0:00:01.50 +01:00:00"
+    );
+}
+
+#[test]
+fn unix_timestamp() -> time::Result<()> {
+    let dt = datetime!("2021-03-15 10:15:00.123_456_789 UTC");
+    assert_eq!(dt.format(&fd!("[unix_timestamp]"))?, "1615803300");
+    assert_eq!(
+        dt.format(&fd!("[unix_timestamp precision:millisecond]"))?,
+        "1615803300123"
+    );
+    assert_eq!(
+        dt.format(&fd!("[unix_timestamp precision:microsecond]"))?,
+        "1615803300123456"
+    );
+    assert_eq!(
+        dt.format(&fd!("[unix_timestamp precision:nanosecond sign:mandatory]"))?,
+        "+1615803300123456789"
+    );
+    assert_eq!(
+        datetime!("2021-03-15 12:15:00 +02:00").format(&fd!("[unix_timestamp]"))?,
+        "1615803300"
+    );
+    assert_eq!(
+        datetime!("1969-12-31 23:59:59.5 UTC").format(&fd!("[unix_timestamp]"))?,
+        "-1"
+    );
+    assert_eq!(
+        datetime!("1969-12-31 23:59:59.5 UTC")
+            .format(&fd!("[unix_timestamp precision:millisecond]"))?,
+        "-500"
+    );
+    assert!(matches!(
+        datetime!("2021-03-15 10:15 UTC")
+            .date()
+            .with_time(time!("10:15"))
+            .format(&fd!("[unix_timestamp]")),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn ignore_end() -> time::Result<()> {
+    assert!(matches!(
+        date!("2021-03-15").format(&fd!("[ignore count:3]")),
+        Err(time::error::Format::InvalidComponent("ignore"))
+    ));
+    assert_eq!(date!("2021-03-15").format(&fd!("[year][end]"))?, "2021");
+    assert_eq!(Time::MIDNIGHT.format(&fd!("[end]"))?, "");
+
+    Ok(())
+}
+
+#[test]
+fn fmt_write() -> time::Result<()> {
+    let mut output = FmtWrite(String::new());
+    let bytes = datetime!("2021-03-15 10:15:30 +1")
+        .format_into(&mut output, &fd!("[year]-[month]-[day] [hour]:[minute]"))?;
+    assert_eq!(bytes, 16);
+    assert_eq!(output.0, "2021-03-15 10:15");
+    assert!(matches!(
+        date!("2021-03-15").format_into(&mut output, &Rfc3339),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    assert!(matches!(
+        Time::MIDNIGHT.format_into(
+            &mut output,
+            &format_description::FormatItem::Literal(&[0xFF])
+        ),
+        Err(time::error::Format::Fmt(_))
+    ));
+    assert_eq!(output.0, "2021-03-15 10:15");
+
+    Ok(())
+}
+
+#[test]
+fn slice_writer() -> time::Result<()> {
+    let mut buf = [0; 10];
+    let mut output = SliceWriter::new(&mut buf);
+    assert!(output.is_empty());
+    assert_eq!(
+        date!("2021-03-15").format_into(&mut output, &fd!("[year]-[month]-[day]"))?,
+        10
+    );
+    assert_eq!(output.written(), b"2021-03-15");
+    assert_eq!(output.len(), 10);
+    assert_eq!(output.remaining(), 0);
+    assert_eq!(buf, *b"2021-03-15");
+
+    let mut buf = [0; 4];
+    let mut output = SliceWriter::new(&mut buf);
+    assert!(matches!(
+        time!("10:15").format_into(&mut output, &fd!("[hour]:[minute]")),
+        Err(time::error::Format::BufferOverflow(BufferOverflow))
+    ));
+    assert_eq!(output.written(), b"10:");
+
+    Ok(())
 }
 
 #[test]
@@ -303,3 +1014,330 @@ fn insufficient_type_information() {
         Err(time::error::Format::InsufficientTypeInformation { .. })
     ));
 }
+
+#[test]
+fn optional() -> time::Result<()> {
+    let format_description = fd!("[hour]:[minute][optional [:[second]]]");
+    assert_eq!(time!("10:15").format(&format_description)?, "10:15:00");
+    assert_eq!(time!("10:15:30").format(&format_description)?, "10:15:30");
+
+    let format_description = fd!("[year][optional [-[month][optional [-[day]]]]]");
+    assert_eq!(
+        date!("2021-03-15").format(&format_description)?,
+        "2021-03-15"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn first() -> time::Result<()> {
+    let format_description = fd!("[first [[year]-[month]-[day]] [[day]/[month]/[year]]]");
+    assert_eq!(
+        date!("2021-03-15").format(&format_description)?,
+        "2021-03-15"
+    );
+    assert_eq!(
+        date!("2021-03-15").format(&format_description::FormatItem::First(&[]))?,
+        ""
+    );
+
+    Ok(())
+}
+
+#[test]
+fn owned() -> time::Result<()> {
+    let format_description =
+        format_description::parse_owned("[hour]:[minute][optional [:[second]]]")?;
+    assert_eq!(time!("10:15:30").format(&format_description)?, "10:15:30");
+
+    let format_description =
+        format_description::parse_owned("[first [[year]-[month]-[day]] [[day]/[month]/[year]]]")?;
+    assert_eq!(
+        date!("2021-03-15").format(&format_description)?,
+        "2021-03-15"
+    );
+    assert_eq!(
+        date!("2021-03-15").format(&format_description::OwnedFormatItem::from(fd!(
+            "[day]/[month]/[year]"
+        )))?,
+        "15/03/2021"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn locale() -> time::Result<()> {
+    #[derive(Debug)]
+    struct Portuguese;
+
+    impl Locale for Portuguese {
+        fn long_month_names(&self) -> &[&str; 12] {
+            &[
+                "janeiro",
+                "fevereiro",
+                "março",
+                "abril",
+                "maio",
+                "junho",
+                "julho",
+                "agosto",
+                "setembro",
+                "outubro",
+                "novembro",
+                "dezembro",
+            ]
+        }
+
+        fn short_month_names(&self) -> &[&str; 12] {
+            &[
+                "jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out", "nov", "dez",
+            ]
+        }
+
+        fn narrow_month_names(&self) -> &[&str; 12] {
+            &["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]
+        }
+
+        fn long_weekday_names(&self) -> &[&str; 7] {
+            &[
+                "segunda-feira",
+                "terça-feira",
+                "quarta-feira",
+                "quinta-feira",
+                "sexta-feira",
+                "sábado",
+                "domingo",
+            ]
+        }
+
+        fn short_weekday_names(&self) -> &[&str; 7] {
+            &["seg", "ter", "qua", "qui", "sex", "sáb", "dom"]
+        }
+
+        fn narrow_weekday_names(&self) -> &[&str; 7] {
+            &["S", "T", "Q", "Q", "S", "S", "D"]
+        }
+
+        fn period_markers(&self, is_uppercase: bool) -> &[&str; 2] {
+            if is_uppercase {
+                &["AM", "PM"]
+            } else {
+                &["am", "pm"]
+            }
+        }
+
+        fn ordinal_suffix(&self, _: u16) -> &str {
+            "º"
+        }
+
+        fn era_names(&self, _: EraRepr) -> &[&str; 2] {
+            &["a.C.", "d.C."]
+        }
+    }
+
+    let format_description = fd!("[weekday], [day] de [month repr:long] de [year]");
+    assert_eq!(
+        date!("2021-03-06").format_with_locale(&format_description, &Portuguese)?,
+        "sábado, 06 de março de 2021"
+    );
+    assert_eq!(
+        date!("2021-03-06").format_with_locale(&format_description, &English)?,
+        date!("2021-03-06").format(&format_description)?
+    );
+    assert_eq!(
+        datetime!("2021-03-06 15:00").format_with_locale(
+            &fd!("[weekday repr:short] [month repr:short] [period]"),
+            &Portuguese
+        )?,
+        "sáb mar PM"
+    );
+    assert_eq!(
+        datetime!("2021-03-06 15:00 UTC").format_with_locale(
+            &format_description::parse_owned("[weekday repr:narrow] [month repr:narrow]")?,
+            &Portuguese
+        )?,
+        "S M"
+    );
+    assert_eq!(
+        time!("3:00")
+            .format_with_locale(&fd!("[hour repr:12] [period case:lower]"), &Portuguese)?,
+        "03 am"
+    );
+    assert_eq!(
+        date!("2021-03-01").format_with_locale(
+            &fd!("[day padding:none suffix:ordinal] de [month repr:long]"),
+            &Portuguese
+        )?,
+        "1º de março"
+    );
+    assert_eq!(
+        date!("-0043-03-15").format_with_locale(
+            &fd!("[year repr:historical padding:none] [era]"),
+            &Portuguese
+        )?,
+        "44 a.C."
+    );
+
+    // Well-known formats are not localized.
+    assert_eq!(
+        datetime!("2021-03-06 15:00 UTC").format_with_locale(&Rfc2822, &Portuguese)?,
+        "Sat, 06 Mar 2021 15:00:00 +0000"
+    );
+
+    let mut buf = Vec::new();
+    date!("2021-03-06").format_into_with_locale(&mut buf, &format_description, &Portuguese)?;
+    assert_eq!(buf, "sábado, 06 de março de 2021".as_bytes());
+
+    Ok(())
+}
+
+#[test]
+fn relative() -> time::Result<()> {
+    let relative = Relative::DEFAULT;
+    assert_eq!(relative.format(Duration::ZERO)?, "just now");
+    assert_eq!(relative.format((-9).seconds())?, "just now");
+    assert_eq!(relative.format(10.seconds())?, "in 10 seconds");
+    assert_eq!(relative.format((-1).minutes())?, "1 minute ago");
+    assert_eq!(relative.format(5.minutes())?, "in 5 minutes");
+    assert_eq!(relative.format((-90).minutes())?, "2 hours ago");
+    assert_eq!(relative.format(23.hours())?, "in 23 hours");
+    assert_eq!(relative.format(1.days())?, "in 1 day");
+    assert_eq!(relative.format((-6).days())?, "6 days ago");
+    assert_eq!(relative.format(3.weeks())?, "in 3 weeks");
+    assert_eq!(relative.format(40.days())?, "in 1 month");
+    assert_eq!(relative.format((-400).days())?, "1 year ago");
+    assert_eq!(relative.format(Duration::MAX)?, "in 292277024627 years");
+    assert_eq!(Relative::default(), relative);
+
+    assert_eq!(
+        relative
+            .set_rounding(Rounding::Floor)
+            .format(119.seconds())?,
+        "in 1 minute"
+    );
+    assert_eq!(
+        relative.set_rounding(Rounding::Ceil).format(61.seconds())?,
+        "in 2 minutes"
+    );
+    assert_eq!(
+        relative
+            .set_rounding(Rounding::Ceil)
+            .format(59.5.minutes())?,
+        "in 1 hour"
+    );
+    assert_eq!(
+        relative.set_just_now(Duration::ZERO).format(1.seconds())?,
+        "in 1 second"
+    );
+    assert_eq!(
+        relative
+            .set_just_now(Duration::ZERO)
+            .format(400.milliseconds())?,
+        "just now"
+    );
+    assert_eq!(
+        relative.set_threshold(Unit::Day, 30).format(17.days())?,
+        "in 17 days"
+    );
+    assert_eq!(
+        relative.set_threshold(Unit::Year, 1).format(3.weeks())?,
+        "in 3 weeks"
+    );
+
+    let mut buf = Vec::new();
+    assert_eq!(relative.format_into(&mut buf, (-2).hours())?, 11);
+    assert_eq!(buf, b"2 hours ago");
+
+    assert_eq!(
+        relative.format_between(
+            datetime!("2021-03-15 09:00 UTC"),
+            datetime!("2021-03-15 12:00 +1"),
+        )?,
+        "2 hours ago"
+    );
+    assert_eq!(
+        relative.format_between(
+            datetime!("2021-03-22 12:00 UTC"),
+            datetime!("2021-03-15 12:00 UTC"),
+        )?,
+        "in 1 week"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn relative_locale() -> time::Result<()> {
+    struct German;
+
+    impl Locale for German {
+        fn long_month_names(&self) -> &[&str; 12] {
+            English.long_month_names()
+        }
+
+        fn short_month_names(&self) -> &[&str; 12] {
+            English.short_month_names()
+        }
+
+        fn narrow_month_names(&self) -> &[&str; 12] {
+            English.narrow_month_names()
+        }
+
+        fn long_weekday_names(&self) -> &[&str; 7] {
+            English.long_weekday_names()
+        }
+
+        fn short_weekday_names(&self) -> &[&str; 7] {
+            English.short_weekday_names()
+        }
+
+        fn narrow_weekday_names(&self) -> &[&str; 7] {
+            English.narrow_weekday_names()
+        }
+
+        fn period_markers(&self, is_uppercase: bool) -> &[&str; 2] {
+            English.period_markers(is_uppercase)
+        }
+
+        fn relative_now(&self) -> &str {
+            "gerade eben"
+        }
+
+        fn relative_time(&self, count: u64, unit: Unit, is_future: bool) -> (&str, &str) {
+            let before = if is_future { "in " } else { "vor " };
+            let after = match (unit, count == 1) {
+                (Unit::Minute, true) => " Minute",
+                (Unit::Minute, false) => " Minuten",
+                (_, true) => " Tag",
+                (_, false) => " Tagen",
+            };
+            (before, after)
+        }
+    }
+
+    let relative = Relative::DEFAULT;
+    assert_eq!(
+        relative.format_with_locale(1.seconds(), &German)?,
+        "gerade eben"
+    );
+    assert_eq!(
+        relative.format_with_locale((-5).minutes(), &German)?,
+        "vor 5 Minuten"
+    );
+    assert_eq!(relative.format_with_locale(1.days(), &German)?, "in 1 Tag");
+    assert_eq!(
+        relative.format_with_locale((-3).days(), &German)?,
+        "vor 3 Tagen"
+    );
+
+    let mut buf = Vec::new();
+    assert_eq!(
+        relative.format_into_with_locale(&mut buf, 1.minutes(), &German)?,
+        11
+    );
+    assert_eq!(buf, "in 1 Minute".as_bytes());
+
+    Ok(())
+}
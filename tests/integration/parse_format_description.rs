@@ -1,6 +1,7 @@
 mod iterator {
     use time::format_description::modifier::{
-        MonthRepr, Padding, SubsecondDigits, WeekNumberRepr, WeekdayRepr, YearRepr,
+        DurationRepr, EraRepr, MonthRepr, OffsetSeparator, Padding, SubsecondDigits,
+        SubsecondRounding, Suffix, UnixTimestampPrecision, WeekNumberRepr, WeekdayRepr, YearRepr,
     };
 
     pub(super) fn padding() -> Vec<(Padding, &'static str)> {
@@ -24,6 +25,7 @@ mod iterator {
             (MonthRepr::Numerical, "repr:numerical"),
             (MonthRepr::Long, "repr:long"),
             (MonthRepr::Short, "repr:short"),
+            (MonthRepr::Narrow, "repr:narrow"),
         ]
     }
 
@@ -39,6 +41,16 @@ mod iterator {
             (SubsecondDigits::Eight, "digits:8"),
             (SubsecondDigits::Nine, "digits:9"),
             (SubsecondDigits::OneOrMore, "digits:1+"),
+            (SubsecondDigits::Range { min: 3, max: 6 }, "digits:3-6"),
+            (SubsecondDigits::Range { min: 9, max: 9 }, "digits:9-9"),
+        ]
+    }
+
+    pub(super) fn subsecond_rounding() -> Vec<(SubsecondRounding, &'static str)> {
+        vec![
+            (SubsecondRounding::Truncate, "rounding:truncate"),
+            (SubsecondRounding::HalfUp, "rounding:half_up"),
+            (SubsecondRounding::HalfEven, "rounding:half_even"),
         ]
     }
 
@@ -46,6 +58,7 @@ mod iterator {
         vec![
             (WeekdayRepr::Short, "repr:short"),
             (WeekdayRepr::Long, "repr:long"),
+            (WeekdayRepr::Narrow, "repr:narrow"),
             (WeekdayRepr::Sunday, "repr:sunday"),
             (WeekdayRepr::Monday, "repr:monday"),
         ]
@@ -63,6 +76,18 @@ mod iterator {
         vec![
             (YearRepr::Full, "repr:full"),
             (YearRepr::LastTwo, "repr:last_two"),
+            (YearRepr::Historical, "repr:historical"),
+        ]
+    }
+
+    pub(super) fn year_digits() -> Vec<(u8, &'static str)> {
+        vec![(4, "digits:4"), (5, "digits:5"), (6, "digits:6")]
+    }
+
+    pub(super) fn era_repr() -> Vec<(EraRepr, &'static str)> {
+        vec![
+            (EraRepr::AnnoDomini, "repr:ad"),
+            (EraRepr::CommonEra, "repr:ce"),
         ]
     }
 
@@ -77,13 +102,60 @@ mod iterator {
     pub(super) fn weekday_is_one_indexed() -> Vec<(bool, &'static str)> {
         vec![(true, "one_indexed:true"), (false, "one_indexed:false")]
     }
+
+    pub(super) fn case_sensitive() -> Vec<(bool, &'static str)> {
+        vec![
+            (true, "case_sensitive:true"),
+            (false, "case_sensitive:false"),
+        ]
+    }
+
+    pub(super) fn offset_zulu() -> Vec<(bool, &'static str)> {
+        vec![(true, "zulu:true"), (false, "zulu:false")]
+    }
+
+    pub(super) fn offset_separator() -> Vec<(OffsetSeparator, &'static str)> {
+        vec![
+            (OffsetSeparator::Colon, "separator:colon"),
+            (OffsetSeparator::None, "separator:none"),
+            (OffsetSeparator::Optional, "separator:optional"),
+        ]
+    }
+
+    pub(super) fn offset_minute_is_optional() -> Vec<(bool, &'static str)> {
+        vec![(false, "minute:mandatory"), (true, "minute:optional")]
+    }
+
+    pub(super) fn duration_repr() -> Vec<(DurationRepr, &'static str)> {
+        vec![
+            (DurationRepr::Total, "repr:total"),
+            (DurationRepr::Remainder, "repr:remainder"),
+        ]
+    }
+
+    pub(super) fn suffix() -> Vec<(Suffix, &'static str)> {
+        vec![
+            (Suffix::None, "suffix:none"),
+            (Suffix::Ordinal, "suffix:ordinal"),
+        ]
+    }
+
+    pub(super) fn unix_timestamp_precision() -> Vec<(UnixTimestampPrecision, &'static str)> {
+        vec![
+            (UnixTimestampPrecision::Second, "precision:second"),
+            (UnixTimestampPrecision::Millisecond, "precision:millisecond"),
+            (UnixTimestampPrecision::Microsecond, "precision:microsecond"),
+            (UnixTimestampPrecision::Nanosecond, "precision:nanosecond"),
+        ]
+    }
 }
 
 use time::error::InvalidFormatDescription;
 use time::format_description::modifier::{
-    self, MonthRepr, Padding, SubsecondDigits, WeekNumberRepr, WeekdayRepr, YearRepr,
+    self, DurationRepr, EraRepr, MonthRepr, OffsetSeparator, Padding, SubsecondDigits,
+    SubsecondRounding, Suffix, WeekNumberRepr, WeekdayRepr, YearRepr,
 };
-use time::format_description::{self, Component, FormatItem};
+use time::format_description::{self, Component, FormatItem, OwnedFormatItem};
 
 #[test]
 fn empty() {
@@ -94,30 +166,34 @@ fn empty() {
 fn only_literal() {
     assert_eq!(
         format_description::parse("foo bar"),
-        Ok(vec![FormatItem::Literal(b"foo bar")])
+        Ok(vec![OwnedFormatItem::Literal(b"foo bar"[..].into())])
     );
     assert_eq!(
         format_description::parse("  leading spaces"),
-        Ok(vec![FormatItem::Literal(b"  leading spaces")])
+        Ok(vec![OwnedFormatItem::Literal(
+            b"  leading spaces"[..].into()
+        )])
     );
     assert_eq!(
         format_description::parse("trailing spaces  "),
-        Ok(vec![FormatItem::Literal(b"trailing spaces  ")])
+        Ok(vec![OwnedFormatItem::Literal(
+            b"trailing spaces  "[..].into()
+        )])
     );
     assert_eq!(
         format_description::parse("     "),
-        Ok(vec![FormatItem::Literal(b"     ")])
+        Ok(vec![OwnedFormatItem::Literal(b"     "[..].into())])
     );
     assert_eq!(
         format_description::parse("[["),
-        Ok(vec![FormatItem::Literal(b"[")])
+        Ok(vec![OwnedFormatItem::Literal(b"["[..].into())])
     );
     assert_eq!(
         format_description::parse("foo[[bar"),
         Ok(vec![
-            FormatItem::Literal(b"foo"),
-            FormatItem::Literal(b"["),
-            FormatItem::Literal(b"bar")
+            OwnedFormatItem::Literal(b"foo"[..].into()),
+            OwnedFormatItem::Literal(b"["[..].into()),
+            OwnedFormatItem::Literal(b"bar"[..].into())
         ])
     );
 }
@@ -126,13 +202,16 @@ fn only_literal() {
 fn simple_component() {
     assert_eq!(
         format_description::parse("[day]"),
-        Ok(vec![FormatItem::Component(Component::Day(modifier::Day {
-            padding: Padding::Zero
-        }))])
+        Ok(vec![OwnedFormatItem::Component(Component::Day(
+            modifier::Day {
+                padding: Padding::Zero,
+                suffix: Suffix::None
+            }
+        ))])
     );
     assert_eq!(
         format_description::parse("[hour]"),
-        Ok(vec![FormatItem::Component(Component::Hour(
+        Ok(vec![OwnedFormatItem::Component(Component::Hour(
             modifier::Hour {
                 padding: Padding::Zero,
                 is_12_hour_clock: false
@@ -141,7 +220,7 @@ fn simple_component() {
     );
     assert_eq!(
         format_description::parse("[minute]"),
-        Ok(vec![FormatItem::Component(Component::Minute(
+        Ok(vec![OwnedFormatItem::Component(Component::Minute(
             modifier::Minute {
                 padding: Padding::Zero
             }
@@ -149,16 +228,17 @@ fn simple_component() {
     );
     assert_eq!(
         format_description::parse("[month]"),
-        Ok(vec![FormatItem::Component(Component::Month(
+        Ok(vec![OwnedFormatItem::Component(Component::Month(
             modifier::Month {
                 padding: Padding::Zero,
-                repr: MonthRepr::Numerical
+                repr: MonthRepr::Numerical,
+                case_sensitive: true
             }
         ))])
     );
     assert_eq!(
         format_description::parse("[offset_hour]"),
-        Ok(vec![FormatItem::Component(Component::OffsetHour(
+        Ok(vec![OwnedFormatItem::Component(Component::OffsetHour(
             modifier::OffsetHour {
                 sign_is_mandatory: false,
                 padding: Padding::Zero
@@ -167,7 +247,7 @@ fn simple_component() {
     );
     assert_eq!(
         format_description::parse("[offset_minute]"),
-        Ok(vec![FormatItem::Component(Component::OffsetMinute(
+        Ok(vec![OwnedFormatItem::Component(Component::OffsetMinute(
             modifier::OffsetMinute {
                 padding: Padding::Zero
             }
@@ -175,29 +255,82 @@ fn simple_component() {
     );
     assert_eq!(
         format_description::parse("[offset_second]"),
-        Ok(vec![FormatItem::Component(Component::OffsetSecond(
+        Ok(vec![OwnedFormatItem::Component(Component::OffsetSecond(
             modifier::OffsetSecond {
                 padding: Padding::Zero
             }
         ))])
     );
+    assert_eq!(
+        format_description::parse("[offset_abbreviation]"),
+        Ok(vec![OwnedFormatItem::Component(
+            Component::OffsetAbbreviation(modifier::OffsetAbbreviation {
+                case_sensitive: true
+            })
+        )])
+    );
+    assert_eq!(
+        format_description::parse("[offset]"),
+        Ok(vec![OwnedFormatItem::Component(Component::Offset(
+            modifier::Offset {
+                zulu: true,
+                separator: OffsetSeparator::Colon,
+                minute_is_optional: false
+            }
+        ))])
+    );
+    assert_eq!(
+        format_description::parse("[duration_day]"),
+        Ok(vec![OwnedFormatItem::Component(Component::DurationDay(
+            modifier::DurationDay
+        ))])
+    );
+    assert_eq!(
+        format_description::parse("[duration_hour]"),
+        Ok(vec![OwnedFormatItem::Component(Component::DurationHour(
+            modifier::DurationHour {
+                padding: Padding::Zero,
+                repr: DurationRepr::Remainder
+            }
+        ))])
+    );
+    assert_eq!(
+        format_description::parse("[duration_subsecond]"),
+        Ok(vec![OwnedFormatItem::Component(
+            Component::DurationSubsecond(modifier::DurationSubsecond {
+                digits: SubsecondDigits::OneOrMore
+            })
+        )])
+    );
+    assert_eq!(
+        format_description::parse("[duration_sign]"),
+        Ok(vec![OwnedFormatItem::Component(Component::DurationSign(
+            modifier::DurationSign {
+                sign_is_mandatory: false
+            }
+        ))])
+    );
     assert_eq!(
         format_description::parse("[ordinal]"),
-        Ok(vec![FormatItem::Component(Component::Ordinal(
+        Ok(vec![OwnedFormatItem::Component(Component::Ordinal(
             modifier::Ordinal {
-                padding: Padding::Zero
+                padding: Padding::Zero,
+                suffix: Suffix::None
             }
         ))])
     );
     assert_eq!(
         format_description::parse("[period]"),
-        Ok(vec![FormatItem::Component(Component::Period(
-            modifier::Period { is_uppercase: true }
+        Ok(vec![OwnedFormatItem::Component(Component::Period(
+            modifier::Period {
+                is_uppercase: true,
+                case_sensitive: true
+            }
         ))])
     );
     assert_eq!(
         format_description::parse("[second]"),
-        Ok(vec![FormatItem::Component(Component::Second(
+        Ok(vec![OwnedFormatItem::Component(Component::Second(
             modifier::Second {
                 padding: Padding::Zero
             }
@@ -205,38 +338,96 @@ fn simple_component() {
     );
     assert_eq!(
         format_description::parse("[subsecond]"),
-        Ok(vec![FormatItem::Component(Component::Subsecond(
+        Ok(vec![OwnedFormatItem::Component(Component::Subsecond(
             modifier::Subsecond {
-                digits: SubsecondDigits::OneOrMore
+                digits: SubsecondDigits::OneOrMore,
+                rounding: SubsecondRounding::Truncate
             }
         ))])
     );
     assert_eq!(
         format_description::parse("[weekday]"),
-        Ok(vec![FormatItem::Component(Component::Weekday(
+        Ok(vec![OwnedFormatItem::Component(Component::Weekday(
             modifier::Weekday {
                 repr: WeekdayRepr::Long,
                 one_indexed: true,
+                case_sensitive: true,
             }
         ))])
     );
     assert_eq!(
         format_description::parse("[week_number]"),
-        Ok(vec![FormatItem::Component(Component::WeekNumber(
+        Ok(vec![OwnedFormatItem::Component(Component::WeekNumber(
             modifier::WeekNumber {
                 padding: Padding::Zero,
-                repr: WeekNumberRepr::Iso
+                repr: WeekNumberRepr::Iso,
+                suffix: Suffix::None
+            }
+        ))])
+    );
+    assert_eq!(
+        format_description::parse("[quarter]"),
+        Ok(vec![OwnedFormatItem::Component(Component::Quarter(
+            modifier::Quarter {
+                padding: Padding::Zero,
+                suffix: Suffix::None
+            }
+        ))])
+    );
+    assert_eq!(
+        format_description::parse("[week_of_month]"),
+        Ok(vec![OwnedFormatItem::Component(Component::WeekOfMonth(
+            modifier::WeekOfMonth {
+                padding: Padding::Zero,
+                suffix: Suffix::None
+            }
+        ))])
+    );
+    assert_eq!(
+        format_description::parse("[week_of_quarter]"),
+        Ok(vec![OwnedFormatItem::Component(Component::WeekOfQuarter(
+            modifier::WeekOfQuarter {
+                padding: Padding::Zero,
+                suffix: Suffix::None
+            }
+        ))])
+    );
+    assert_eq!(
+        format_description::parse("[weekday_in_month]"),
+        Ok(vec![OwnedFormatItem::Component(Component::WeekdayInMonth(
+            modifier::WeekdayInMonth {
+                padding: Padding::Zero,
+                suffix: Suffix::None
+            }
+        ))])
+    );
+    assert_eq!(
+        format_description::parse("[century]"),
+        Ok(vec![OwnedFormatItem::Component(Component::Century(
+            modifier::Century {
+                padding: Padding::Zero,
+                suffix: Suffix::None
             }
         ))])
     );
     assert_eq!(
         format_description::parse("[year]"),
-        Ok(vec![FormatItem::Component(Component::Year(
+        Ok(vec![OwnedFormatItem::Component(Component::Year(
             modifier::Year {
                 padding: Padding::Zero,
                 repr: YearRepr::Full,
                 iso_week_based: false,
-                sign_is_mandatory: false
+                sign_is_mandatory: false,
+                digits: 4
+            }
+        ))])
+    );
+    assert_eq!(
+        format_description::parse("[era]"),
+        Ok(vec![OwnedFormatItem::Component(Component::Era(
+            modifier::Era {
+                repr: EraRepr::AnnoDomini,
+                case_sensitive: true
             }
         ))])
     );
@@ -266,44 +457,157 @@ fn errors() {
             index: 5
         })
     );
+    assert_eq!(
+        format_description::parse("[ignore]"),
+        Err(InvalidFormatDescription::Expected {
+            what: "`count` modifier",
+            index: 7
+        })
+    );
+    assert_eq!(
+        format_description::parse("[ignore count:-1]"),
+        Err(InvalidFormatDescription::InvalidModifier {
+            value: "count:-1".to_owned(),
+            index: 8
+        })
+    );
+    assert_eq!(
+        format_description::parse("[subsecond digits:6-3]"),
+        Err(InvalidFormatDescription::InvalidModifier {
+            value: "digits:6-3".to_owned(),
+            index: 11
+        })
+    );
+    assert_eq!(
+        format_description::parse("[offset separator:dash]"),
+        Err(InvalidFormatDescription::InvalidModifier {
+            value: "separator:dash".to_owned(),
+            index: 8
+        })
+    );
+    assert_eq!(
+        format_description::parse("[end count:1]"),
+        Err(InvalidFormatDescription::InvalidModifier {
+            value: "count:1".to_owned(),
+            index: 5
+        })
+    );
+    assert_eq!(
+        format_description::parse("[optional]"),
+        Err(InvalidFormatDescription::Expected {
+            what: "opening bracket",
+            index: 9
+        })
+    );
+    assert_eq!(
+        format_description::parse("[optional [[hour]"),
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: 10 })
+    );
+    assert_eq!(
+        format_description::parse("[optional [[hour]]"),
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: 0 })
+    );
+    assert_eq!(
+        format_description::parse("[optional [[invalid]]]"),
+        Err(InvalidFormatDescription::InvalidComponentName {
+            name: "invalid".to_owned(),
+            index: 12
+        })
+    );
+    assert_eq!(
+        format_description::parse("[first]"),
+        Err(InvalidFormatDescription::Expected {
+            what: "opening bracket",
+            index: 6
+        })
+    );
+    assert_eq!(
+        format_description::parse("[first [[hour]] [[minute]]"),
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: 0 })
+    );
+    assert_eq!(
+        format_description::parse("[first [[hour]] [[minute]"),
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: 16 })
+    );
 }
 
 #[test]
 fn component_with_modifiers() {
     for (padding, padding_str) in iterator::padding() {
-        assert_eq!(
-            format_description::parse(&format!("[day {}]", padding_str)),
-            Ok(vec![FormatItem::Component(Component::Day(modifier::Day {
-                padding
-            }))])
-        );
+        for (suffix, suffix_str) in iterator::suffix() {
+            assert_eq!(
+                format_description::parse(&format!("[day {} {}]", padding_str, suffix_str)),
+                Ok(vec![OwnedFormatItem::Component(Component::Day(
+                    modifier::Day { padding, suffix }
+                ))])
+            );
+            assert_eq!(
+                format_description::parse(&format!("[ordinal {} {}]", padding_str, suffix_str)),
+                Ok(vec![OwnedFormatItem::Component(Component::Ordinal(
+                    modifier::Ordinal { padding, suffix }
+                ))])
+            );
+            assert_eq!(
+                format_description::parse(&format!("[quarter {} {}]", padding_str, suffix_str)),
+                Ok(vec![OwnedFormatItem::Component(Component::Quarter(
+                    modifier::Quarter { padding, suffix }
+                ))])
+            );
+            assert_eq!(
+                format_description::parse(&format!(
+                    "[week_of_month {} {}]",
+                    padding_str, suffix_str
+                )),
+                Ok(vec![OwnedFormatItem::Component(Component::WeekOfMonth(
+                    modifier::WeekOfMonth { padding, suffix }
+                ))])
+            );
+            assert_eq!(
+                format_description::parse(&format!(
+                    "[week_of_quarter {} {}]",
+                    padding_str, suffix_str
+                )),
+                Ok(vec![OwnedFormatItem::Component(Component::WeekOfQuarter(
+                    modifier::WeekOfQuarter { padding, suffix }
+                ))])
+            );
+            assert_eq!(
+                format_description::parse(&format!(
+                    "[weekday_in_month {} {}]",
+                    padding_str, suffix_str
+                )),
+                Ok(vec![OwnedFormatItem::Component(Component::WeekdayInMonth(
+                    modifier::WeekdayInMonth { padding, suffix }
+                ))])
+            );
+            assert_eq!(
+                format_description::parse(&format!("[century {} {}]", padding_str, suffix_str)),
+                Ok(vec![OwnedFormatItem::Component(Component::Century(
+                    modifier::Century { padding, suffix }
+                ))])
+            );
+        }
         assert_eq!(
             format_description::parse(&format!("[minute {}]", padding_str)),
-            Ok(vec![FormatItem::Component(Component::Minute(
+            Ok(vec![OwnedFormatItem::Component(Component::Minute(
                 modifier::Minute { padding }
             ))])
         );
         assert_eq!(
             format_description::parse(&format!("[offset_minute {}]", padding_str)),
-            Ok(vec![FormatItem::Component(Component::OffsetMinute(
+            Ok(vec![OwnedFormatItem::Component(Component::OffsetMinute(
                 modifier::OffsetMinute { padding }
             ))])
         );
         assert_eq!(
             format_description::parse(&format!("[offset_second {}]", padding_str)),
-            Ok(vec![FormatItem::Component(Component::OffsetSecond(
+            Ok(vec![OwnedFormatItem::Component(Component::OffsetSecond(
                 modifier::OffsetSecond { padding }
             ))])
         );
-        assert_eq!(
-            format_description::parse(&format!("[ordinal {}]", padding_str)),
-            Ok(vec![FormatItem::Component(Component::Ordinal(
-                modifier::Ordinal { padding }
-            ))])
-        );
         assert_eq!(
             format_description::parse(&format!("[second {}]", padding_str)),
-            Ok(vec![FormatItem::Component(Component::Second(
+            Ok(vec![OwnedFormatItem::Component(Component::Second(
                 modifier::Second { padding }
             ))])
         );
@@ -314,7 +618,7 @@ fn component_with_modifiers() {
                     "[hour {} {}]",
                     padding_str, is_12_hour_clock_str
                 )),
-                Ok(vec![FormatItem::Component(Component::Hour(
+                Ok(vec![OwnedFormatItem::Component(Component::Hour(
                     modifier::Hour {
                         padding,
                         is_12_hour_clock
@@ -323,26 +627,60 @@ fn component_with_modifiers() {
             );
         }
         for (repr, repr_str) in iterator::month_repr() {
-            assert_eq!(
-                format_description::parse(&format!("[month {} {}]", padding_str, repr_str)),
-                Ok(vec![FormatItem::Component(Component::Month(
-                    modifier::Month { padding, repr }
-                ))])
-            );
+            for (case_sensitive, case_sensitive_str) in iterator::case_sensitive() {
+                assert_eq!(
+                    format_description::parse(&format!(
+                        "[month {} {} {}]",
+                        padding_str, repr_str, case_sensitive_str
+                    )),
+                    Ok(vec![OwnedFormatItem::Component(Component::Month(
+                        modifier::Month {
+                            padding,
+                            repr,
+                            case_sensitive
+                        }
+                    ))])
+                );
+            }
         }
         for (is_uppercase, is_uppercase_str) in iterator::period_is_uppercase() {
-            assert_eq!(
-                format_description::parse(&format!("[period {}]", is_uppercase_str)),
-                Ok(vec![FormatItem::Component(Component::Period(
-                    modifier::Period { is_uppercase }
-                ))])
-            );
+            for (case_sensitive, case_sensitive_str) in iterator::case_sensitive() {
+                assert_eq!(
+                    format_description::parse(&format!(
+                        "[period {} {}]",
+                        is_uppercase_str, case_sensitive_str
+                    )),
+                    Ok(vec![OwnedFormatItem::Component(Component::Period(
+                        modifier::Period {
+                            is_uppercase,
+                            case_sensitive
+                        }
+                    ))])
+                );
+            }
         }
         for (repr, repr_str) in iterator::week_number_repr() {
             assert_eq!(
                 format_description::parse(&format!("[week_number {} {}]", padding_str, repr_str)),
-                Ok(vec![FormatItem::Component(Component::WeekNumber(
-                    modifier::WeekNumber { padding, repr }
+                Ok(vec![OwnedFormatItem::Component(Component::WeekNumber(
+                    modifier::WeekNumber {
+                        padding,
+                        repr,
+                        suffix: Suffix::None
+                    }
+                ))])
+            );
+            assert_eq!(
+                format_description::parse(&format!(
+                    "[week_number {} {} suffix:ordinal]",
+                    padding_str, repr_str
+                )),
+                Ok(vec![OwnedFormatItem::Component(Component::WeekNumber(
+                    modifier::WeekNumber {
+                        padding,
+                        repr,
+                        suffix: Suffix::Ordinal
+                    }
                 ))])
             );
         }
@@ -352,7 +690,7 @@ fn component_with_modifiers() {
                     "[offset_hour {} {}]",
                     padding_str, sign_is_mandatory_str
                 )),
-                Ok(vec![FormatItem::Component(Component::OffsetHour(
+                Ok(vec![OwnedFormatItem::Component(Component::OffsetHour(
                     modifier::OffsetHour {
                         sign_is_mandatory,
                         padding
@@ -362,40 +700,178 @@ fn component_with_modifiers() {
 
             for (repr, repr_str) in iterator::year_repr() {
                 for (iso_week_based, iso_week_based_str) in iterator::year_is_iso_week_based() {
-                    assert_eq!(
-                        format_description::parse(&format!(
-                            "[year {} {} {} {}]",
-                            padding_str, repr_str, iso_week_based_str, sign_is_mandatory_str
-                        )),
-                        Ok(vec![FormatItem::Component(Component::Year(
-                            modifier::Year {
-                                padding,
-                                repr,
-                                iso_week_based,
-                                sign_is_mandatory
-                            }
-                        ))])
-                    );
+                    for (digits, digits_str) in iterator::year_digits() {
+                        assert_eq!(
+                            format_description::parse(&format!(
+                                "[year {} {} {} {} {}]",
+                                padding_str,
+                                repr_str,
+                                iso_week_based_str,
+                                sign_is_mandatory_str,
+                                digits_str
+                            )),
+                            Ok(vec![OwnedFormatItem::Component(Component::Year(
+                                modifier::Year {
+                                    padding,
+                                    repr,
+                                    iso_week_based,
+                                    sign_is_mandatory,
+                                    digits
+                                }
+                            ))])
+                        );
+                    }
                 }
             }
         }
     }
 
     for (digits, digits_str) in iterator::subsecond_digits() {
+        for (rounding, rounding_str) in iterator::subsecond_rounding() {
+            assert_eq!(
+                format_description::parse(&format!("[subsecond {} {}]", digits_str, rounding_str)),
+                Ok(vec![OwnedFormatItem::Component(Component::Subsecond(
+                    modifier::Subsecond { digits, rounding }
+                ))])
+            );
+        }
+    }
+
+    for (repr, repr_str) in iterator::weekday_repr() {
+        for (one_indexed, one_indexed_str) in iterator::weekday_is_one_indexed() {
+            for (case_sensitive, case_sensitive_str) in iterator::case_sensitive() {
+                assert_eq!(
+                    format_description::parse(&format!(
+                        "[weekday {} {} {} ]",
+                        repr_str, one_indexed_str, case_sensitive_str
+                    )),
+                    Ok(vec![OwnedFormatItem::Component(Component::Weekday(
+                        modifier::Weekday {
+                            repr,
+                            one_indexed,
+                            case_sensitive
+                        }
+                    ))])
+                );
+            }
+        }
+    }
+
+    for (repr, repr_str) in iterator::era_repr() {
+        for (case_sensitive, case_sensitive_str) in iterator::case_sensitive() {
+            assert_eq!(
+                format_description::parse(&format!("[era {} {}]", repr_str, case_sensitive_str)),
+                Ok(vec![OwnedFormatItem::Component(Component::Era(
+                    modifier::Era {
+                        repr,
+                        case_sensitive
+                    }
+                ))])
+            );
+        }
+    }
+
+    for (case_sensitive, case_sensitive_str) in iterator::case_sensitive() {
         assert_eq!(
-            format_description::parse(&format!("[subsecond {}]", digits_str)),
-            Ok(vec![FormatItem::Component(Component::Subsecond(
-                modifier::Subsecond { digits }
+            format_description::parse(&format!("[offset_abbreviation {}]", case_sensitive_str)),
+            Ok(vec![OwnedFormatItem::Component(
+                Component::OffsetAbbreviation(modifier::OffsetAbbreviation { case_sensitive })
+            )])
+        );
+    }
+
+    for (zulu, zulu_str) in iterator::offset_zulu() {
+        for (separator, separator_str) in iterator::offset_separator() {
+            for (minute_is_optional, minute_str) in iterator::offset_minute_is_optional() {
+                assert_eq!(
+                    format_description::parse(&format!(
+                        "[offset {} {} {}]",
+                        zulu_str, separator_str, minute_str
+                    )),
+                    Ok(vec![OwnedFormatItem::Component(Component::Offset(
+                        modifier::Offset {
+                            zulu,
+                            separator,
+                            minute_is_optional
+                        }
+                    ))])
+                );
+            }
+        }
+    }
+
+    for (padding, padding_str) in iterator::padding() {
+        for (repr, repr_str) in iterator::duration_repr() {
+            assert_eq!(
+                format_description::parse(&format!("[duration_hour {} {}]", padding_str, repr_str)),
+                Ok(vec![OwnedFormatItem::Component(Component::DurationHour(
+                    modifier::DurationHour { padding, repr }
+                ))])
+            );
+            assert_eq!(
+                format_description::parse(&format!(
+                    "[duration_minute {} {}]",
+                    padding_str, repr_str
+                )),
+                Ok(vec![OwnedFormatItem::Component(Component::DurationMinute(
+                    modifier::DurationMinute { padding, repr }
+                ))])
+            );
+            assert_eq!(
+                format_description::parse(&format!(
+                    "[duration_second {} {}]",
+                    padding_str, repr_str
+                )),
+                Ok(vec![OwnedFormatItem::Component(Component::DurationSecond(
+                    modifier::DurationSecond { padding, repr }
+                ))])
+            );
+        }
+    }
+
+    for (digits, digits_str) in iterator::subsecond_digits() {
+        assert_eq!(
+            format_description::parse(&format!("[duration_subsecond {}]", digits_str)),
+            Ok(vec![OwnedFormatItem::Component(
+                Component::DurationSubsecond(modifier::DurationSubsecond { digits })
+            )])
+        );
+    }
+
+    for (sign_is_mandatory, sign_is_mandatory_str) in iterator::sign_is_mandatory() {
+        assert_eq!(
+            format_description::parse(&format!("[duration_sign {}]", sign_is_mandatory_str)),
+            Ok(vec![OwnedFormatItem::Component(Component::DurationSign(
+                modifier::DurationSign { sign_is_mandatory }
             ))])
         );
     }
 
-    for (repr, repr_str) in iterator::weekday_repr() {
-        for (one_indexed, one_indexed_str) in iterator::weekday_is_one_indexed() {
+    assert_eq!(
+        format_description::parse("[ignore count:3]"),
+        Ok(vec![OwnedFormatItem::Component(Component::Ignore(
+            modifier::Ignore { count: 3 }
+        ))])
+    );
+    assert_eq!(
+        format_description::parse("[end]"),
+        Ok(vec![OwnedFormatItem::Component(Component::End(
+            modifier::End
+        ))])
+    );
+
+    for (precision, precision_str) in iterator::unix_timestamp_precision() {
+        for (sign_is_mandatory, sign_is_mandatory_str) in iterator::sign_is_mandatory() {
             assert_eq!(
-                format_description::parse(&format!("[weekday {} {} ]", repr_str, one_indexed_str)),
-                Ok(vec![FormatItem::Component(Component::Weekday(
-                    modifier::Weekday { repr, one_indexed }
+                format_description::parse(&format!(
+                    "[unix_timestamp {} {}]",
+                    precision_str, sign_is_mandatory_str
+                )),
+                Ok(vec![OwnedFormatItem::Component(Component::UnixTimestamp(
+                    modifier::UnixTimestamp {
+                        precision,
+                        sign_is_mandatory
+                    }
                 ))])
             );
         }
@@ -428,6 +904,24 @@ fn error_display() {
         InvalidFormatDescription::MissingComponentName { index: 4 }.to_string(),
         "missing component name at byte index 4"
     );
+    assert_eq!(
+        InvalidFormatDescription::Expected {
+            what: "opening bracket",
+            index: 5
+        }
+        .to_string(),
+        "expected opening bracket at byte index 5"
+    );
+    assert_eq!(
+        InvalidFormatDescription::NotSupported {
+            what: "padding flag",
+            context: "conversion specifiers without a numerical value",
+            index: 0
+        }
+        .to_string(),
+        "padding flag is not supported in conversion specifiers without a numerical value at byte \
+         index 0"
+    );
 }
 
 #[test]
@@ -438,46 +932,428 @@ fn rfc_3339() {
              sign:mandatory]:[offset_minute]"
         ),
         Ok(vec![
-            FormatItem::Component(Component::Year(modifier::Year {
+            OwnedFormatItem::Component(Component::Year(modifier::Year {
                 padding: Padding::Zero,
                 repr: YearRepr::Full,
                 iso_week_based: false,
-                sign_is_mandatory: false
+                sign_is_mandatory: false,
+                digits: 4
             })),
-            FormatItem::Literal(b"-"),
-            FormatItem::Component(Component::Month(modifier::Month {
+            OwnedFormatItem::Literal(b"-"[..].into()),
+            OwnedFormatItem::Component(Component::Month(modifier::Month {
                 padding: Padding::Zero,
-                repr: MonthRepr::Numerical
+                repr: MonthRepr::Numerical,
+                case_sensitive: true
             })),
-            FormatItem::Literal(b"-"),
-            FormatItem::Component(Component::Day(modifier::Day {
-                padding: Padding::Zero
+            OwnedFormatItem::Literal(b"-"[..].into()),
+            OwnedFormatItem::Component(Component::Day(modifier::Day {
+                padding: Padding::Zero,
+                suffix: Suffix::None
             })),
-            FormatItem::Literal(b"T"),
-            FormatItem::Component(Component::Hour(modifier::Hour {
+            OwnedFormatItem::Literal(b"T"[..].into()),
+            OwnedFormatItem::Component(Component::Hour(modifier::Hour {
                 padding: Padding::Zero,
                 is_12_hour_clock: false
             })),
-            FormatItem::Literal(b":"),
-            FormatItem::Component(Component::Minute(modifier::Minute {
+            OwnedFormatItem::Literal(b":"[..].into()),
+            OwnedFormatItem::Component(Component::Minute(modifier::Minute {
                 padding: Padding::Zero
             })),
-            FormatItem::Literal(b":"),
-            FormatItem::Component(Component::Second(modifier::Second {
+            OwnedFormatItem::Literal(b":"[..].into()),
+            OwnedFormatItem::Component(Component::Second(modifier::Second {
                 padding: Padding::Zero
             })),
-            FormatItem::Literal(b"."),
-            FormatItem::Component(Component::Subsecond(modifier::Subsecond {
-                digits: SubsecondDigits::OneOrMore
+            OwnedFormatItem::Literal(b"."[..].into()),
+            OwnedFormatItem::Component(Component::Subsecond(modifier::Subsecond {
+                digits: SubsecondDigits::OneOrMore,
+                rounding: SubsecondRounding::Truncate
             })),
-            FormatItem::Component(Component::OffsetHour(modifier::OffsetHour {
+            OwnedFormatItem::Component(Component::OffsetHour(modifier::OffsetHour {
                 padding: Padding::Zero,
                 sign_is_mandatory: true
             })),
-            FormatItem::Literal(b":"),
-            FormatItem::Component(Component::OffsetMinute(modifier::OffsetMinute {
+            OwnedFormatItem::Literal(b":"[..].into()),
+            OwnedFormatItem::Component(Component::OffsetMinute(modifier::OffsetMinute {
                 padding: Padding::Zero
             }))
         ])
     );
 }
+
+#[test]
+fn owned() {
+    let hour = Component::Hour(modifier::Hour {
+        padding: Padding::Zero,
+        is_12_hour_clock: false,
+    });
+    let minute = Component::Minute(modifier::Minute {
+        padding: Padding::Zero,
+    });
+
+    assert_eq!(
+        format_description::parse_owned("[hour]:[minute]"),
+        Ok(OwnedFormatItem::Compound(
+            format_description::parse("[hour]:[minute]").unwrap().into()
+        ))
+    );
+    assert_eq!(
+        format_description::parse_owned("[optional [[hour]]]"),
+        Ok(OwnedFormatItem::Compound(
+            vec![OwnedFormatItem::Optional(Box::new(
+                OwnedFormatItem::Compound(vec![OwnedFormatItem::Component(hour)].into())
+            ))]
+            .into()
+        ))
+    );
+    assert_eq!(
+        format_description::parse("[hour][optional [:[minute]]]"),
+        Ok(vec![
+            OwnedFormatItem::Component(hour),
+            OwnedFormatItem::Optional(Box::new(OwnedFormatItem::Compound(
+                vec![
+                    OwnedFormatItem::Literal(b":"[..].into()),
+                    OwnedFormatItem::Component(minute),
+                ]
+                .into()
+            ))),
+        ])
+    );
+    assert_eq!(
+        format_description::parse("[first [[hour]] [[minute]]]"),
+        Ok(vec![OwnedFormatItem::First(
+            vec![
+                OwnedFormatItem::Compound(vec![OwnedFormatItem::Component(hour)].into()),
+                OwnedFormatItem::Compound(vec![OwnedFormatItem::Component(minute)].into()),
+            ]
+            .into()
+        )])
+    );
+    assert_eq!(
+        format_description::parse_owned("[first [[hour]] [[[[minute]]]"),
+        Ok(OwnedFormatItem::Compound(
+            vec![OwnedFormatItem::First(
+                vec![
+                    OwnedFormatItem::Compound(vec![OwnedFormatItem::Component(hour)].into()),
+                    OwnedFormatItem::Compound(
+                        vec![
+                            OwnedFormatItem::Literal(b"["[..].into()),
+                            OwnedFormatItem::Component(minute),
+                        ]
+                        .into()
+                    ),
+                ]
+                .into()
+            )]
+            .into()
+        ))
+    );
+    assert_eq!(
+        format_description::parse_owned("[optional [[hour]]"),
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: 0 })
+    );
+    assert_eq!(
+        format_description::parse_owned("[optional [[foo]]]"),
+        Err(InvalidFormatDescription::InvalidComponentName {
+            name: "foo".to_owned(),
+            index: 12
+        })
+    );
+
+    // The result does not borrow from the input.
+    let format_description = {
+        let input = String::from("[hour]");
+        format_description::parse_owned(&input).unwrap()
+    };
+    assert_eq!(
+        std::thread::spawn(move || format_description)
+            .join()
+            .unwrap(),
+        OwnedFormatItem::Compound(vec![OwnedFormatItem::Component(hour)].into())
+    );
+}
+
+#[test]
+fn version_2() {
+    use time::macros::format_description;
+
+    let hour = Component::Hour(modifier::Hour {
+        padding: Padding::Zero,
+        is_12_hour_clock: false,
+    });
+    let minute = Component::Minute(modifier::Minute {
+        padding: Padding::Zero,
+    });
+
+    // Existing format descriptions are unchanged.
+    assert_eq!(
+        format_description::parse("[version 1][[[hour]\\"),
+        Ok(vec![
+            OwnedFormatItem::Literal(b"["[..].into()),
+            OwnedFormatItem::Component(hour),
+            OwnedFormatItem::Literal(b"\\"[..].into()),
+        ])
+    );
+    assert_eq!(
+        format_description::parse("[version 2]"),
+        format_description::parse("")
+    );
+    assert_eq!(
+        format_description::parse(r"[version 2]\[[hour]\]\\"),
+        Ok(vec![
+            OwnedFormatItem::Literal(b"["[..].into()),
+            OwnedFormatItem::Component(hour),
+            OwnedFormatItem::Literal(b"]"[..].into()),
+            OwnedFormatItem::Literal(b"\\"[..].into()),
+        ])
+    );
+    assert_eq!(
+        format_description::parse("[ version  2 ][[[hour]:]][minute]"),
+        Ok(vec![
+            OwnedFormatItem::Compound(
+                vec![
+                    OwnedFormatItem::Component(hour),
+                    OwnedFormatItem::Literal(b":"[..].into()),
+                ]
+                .into()
+            ),
+            OwnedFormatItem::Component(minute),
+        ])
+    );
+    assert_eq!(
+        format_description::parse_owned("[version 2][[[hour]:] ][minute]"),
+        Ok(OwnedFormatItem::Compound(
+            vec![
+                OwnedFormatItem::Compound(
+                    vec![
+                        OwnedFormatItem::Component(hour),
+                        OwnedFormatItem::Literal(b":"[..].into()),
+                    ]
+                    .into()
+                ),
+                OwnedFormatItem::Component(minute),
+            ]
+            .into()
+        ))
+    );
+    assert_eq!(
+        format_description!(r"[version 2]\[[[[hour]:]][minute]"),
+        &[
+            FormatItem::Literal(b"["),
+            FormatItem::Compound(&[FormatItem::Component(hour), FormatItem::Literal(b":")]),
+            FormatItem::Component(minute),
+        ]
+    );
+    assert_eq!(
+        format_description!("[version 2][optional [\\[[hour]\\]]]"),
+        &[FormatItem::Optional(&FormatItem::Compound(&[
+            FormatItem::Literal(b"["),
+            FormatItem::Component(hour),
+            FormatItem::Literal(b"]"),
+        ]))]
+    );
+    assert_eq!(
+        format_description!("\t[hour]\u{2d}\x2d"),
+        &[
+            FormatItem::Literal(b"\t"),
+            FormatItem::Component(hour),
+            FormatItem::Literal(b"--"),
+        ]
+    );
+    assert_eq!(
+        format_description!("[[[hour]"),
+        &[FormatItem::Literal(b"["), FormatItem::Component(hour)]
+    );
+
+    assert_eq!(
+        format_description::parse(r"[version 2]\a"),
+        Err(InvalidFormatDescription::Expected {
+            what: r"`\`, `[`, or `]` after backslash",
+            index: 12
+        })
+    );
+    assert_eq!(
+        format_description::parse(r"[version 2]\"),
+        Err(InvalidFormatDescription::Expected {
+            what: r"`\`, `[`, or `]` after backslash",
+            index: 12
+        })
+    );
+    assert_eq!(
+        format_description::parse("[version 3]"),
+        Err(InvalidFormatDescription::Expected {
+            what: "version 1 or 2",
+            index: 9
+        })
+    );
+    assert_eq!(
+        format_description::parse("[version 2"),
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: 0 })
+    );
+    assert_eq!(
+        format_description::parse("[hour][version 2]"),
+        Err(InvalidFormatDescription::InvalidComponentName {
+            name: "version".to_owned(),
+            index: 7
+        })
+    );
+    assert_eq!(
+        format_description::parse("[version 2][[[hour]]"),
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: 11 })
+    );
+}
+
+#[test]
+fn strftime() {
+    assert_eq!(
+        format_description::parse_strftime("%Y-%m-%d %H:%M:%S").map(OwnedFormatItem::from),
+        format_description::parse_owned("[year]-[month]-[day] [hour]:[minute]:[second]")
+    );
+    assert_eq!(
+        format_description::parse_strftime("%F %T%z").map(OwnedFormatItem::from),
+        format_description::parse_owned(
+            "[year]-[month]-[day] [hour]:[minute]:[second][offset_hour \
+             sign:mandatory][offset_minute]"
+        )
+    );
+    assert_eq!(
+        format_description::parse_strftime("%a, %e %b %Y").map(OwnedFormatItem::from),
+        format_description::parse_owned(
+            "[weekday repr:short], [day padding:space] [month repr:short] [year]"
+        )
+    );
+    assert_eq!(
+        format_description::parse_strftime("%A %B %-d %_H %0k %l%P").map(OwnedFormatItem::from),
+        format_description::parse_owned(
+            "[weekday] [month repr:long] [day padding:none] [hour padding:space] [hour] [hour \
+             repr:12 padding:space][period case:lower]"
+        )
+    );
+    assert_eq!(
+        format_description::parse_strftime("%u %w %U %W %V %G %g %j %y %Ey %OS")
+            .map(OwnedFormatItem::from),
+        format_description::parse_owned(
+            "[weekday repr:monday] [weekday repr:sunday one_indexed:false] [week_number \
+             repr:sunday] [week_number repr:monday] [week_number] [year base:iso_week] [year \
+             base:iso_week repr:last_two] [ordinal] [year repr:last_two] [year repr:last_two] \
+             [second]"
+        )
+    );
+    assert_eq!(
+        format_description::parse_strftime("%C%y").map(OwnedFormatItem::from),
+        format_description::parse_owned("[century][year repr:last_two]")
+    );
+    assert_eq!(
+        format_description::parse_strftime("%T %Z").map(OwnedFormatItem::from),
+        format_description::parse_owned("[hour]:[minute]:[second] [offset_abbreviation]")
+    );
+    assert_eq!(
+        format_description::parse_strftime("%r").map(OwnedFormatItem::from),
+        format_description::parse_owned("[hour repr:12]:[minute]:[second] [period]")
+    );
+    assert_eq!(
+        format_description::parse_strftime("100%%%n"),
+        Ok(vec![
+            FormatItem::Literal(b"100"),
+            FormatItem::Literal(b"%"),
+            FormatItem::Literal(b"\n"),
+        ])
+    );
+
+    assert_eq!(
+        format_description::parse_strftime("%H%"),
+        Err(InvalidFormatDescription::Expected {
+            what: "conversion specifier",
+            index: 3
+        })
+    );
+    assert_eq!(
+        format_description::parse_strftime("%H:%Q"),
+        Err(InvalidFormatDescription::InvalidComponentName {
+            name: "%Q".to_owned(),
+            index: 3
+        })
+    );
+    assert_eq!(
+        format_description::parse_strftime("%-b"),
+        Err(InvalidFormatDescription::NotSupported {
+            what: "padding flag",
+            context: "conversion specifiers without a numerical value",
+            index: 0
+        })
+    );
+    assert_eq!(
+        format_description::parse_strftime("%T %+"),
+        Err(InvalidFormatDescription::NotSupported {
+            what: "date and time in date(1) format",
+            context: "format descriptions",
+            index: 3
+        })
+    );
+
+    for s in &[
+        "%Y-%m-%dT%H:%M:%S%z",
+        "%H:%M %Z",
+        "%a, %-d %b %Y %-I:%M %p",
+        "%A %B %e %k %l%P",
+        "%u %w %U %W %V %G %g %j %y",
+        "%C%y-%m-%d",
+        "%_C %-C",
+        "100%% of %-m/%e",
+    ] {
+        assert_eq!(
+            format_description::to_strftime(&format_description::parse_strftime(s).unwrap())
+                .unwrap(),
+            *s
+        );
+    }
+    assert!(matches!(
+        format_description::to_strftime(time::macros::format_description!(
+            "[first [[hour]] [[hour repr:12]]]"
+        )),
+        Err(time::error::Format::InvalidComponent("first"))
+    ));
+    assert!(matches!(
+        format_description::to_strftime(time::macros::format_description!("[second].[subsecond]")),
+        Err(time::error::Format::InvalidComponent("subsecond"))
+    ));
+    assert!(matches!(
+        format_description::to_strftime(time::macros::format_description!("[offset_hour]")),
+        Err(time::error::Format::InvalidComponent("offset_hour"))
+    ));
+    assert!(matches!(
+        format_description::to_strftime(time::macros::format_description!(
+            "[offset_abbreviation case_sensitive:false]"
+        )),
+        Err(time::error::Format::InvalidComponent("offset_abbreviation"))
+    ));
+    assert!(matches!(
+        format_description::to_strftime(time::macros::format_description!("[offset]")),
+        Err(time::error::Format::InvalidComponent("offset"))
+    ));
+    assert!(matches!(
+        format_description::to_strftime(time::macros::format_description!("[day suffix:ordinal]")),
+        Err(time::error::Format::InvalidComponent("day"))
+    ));
+    assert!(matches!(
+        format_description::to_strftime(time::macros::format_description!("[quarter]")),
+        Err(time::error::Format::InvalidComponent("quarter"))
+    ));
+    assert!(matches!(
+        format_description::to_strftime(time::macros::format_description!("[week_of_quarter]")),
+        Err(time::error::Format::InvalidComponent("week_of_quarter"))
+    ));
+    assert!(matches!(
+        format_description::to_strftime(time::macros::format_description!("[era]")),
+        Err(time::error::Format::InvalidComponent("era"))
+    ));
+    assert!(matches!(
+        format_description::to_strftime(time::macros::format_description!(
+            "[year repr:historical]"
+        )),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        format_description::to_strftime(time::macros::format_description!("[year digits:6]")),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+}
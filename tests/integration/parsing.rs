@@ -1,10 +1,160 @@
 use core::convert::{TryFrom, TryInto};
+use std::io::{BufRead, BufReader, ErrorKind, Read};
 
-use time::format_description::well_known::Rfc3339;
+use time::format_description::locale::{English, Locale};
+use time::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
 use time::format_description::{modifier, Component};
-use time::macros::{date, datetime, time};
-use time::parsing::Parsed;
-use time::{format_description as fd, Date, OffsetDateTime, Time, UtcOffset, Weekday};
+use time::macros::{date, datetime, format_description, offset, time};
+use time::parsing::{LeapSecondPolicy, Parsed};
+use time::{
+    format_description as fd, Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset,
+    Weekday,
+};
+
+#[test]
+fn rfc_2822() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 GMT", &Rfc2822)?,
+        datetime!("2021-01-02 03:04:05 UTC"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Tue, 1 Jul 2003 10:52:37 +0200", &Rfc2822)?,
+        datetime!("2003-07-01 10:52:37 +02:00"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("1 Jul 2003 10:52 -0330", &Rfc2822)?,
+        datetime!("2003-07-01 10:52 -03:30"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "Thu,\r\n 13\r\n  Feb\r\n    1969\r\n 23:32\r\n -0330",
+            &Rfc2822
+        )?,
+        datetime!("1969-02-13 23:32 -03:30"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "Thu, 13 Feb 69 23:32 (Thursday (really)) EST (Eastern Standard Time)",
+            &Rfc2822
+        )?,
+        datetime!("1969-02-13 23:32 -05:00"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Fri, 21 Nov 97 09:55:06 PDT", &Rfc2822)?,
+        datetime!("1997-11-21 09:55:06 -07:00"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sat, 02 Jan 21 03:04 : 05 Q", &Rfc2822)?,
+        datetime!("2021-01-02 03:04:05 UTC"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:60 UT", &Rfc2822)?,
+        datetime!("2021-01-02 03:04:59 UTC"),
+    );
+
+    assert_eq!(
+        Date::parse("Sat, 02 Jan 2021 03:04:05 GMT", &Rfc2822)?,
+        date!("2021-01-02"),
+    );
+    assert_eq!(
+        Time::parse("Tue, 1 Jul 2003 10:52:37 +0200", &Rfc2822)?,
+        time!("10:52:37"),
+    );
+    assert_eq!(
+        UtcOffset::parse("Tue, 1 Jul 2003 10:52:37 -0200", &Rfc2822)?,
+        offset!("-2"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Tue, 1 Jul 2003 10:52:37 -0030", &Rfc2822)?,
+        datetime!("2003-07-01 10:52:37 -00:30"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Tue, 1 Jul 2003 10:52:37 +0030", &Rfc2822)?,
+        datetime!("2003-07-01 10:52:37 +00:30"),
+    );
+    assert_eq!(
+        UtcOffset::parse("Tue, 1 Jul 2003 10:52:37 -0030", &Rfc2822)?,
+        offset!("-0:30"),
+    );
+    assert_eq!(
+        UtcOffset::parse("Tue, 1 Jul 2003 10:52:37 +0030", &Rfc2822)?,
+        offset!("+0:30"),
+    );
+
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 02 Jan 2021 03:04:05 GMT", &Rfc2822),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::ComponentRange(time::error::ComponentRange {
+                name: "weekday",
+                ..
+            })
+        ))
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("Mon, 01 Jul 2003 10:52:37 +0200", &Rfc2822),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::ComponentRange(time::error::ComponentRange {
+                name: "weekday",
+                ..
+            })
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("Mon, 01 Jul 2003 10:52:37 +0200", &Rfc2822),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::ComponentRange(time::error::ComponentRange {
+                name: "weekday",
+                ..
+            })
+        ))
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sat, 02 Jan 1899 03:04:05 GMT", &Rfc2822),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("year")
+        ))
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sat 02 Jan 2021 03:04:05 GMT", &Rfc2822),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidLiteral { .. }
+        ))
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 +02", &Rfc2822),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("offset_hour")
+        ))
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 GMT x", &Rfc2822),
+        Err(time::error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        Date::parse("Sat, 02 Jan 2021 03:04:05 GMT x", &Rfc2822),
+        Err(time::error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+
+    // Deeply nested comments must not overflow the stack.
+    let nested = format!(
+        "Sat, 02 Jan 2021 03:04:05 GMT {}{}",
+        "(".repeat(200_000),
+        ")".repeat(200_000)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(&nested, &Rfc2822)?,
+        datetime!("2021-01-02 03:04:05 UTC"),
+    );
+    assert!(matches!(
+        OffsetDateTime::parse(
+            &format!("Sat, 02 Jan 2021 03:04:05 GMT {}", "(".repeat(200_000)),
+            &Rfc2822
+        ),
+        Err(time::error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+
+    Ok(())
+}
 
 #[test]
 fn rfc_3339() -> time::Result<()> {
@@ -77,6 +227,234 @@ fn rfc_3339() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn duration_from_str() -> time::Result<()> {
+    assert_eq!("0".parse::<Duration>()?, Duration::ZERO);
+    assert_eq!("0s".parse::<Duration>()?, Duration::ZERO);
+    assert_eq!(
+        "2d 3h 4m 5.006s".parse::<Duration>()?,
+        Duration::new(183_845, 6_000_000)
+    );
+    assert_eq!(
+        "1h2m3.5s".parse::<Duration>()?,
+        Duration::new(3_723, 500_000_000)
+    );
+    assert_eq!("1m30s".parse::<Duration>()?, Duration::seconds(90));
+    assert_eq!("250ms".parse::<Duration>()?, Duration::milliseconds(250));
+    assert_eq!("1.5h".parse::<Duration>()?, Duration::minutes(90));
+    assert_eq!("10us".parse::<Duration>()?, Duration::microseconds(10));
+    assert_eq!("10µs".parse::<Duration>()?, Duration::microseconds(10));
+    assert_eq!("7ns".parse::<Duration>()?, Duration::nanoseconds(7));
+    assert_eq!("-2d 3h".parse::<Duration>()?, Duration::hours(-51));
+    assert_eq!("+1s".parse::<Duration>()?, Duration::SECOND);
+    assert_eq!(
+        Duration::MIN.to_string().parse::<Duration>()?,
+        Duration::MIN
+    );
+    assert_eq!(
+        Duration::MAX.to_string().parse::<Duration>()?,
+        Duration::MAX
+    );
+
+    assert!(matches!(
+        "".parse::<Duration>(),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("duration")
+        ))
+    ));
+    assert!(matches!(
+        "5".parse::<Duration>(),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("duration")
+        ))
+    ));
+    assert!(matches!(
+        "5s ".parse::<Duration>(),
+        Err(time::error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        "1y".parse::<Duration>(),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("duration")
+        ))
+    ));
+    assert!(matches!(
+        "106751991167301d".parse::<Duration>(),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::ComponentRange(_)
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn iso_8601_duration() -> time::Result<()> {
+    let iso = Iso8601::DEFAULT;
+    assert_eq!(Duration::parse("PT0S", &iso)?, Duration::ZERO);
+    assert_eq!(
+        Duration::parse("PT1H30M5.5S", &iso)?,
+        Duration::new(5_405, 500_000_000)
+    );
+    assert_eq!(Duration::parse("P3DT4H", &iso)?, Duration::hours(76));
+    assert_eq!(Duration::parse("P2W", &iso)?, Duration::weeks(2));
+    assert_eq!(Duration::parse("PT1,5H", &iso)?, Duration::minutes(90));
+    assert_eq!(Duration::parse("P0.5D", &iso)?, Duration::hours(12));
+    assert_eq!(Duration::parse("-PT1M", &iso)?, Duration::minutes(-1));
+    assert_eq!(Duration::parse("+PT90S", &iso)?, Duration::seconds(90));
+    assert_eq!(
+        Duration::parse("-PT0.000000001S", &iso)?,
+        Duration::nanoseconds(-1)
+    );
+
+    assert!(matches!(
+        Duration::parse("P1Y", &iso),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("duration year")
+        ))
+    ));
+    assert!(matches!(
+        Duration::parse("P1M", &iso),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("duration month")
+        ))
+    ));
+    assert!(matches!(
+        Duration::parse("P", &iso),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("duration")
+        ))
+    ));
+    assert!(matches!(
+        Duration::parse("P1DT", &iso),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("duration")
+        ))
+    ));
+    assert!(matches!(
+        Duration::parse("PT1S1M", &iso),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("duration")
+        ))
+    ));
+    assert!(matches!(
+        Duration::parse("PT1.5M30S", &iso),
+        Err(time::error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        Duration::parse("P99999999999999999D", &iso),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("duration day")
+        ))
+    ));
+    assert!(matches!(
+        Duration::parse("2021-03-15", &iso),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidLiteral { .. }
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("P1D", &iso),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("year")
+        ))
+    ));
+    assert!(matches!(
+        Time::parse("PT1H", &iso),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("year")
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn iso_8601() -> time::Result<()> {
+    let iso = Iso8601::DEFAULT;
+
+    assert_eq!(
+        OffsetDateTime::parse("2021-03-15T10:15:30.123456789+01:30", &iso)?,
+        datetime!("2021-03-15 10:15:30.123_456_789 +01:30"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("20210315T101500Z", &iso)?,
+        datetime!("2021-03-15 10:15 UTC"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-03-15T10:15-00:30", &iso)?,
+        datetime!("2021-03-15 10:15 -00:30"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-074T10:15-05", &iso)?,
+        datetime!("2021-03-15 10:15 -05:00"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021074T1015-0500", &iso)?,
+        datetime!("2021-03-15 10:15 -05:00"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-W11-1T10:15:30,5+01", &iso)?,
+        datetime!("2021-03-15 10:15:30.5 +01:00"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021W111T10Z", &iso)?,
+        datetime!("2021-03-15 10:00 UTC"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-03-15T10.25Z", &iso)?,
+        datetime!("2021-03-15 10:15 UTC"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-03-15T10:15.5Z", &iso)?,
+        datetime!("2021-03-15 10:15:30 UTC"),
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-03-15T10:15:60", &iso)?,
+        datetime!("2021-03-15 10:15:59"),
+    );
+    assert_eq!(Date::parse("2021-03-15", &iso)?, date!("2021-03-15"));
+    assert_eq!(Date::parse("2021-W11-1", &iso)?, date!("2021-03-15"));
+    assert_eq!(Date::parse("2021074", &iso)?, date!("2021-03-15"));
+    assert_eq!(Time::parse("T10:15", &iso)?, time!("10:15"));
+    assert_eq!(Time::parse("10:15:30", &iso)?, time!("10:15:30"));
+    assert_eq!(Time::parse("T101530.25", &iso)?, time!("10:15:30.25"));
+    assert_eq!(UtcOffset::parse("-0130", &iso)?, offset!("-01:30"));
+    assert_eq!(UtcOffset::parse("Z", &iso)?, offset!("UTC"));
+    assert_eq!(Date::parse("+002021-03-15", &iso)?, date!("2021-03-15"));
+    assert_eq!(Date::parse("-00001-01-01", &iso)?, date!("-0001-01-01"));
+    assert_eq!(Date::parse("+2021-03-15", &iso)?, date!("2021-03-15"));
+    #[cfg(not(feature = "large-dates"))]
+    assert_eq!(Date::parse("+20210315", &iso)?, date!("2021-03-15"));
+
+    assert!(matches!(
+        OffsetDateTime::parse("2021-03-15T1015Z", &iso),
+        Err(time::error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("20210315T10:15Z", &iso),
+        Err(time::error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        Date::parse("2021-13-01", &iso),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("month")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("2021-W54-1", &iso),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("week number")
+        ))
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-03-15+01:00", &iso),
+        Err(time::error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn parse_time() -> time::Result<()> {
     let format_input_output = [
@@ -146,37 +524,37 @@ fn parse_date() -> time::Result<()> {
         (
             fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
             "2021-W00-6",
-            date!("2021-01-02"),
+            date!("2021-01-01"),
         ),
         (
             fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
             "2023-W01-1",
-            date!("2023-01-02"),
+            date!("2023-01-01"),
         ),
         (
             fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
             "2022-W00-7",
-            date!("2022-01-02"),
+            date!("2022-01-01"),
         ),
         (
             fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
             "2026-W00-5",
-            date!("2026-01-02"),
+            date!("2026-01-01"),
         ),
         (
             fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
             "2025-W00-4",
-            date!("2025-01-02"),
+            date!("2025-01-01"),
         ),
         (
             fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
             "2019-W00-3",
-            date!("2019-01-02"),
+            date!("2019-01-01"),
         ),
         (
             fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
             "2018-W01-2",
-            date!("2018-01-02"),
+            date!("2018-01-08"),
         ),
     ];
 
@@ -184,249 +562,1507 @@ fn parse_date() -> time::Result<()> {
         assert_eq!(&Date::parse(input, format_description)?, output);
     }
 
-    assert!(matches!(
-        Date::try_from(Parsed::new()),
-        Err(time::error::TryFromParsed::InsufficientInformation { .. })
-    ));
-
-    Ok(())
-}
-
-#[test]
-fn parse_offset() -> time::Result<()> {
+    let format_description =
+        fd::parse("[month repr:long] [day padding:none suffix:ordinal], [year]")?;
     assert_eq!(
-        UtcOffset::parse("01", &fd::parse("[offset_hour sign:mandatory]")?),
-        Err(time::error::Parse::ParseFromDescription(
-            time::error::ParseFromDescription::InvalidComponent("offset hour")
-        ))
+        Date::parse("March 3rd, 2021", &format_description)?,
+        date!("2021-03-03")
     );
-
-    Ok(())
-}
-
-#[test]
-fn parse_components() -> time::Result<()> {
-    macro_rules! parse_component {
-        ($component:expr, $input:expr,_. $property:ident == $expected:expr) => {
-            let mut parsed = Parsed::new();
-            parsed.parse_component($input, $component)?;
-            assert_eq!(parsed.$property, $expected);
-        };
-    }
-
-    parse_component!(
-        Component::Year(modifier::Year {
-            padding: modifier::Padding::Zero,
-            repr: modifier::YearRepr::Full,
-            iso_week_based: false,
-            sign_is_mandatory: false,
-        }),
-        b"2021",
-        _.year == Some(2021)
+    assert_eq!(
+        Date::parse("March 22ND, 2021", &format_description)?,
+        date!("2021-03-22")
     );
-    parse_component!(
-        Component::Year(modifier::Year {
-            padding: modifier::Padding::Zero,
-            repr: modifier::YearRepr::LastTwo,
-            iso_week_based: false,
-            sign_is_mandatory: false,
-        }),
-        b"21",
-        _.year_last_two == Some(21)
+    assert_eq!(
+        Date::parse("2021 112th", &fd::parse("[year] [ordinal suffix:ordinal]")?)?,
+        date!("2021-112")
     );
-    parse_component!(
-        Component::Year(modifier::Year {
+    assert_eq!(
+        Date::parse(
+            "2021 2nd 4",
+            &fd::parse(
+                "[year base:iso_week] [week_number padding:none suffix:ordinal] [weekday \
+                 repr:monday]"
+            )?
+        )?,
+        date!("2021-01-14")
+    );
+    assert_eq!(
+        Date::parse(
+            "20210309",
+            &fd::parse("[century][year repr:last_two][month][day]")?
+        )?,
+        date!("2021-03-09")
+    );
+    assert_eq!(
+        Date::parse(
+            "-01 50-01-01",
+            &fd::parse("[century] [year repr:last_two]-[month]-[day]")?
+        )?,
+        date!("-0150-01-01")
+    );
+    let description = fd::parse("[century][year repr:last_two]-[month]-[day]")?;
+    for &date in &[
+        date!("-0999-01-01"),
+        date!("-0150-01-01"),
+        date!("-0099-01-01"),
+        date!("-0050-01-01"),
+        date!("-0001-01-01"),
+        date!("0000-01-01"),
+        date!("0050-01-01"),
+        date!("2021-01-01"),
+    ] {
+        assert_eq!(
+            Date::parse(&date.format(&description)?, &description)?,
+            date
+        );
+    }
+    assert_eq!(
+        Date::parse(
+            "2nd Tuesday of March 2021",
+            &fd::parse("[weekday_in_month suffix:ordinal] [weekday] of [month repr:long] [year]")?
+        )?,
+        date!("2021-03-09")
+    );
+    assert_eq!(
+        Date::parse(
+            "2021-03 week 2 Tuesday",
+            &fd::parse("[year]-[month] week [week_of_month] [weekday]")?
+        )?,
+        date!("2021-03-09")
+    );
+    assert_eq!(
+        Date::parse(
+            "2021-05 week 1 Sunday",
+            &fd::parse("[year]-[month] week [week_of_month] [weekday]")?
+        )?,
+        date!("2021-05-02")
+    );
+    assert_eq!(
+        Date::parse(
+            "2021-Q3 week 02 Tuesday",
+            &fd::parse("[year]-Q[quarter] week [week_of_quarter] [weekday]")?
+        )?,
+        date!("2021-07-06")
+    );
+    assert_eq!(
+        Date::parse(
+            "2021-Q1 week 14 Wednesday",
+            &fd::parse("[year]-Q[quarter] week [week_of_quarter] [weekday]")?
+        )?,
+        date!("2021-03-31")
+    );
+    assert_eq!(
+        Date::parse(
+            "Q3 2021-07-01",
+            &fd::parse("Q[quarter] [year]-[month]-[day]")?
+        )?,
+        date!("2021-07-01")
+    );
+    let description = fd::parse("[year]-Q[quarter] week [week_of_quarter] [weekday]")?;
+    for &date in &[
+        date!("2021-01-01"),
+        date!("2021-03-29"),
+        date!("2021-04-01"),
+        date!("2021-12-31"),
+        date!("2020-12-31"),
+    ] {
+        assert_eq!(
+            Date::parse(&date.format(&description)?, &description)?,
+            date
+        );
+    }
+    #[cfg(feature = "large-dates")]
+    assert_eq!(
+        Date::parse(
+            "+123-45-01-01",
+            &fd::parse("[century]-[year repr:last_two]-[month]-[day]")?
+        )?,
+        date!("+12345-01-01")
+    );
+    assert_eq!(
+        Date::parse(
+            "0044 BC-03-15",
+            &fd::parse("[year repr:historical] [era]-[month]-[day]")?
+        )?,
+        date!("-0043-03-15")
+    );
+    assert_eq!(
+        Date::parse(
+            "1 bce-12-31",
+            &fd::parse(
+                "[year repr:historical padding:none] [era repr:ce \
+                 case_sensitive:false]-[month]-[day]"
+            )?
+        )?,
+        date!("0000-12-31")
+    );
+    assert_eq!(
+        Date::parse(
+            "2021-03-15",
+            &fd::parse("[year repr:historical]-[month]-[day]")?
+        )?,
+        date!("2021-03-15")
+    );
+    assert_eq!(
+        Date::parse(
+            "+002021-03-15",
+            &fd::parse("[year digits:6]-[month]-[day]")?
+        )?,
+        date!("2021-03-15")
+    );
+    assert_eq!(
+        Date::parse("-00044-03-15", &fd::parse("[year digits:5]-[month]-[day]")?)?,
+        date!("-0044-03-15")
+    );
+    assert!(matches!(
+        Date::parse("2021-03-15", &fd::parse("[year digits:6]-[month]-[day]")?),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("year")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse(
+            "0000 AD-03-15",
+            &fd::parse("[year repr:historical] [era]-[month]-[day]")?
+        ),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("year")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse(
+            "0044 bc-03-15",
+            &fd::parse("[year repr:historical] [era]-[month]-[day]")?
+        ),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("era")
+        ))
+    ));
+    assert_eq!(
+        Date::parse("2021 AD-03-15", &fd::parse("[year] [era]-[month]-[day]")?)?,
+        date!("2021-03-15")
+    );
+    assert_eq!(
+        Date::parse("-0043 BC-03-15", &fd::parse("[year] [era]-[month]-[day]")?)?,
+        date!("-0043-03-15")
+    );
+    assert!(matches!(
+        Date::parse("2021 BC-03-15", &fd::parse("[year] [era]-[month]-[day]")?),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::ComponentRange(time::error::ComponentRange {
+                name: "era",
+                ..
+            })
+        ))
+    ));
+    assert!(matches!(
+        Date::parse(
+            "Monday, 2021-01-02",
+            &fd::parse("[weekday], [year]-[month]-[day]")?
+        ),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::ComponentRange(time::error::ComponentRange {
+                name: "weekday",
+                ..
+            })
+        ))
+    ));
+    assert!(matches!(
+        Date::parse(
+            "2021-05 week 1 Monday",
+            &fd::parse("[year]-[month] week [week_of_month] [weekday]")?
+        ),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::ComponentRange(_)
+        ))
+    ));
+    assert!(matches!(
+        Date::parse(
+            "Q1 2021-07-01",
+            &fd::parse("Q[quarter] [year]-[month]-[day]")?
+        ),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::ComponentRange(time::error::ComponentRange {
+                name: "quarter",
+                ..
+            })
+        ))
+    ));
+    assert!(matches!(
+        Date::parse(
+            "2021-Q2 week 01 Monday",
+            &fd::parse("[year]-Q[quarter] week [week_of_quarter] [weekday]")?
+        ),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::ComponentRange(time::error::ComponentRange {
+                name: "quarter",
+                ..
+            })
+        ))
+    ));
+    assert!(matches!(
+        Date::parse(
+            "2021-Q1 week 15 Monday",
+            &fd::parse("[year]-Q[quarter] week [week_of_quarter] [weekday]")?
+        ),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("week of quarter")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse(
+            "5 Thursday 2021-03",
+            &fd::parse("[weekday_in_month] [weekday] [year]-[month]")?
+        ),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::ComponentRange(_)
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("Q5 2021", &fd::parse("Q[quarter] [year]")?),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("quarter")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("Q3 2021", &fd::parse("Q[quarter] [year]")?),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::InsufficientInformation
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("March 3th, 2021", &format_description),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("day")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("March 3, 2021", &format_description),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("day")
+        ))
+    ));
+
+    assert!(matches!(
+        Date::try_from(Parsed::new()),
+        Err(time::error::TryFromParsed::InsufficientInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_offset() -> time::Result<()> {
+    assert_eq!(
+        UtcOffset::parse("01", &fd::parse("[offset_hour sign:mandatory]")?),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("offset hour")
+        ))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn offset_abbreviation() -> time::Result<()> {
+    let format_description = fd::parse("[offset_abbreviation]")?;
+    assert_eq!(UtcOffset::parse("EST", &format_description)?, offset!("-5"));
+    assert_eq!(
+        UtcOffset::parse("IST", &format_description)?,
+        offset!("+5:30")
+    );
+    assert_eq!(
+        UtcOffset::parse("AKST", &format_description)?,
+        offset!("-9")
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2021-07-04 12:00 CEST",
+            &fd::parse("[year]-[month]-[day] [hour]:[minute] [offset_abbreviation]")?
+        )?,
+        datetime!("2021-07-04 12:00 +2")
+    );
+    assert_eq!(
+        UtcOffset::parse(
+            "pdt",
+            &fd::parse("[offset_abbreviation case_sensitive:false]")?
+        )?,
+        offset!("-7")
+    );
+    assert!(matches!(
+        UtcOffset::parse("pdt", &format_description),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("offset abbreviation")
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn offset() -> time::Result<()> {
+    let format_description = fd::parse("[offset]")?;
+    assert_eq!(UtcOffset::parse("Z", &format_description)?, offset!("UTC"));
+    assert_eq!(UtcOffset::parse("z", &format_description)?, offset!("UTC"));
+    assert_eq!(
+        UtcOffset::parse("+00:00", &format_description)?,
+        offset!("UTC")
+    );
+    assert_eq!(
+        UtcOffset::parse("+05:30", &format_description)?,
+        offset!("+5:30")
+    );
+    assert_eq!(
+        UtcOffset::parse("-00:30", &format_description)?,
+        offset!("-0:30")
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2021-07-04T12:00:00Z",
+            &fd::parse("[year]-[month]-[day]T[hour]:[minute]:[second][offset]")?
+        )?,
+        datetime!("2021-07-04 12:00 UTC")
+    );
+
+    let format_description = fd::parse("[offset separator:optional minute:optional]")?;
+    assert_eq!(
+        UtcOffset::parse("+05:30", &format_description)?,
+        offset!("+5:30")
+    );
+    assert_eq!(
+        UtcOffset::parse("+0530", &format_description)?,
+        offset!("+5:30")
+    );
+    assert_eq!(UtcOffset::parse("-08", &format_description)?, offset!("-8"));
+    assert_eq!(
+        UtcOffset::parse("-08:", &fd::parse("[offset minute:optional]:")?)?,
+        offset!("-8")
+    );
+    assert_eq!(
+        UtcOffset::parse("+0530", &fd::parse("[offset separator:none]")?)?,
+        offset!("+5:30")
+    );
+
+    for (input, format_description) in [
+        ("Z", "[offset zulu:false]"),
+        ("05:30", "[offset]"),
+        ("+0530", "[offset]"),
+        ("+05:30", "[offset separator:none]"),
+        ("+05", "[offset]"),
+        ("+24:00", "[offset]"),
+        ("+05:60", "[offset]"),
+    ]
+    .iter()
+    {
+        assert!(matches!(
+            UtcOffset::parse(input, &fd::parse(format_description)?),
+            Err(time::error::Parse::ParseFromDescription(
+                time::error::ParseFromDescription::InvalidComponent("offset")
+            ))
+        ));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn leap_second() -> time::Result<()> {
+    let format_description =
+        fd::parse("[year]-[month]-[day] [hour]:[minute]:[second].[subsecond]")?;
+    let input = b"2016-12-31 23:59:60.5";
+
+    assert!(matches!(
+        PrimitiveDateTime::parse("2016-12-31 23:59:60.5", &format_description),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::ComponentRange(_)
+        ))
+    ));
+
+    let mut parsed = Parsed::new();
+    parsed.leap_second_policy = Some(LeapSecondPolicy::Reject);
+    parsed.parse_items(input, &format_description)?;
+    assert_eq!(parsed.is_leap_second, Some(true));
+    assert!(matches!(
+        PrimitiveDateTime::try_from(parsed),
+        Err(time::error::TryFromParsed::ComponentRange(_))
+    ));
+
+    parsed.leap_second_policy = Some(LeapSecondPolicy::Clamp);
+    assert_eq!(
+        PrimitiveDateTime::try_from(parsed)?,
+        datetime!("2016-12-31 23:59:59.999_999_999")
+    );
+    assert_eq!(Time::try_from(parsed)?, time!("23:59:59.999_999_999"));
+
+    parsed.leap_second_policy = Some(LeapSecondPolicy::RollOver);
+    assert_eq!(
+        PrimitiveDateTime::try_from(parsed)?,
+        datetime!("2017-01-01 0:00:00.5")
+    );
+    assert_eq!(Time::try_from(parsed)?, time!("0:00:00.5"));
+
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"2016-12-31 23:59:59.5", &format_description)?;
+    assert_eq!(parsed.is_leap_second, Some(false));
+
+    // Well-known formats treat a leap second as the previous second unless a policy is set.
+    let mut parsed = Parsed::new();
+    parsed.parse_prefix(b"2016-12-31T23:59:60Z", &Rfc3339)?;
+    assert_eq!(parsed.is_leap_second, Some(true));
+    assert_eq!(
+        OffsetDateTime::try_from(parsed)?,
+        datetime!("2016-12-31 23:59:59 UTC")
+    );
+
+    let mut parsed = Parsed::new();
+    parsed.leap_second_policy = Some(LeapSecondPolicy::RollOver);
+    parsed.parse_prefix(b"2016-12-31T23:59:60Z", &Rfc3339)?;
+    assert_eq!(
+        OffsetDateTime::try_from(parsed)?,
+        datetime!("2017-01-01 0:00 UTC")
+    );
+
+    let mut parsed = Parsed::new();
+    parsed.leap_second_policy = Some(LeapSecondPolicy::Reject);
+    parsed.parse_prefix(b"2016-12-31T23:59:60Z", &Iso8601::DEFAULT)?;
+    assert!(matches!(
+        OffsetDateTime::try_from(parsed),
+        Err(time::error::TryFromParsed::ComponentRange(_))
+    ));
+
+    let mut parsed = Parsed::new();
+    parsed.leap_second_policy = Some(LeapSecondPolicy::RollOver);
+    parsed.parse_prefix(b"Sat, 31 Dec 2016 23:59:60 +0000", &Rfc2822)?;
+    assert_eq!(
+        OffsetDateTime::try_from(parsed)?,
+        datetime!("2017-01-01 0:00 UTC")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn duration() -> time::Result<()> {
+    let stopwatch = fd::parse(
+        "[duration_sign][duration_hour \
+         repr:total]:[duration_minute]:[duration_second].[duration_subsecond]",
+    )?;
+    assert_eq!(
+        Duration::parse("01:23:45.123", &stopwatch)?,
+        Duration::new(5_025, 123_000_000)
+    );
+    assert_eq!(
+        Duration::parse("-01:23:45.123", &stopwatch)?,
+        Duration::new(-5_025, -123_000_000)
+    );
+    assert_eq!(
+        Duration::parse("100:00:00.0", &stopwatch)?,
+        Duration::hours(100)
+    );
+    assert_eq!(
+        Duration::parse(
+            "1d 1h 5m",
+            &fd::parse(
+                "[duration_day]d [duration_hour padding:none]h [duration_minute padding:none]m"
+            )?
+        )?,
+        Duration::minutes(1_505)
+    );
+    assert_eq!(
+        Duration::parse("90", &fd::parse("[duration_second repr:total]")?)?,
+        Duration::seconds(90)
+    );
+    assert_eq!(
+        Duration::parse(
+            "+05",
+            &fd::parse("[duration_sign sign:mandatory][duration_second]")?
+        )?,
+        Duration::seconds(5)
+    );
+
+    assert!(matches!(
+        Duration::parse("01:60:00.0", &stopwatch),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("duration minute")
+        ))
+    ));
+    assert!(matches!(
+        Duration::parse(
+            "05",
+            &fd::parse("[duration_sign sign:mandatory][duration_second]")?
+        ),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("duration sign")
+        ))
+    ));
+    assert!(matches!(
+        Duration::parse("-", &fd::parse("[duration_sign]")?),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::InsufficientInformation { .. }
+        ))
+    ));
+    assert!(matches!(
+        Duration::parse("9999999999999999999", &fd::parse("[duration_day]")?),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::ComponentRange(_)
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_components() -> time::Result<()> {
+    macro_rules! parse_component {
+        ($component:expr, $input:expr,_. $property:ident == $expected:expr) => {
+            let mut parsed = Parsed::new();
+            parsed.parse_component($input, $component)?;
+            assert_eq!(parsed.$property, $expected);
+        };
+    }
+
+    parse_component!(
+        Component::Year(modifier::Year {
             padding: modifier::Padding::Zero,
             repr: modifier::YearRepr::Full,
-            iso_week_based: true,
+            iso_week_based: false,
             sign_is_mandatory: false,
+            digits: 4,
         }),
         b"2021",
-        _.iso_year == Some(2021)
+        _.year == Some(2021)
+    );
+    parse_component!(
+        Component::Year(modifier::Year {
+            padding: modifier::Padding::Zero,
+            repr: modifier::YearRepr::LastTwo,
+            iso_week_based: false,
+            sign_is_mandatory: false,
+            digits: 4,
+        }),
+        b"21",
+        _.year_last_two == Some(21)
+    );
+    parse_component!(
+        Component::Year(modifier::Year {
+            padding: modifier::Padding::Zero,
+            repr: modifier::YearRepr::Full,
+            iso_week_based: true,
+            sign_is_mandatory: false,
+            digits: 4,
+        }),
+        b"2021",
+        _.iso_year == Some(2021)
+    );
+    parse_component!(
+        Component::Year(modifier::Year {
+            padding: modifier::Padding::Zero,
+            repr: modifier::YearRepr::LastTwo,
+            iso_week_based: true,
+            sign_is_mandatory: false,
+            digits: 4,
+        }),
+        b"21",
+        _.iso_year_last_two == Some(21)
+    );
+    parse_component!(
+        Component::Month(modifier::Month {
+            padding: modifier::Padding::Space,
+            repr: modifier::MonthRepr::Numerical,
+            case_sensitive: true,
+        }),
+        b" 1",
+        _.month == 1.try_into().ok()
+    );
+    parse_component!(
+        Component::Month(modifier::Month {
+            padding: modifier::Padding::None,
+            repr: modifier::MonthRepr::Short,
+            case_sensitive: true,
+        }),
+        b"Jan",
+        _.month == 1.try_into().ok()
+    );
+    parse_component!(
+        Component::Month(modifier::Month {
+            padding: modifier::Padding::None,
+            repr: modifier::MonthRepr::Long,
+            case_sensitive: true,
+        }),
+        b"January",
+        _.month == 1.try_into().ok()
+    );
+    parse_component!(
+        Component::Quarter(modifier::Quarter {
+            padding: modifier::Padding::Zero,
+            suffix: modifier::Suffix::None,
+        }),
+        b"3",
+        _.quarter == 3.try_into().ok()
+    );
+    parse_component!(
+        Component::WeekOfMonth(modifier::WeekOfMonth {
+            padding: modifier::Padding::Zero,
+            suffix: modifier::Suffix::None,
+        }),
+        b"6",
+        _.week_of_month == 6.try_into().ok()
+    );
+    parse_component!(
+        Component::WeekOfQuarter(modifier::WeekOfQuarter {
+            padding: modifier::Padding::Zero,
+            suffix: modifier::Suffix::None,
+        }),
+        b"14",
+        _.week_of_quarter == 14.try_into().ok()
+    );
+    parse_component!(
+        Component::WeekdayInMonth(modifier::WeekdayInMonth {
+            padding: modifier::Padding::Zero,
+            suffix: modifier::Suffix::Ordinal,
+        }),
+        b"2nd",
+        _.weekday_in_month == 2.try_into().ok()
+    );
+    parse_component!(
+        Component::Century(modifier::Century {
+            padding: modifier::Padding::Zero,
+            suffix: modifier::Suffix::None,
+        }),
+        b"20",
+        _.century == Some(20)
+    );
+    parse_component!(
+        Component::Century(modifier::Century {
+            padding: modifier::Padding::Zero,
+            suffix: modifier::Suffix::None,
+        }),
+        b"-01",
+        _.century == Some(-1)
+    );
+    parse_component!(
+        Component::Century(modifier::Century {
+            padding: modifier::Padding::Zero,
+            suffix: modifier::Suffix::None,
+        }),
+        b"-00",
+        _.century_is_negative == Some(true)
+    );
+    parse_component!(
+        Component::Ordinal(modifier::Ordinal {
+            padding: modifier::Padding::Zero,
+            suffix: modifier::Suffix::None,
+        }),
+        b"012",
+        _.ordinal == 12.try_into().ok()
+    );
+    parse_component!(
+        Component::Weekday(modifier::Weekday {
+            repr: modifier::WeekdayRepr::Short,
+            one_indexed: false,
+            case_sensitive: true,
+        }),
+        b"Sun",
+        _.weekday == Some(Weekday::Sunday)
+    );
+    parse_component!(
+        Component::Weekday(modifier::Weekday {
+            repr: modifier::WeekdayRepr::Long,
+            one_indexed: false,
+            case_sensitive: true,
+        }),
+        b"Sunday",
+        _.weekday == Some(Weekday::Sunday)
+    );
+    parse_component!(
+        Component::Weekday(modifier::Weekday {
+            repr: modifier::WeekdayRepr::Sunday,
+            one_indexed: false,
+            case_sensitive: true,
+        }),
+        b"0",
+        _.weekday == Some(Weekday::Sunday)
+    );
+    parse_component!(
+        Component::Weekday(modifier::Weekday {
+            repr: modifier::WeekdayRepr::Sunday,
+            one_indexed: true,
+            case_sensitive: true,
+        }),
+        b"1",
+        _.weekday == Some(Weekday::Sunday)
+    );
+    parse_component!(
+        Component::Weekday(modifier::Weekday {
+            repr: modifier::WeekdayRepr::Monday,
+            one_indexed: false,
+            case_sensitive: true,
+        }),
+        b"6",
+        _.weekday == Some(Weekday::Sunday)
+    );
+    parse_component!(
+        Component::Weekday(modifier::Weekday {
+            repr: modifier::WeekdayRepr::Monday,
+            one_indexed: true,
+            case_sensitive: true,
+        }),
+        b"7",
+        _.weekday == Some(Weekday::Sunday)
+    );
+    parse_component!(
+        Component::WeekNumber(modifier::WeekNumber {
+            padding: modifier::Padding::None,
+            repr: modifier::WeekNumberRepr::Sunday,
+            suffix: modifier::Suffix::None,
+        }),
+        b"2",
+        _.sunday_week_number == Some(2)
+    );
+    parse_component!(
+        Component::WeekNumber(modifier::WeekNumber {
+            padding: modifier::Padding::None,
+            repr: modifier::WeekNumberRepr::Monday,
+            suffix: modifier::Suffix::None,
+        }),
+        b"2",
+        _.monday_week_number == Some(2)
+    );
+    parse_component!(
+        Component::WeekNumber(modifier::WeekNumber {
+            padding: modifier::Padding::None,
+            repr: modifier::WeekNumberRepr::Iso,
+            suffix: modifier::Suffix::None,
+        }),
+        b"2",
+        _.iso_week_number == 2.try_into().ok()
+    );
+    parse_component!(
+        Component::Subsecond(modifier::Subsecond {
+            digits: modifier::SubsecondDigits::One,
+            rounding: modifier::SubsecondRounding::Truncate,
+        }),
+        b"1",
+        _.subsecond == Some(100_000_000)
+    );
+    parse_component!(
+        Component::Subsecond(modifier::Subsecond {
+            digits: modifier::SubsecondDigits::Two,
+            rounding: modifier::SubsecondRounding::Truncate,
+        }),
+        b"12",
+        _.subsecond == Some(120_000_000)
+    );
+    parse_component!(
+        Component::Subsecond(modifier::Subsecond {
+            digits: modifier::SubsecondDigits::Three,
+            rounding: modifier::SubsecondRounding::Truncate,
+        }),
+        b"123",
+        _.subsecond == Some(123_000_000)
+    );
+    parse_component!(
+        Component::Subsecond(modifier::Subsecond {
+            digits: modifier::SubsecondDigits::Four,
+            rounding: modifier::SubsecondRounding::Truncate,
+        }),
+        b"1234",
+        _.subsecond == Some(123_400_000)
+    );
+    parse_component!(
+        Component::Subsecond(modifier::Subsecond {
+            digits: modifier::SubsecondDigits::Five,
+            rounding: modifier::SubsecondRounding::Truncate,
+        }),
+        b"12345",
+        _.subsecond == Some(123_450_000)
+    );
+    parse_component!(
+        Component::Subsecond(modifier::Subsecond {
+            digits: modifier::SubsecondDigits::Six,
+            rounding: modifier::SubsecondRounding::Truncate,
+        }),
+        b"123456",
+        _.subsecond == Some(123_456_000)
+    );
+    parse_component!(
+        Component::Subsecond(modifier::Subsecond {
+            digits: modifier::SubsecondDigits::Seven,
+            rounding: modifier::SubsecondRounding::Truncate,
+        }),
+        b"1234567",
+        _.subsecond == Some(123_456_700)
+    );
+    parse_component!(
+        Component::Subsecond(modifier::Subsecond {
+            digits: modifier::SubsecondDigits::Eight,
+            rounding: modifier::SubsecondRounding::Truncate,
+        }),
+        b"12345678",
+        _.subsecond == Some(123_456_780)
+    );
+    parse_component!(
+        Component::Subsecond(modifier::Subsecond {
+            digits: modifier::SubsecondDigits::Nine,
+            rounding: modifier::SubsecondRounding::Truncate,
+        }),
+        b"123456789",
+        _.subsecond == Some(123_456_789)
+    );
+    parse_component!(
+        Component::Subsecond(modifier::Subsecond {
+            digits: modifier::SubsecondDigits::OneOrMore,
+            rounding: modifier::SubsecondRounding::Truncate,
+        }),
+        b"123456789",
+        _.subsecond == Some(123_456_789)
+    );
+    parse_component!(
+        Component::Subsecond(modifier::Subsecond {
+            digits: modifier::SubsecondDigits::Range { min: 3, max: 6 },
+            rounding: modifier::SubsecondRounding::HalfEven,
+        }),
+        b"1234",
+        _.subsecond == Some(123_400_000)
+    );
+    parse_component!(
+        Component::Subsecond(modifier::Subsecond {
+            digits: modifier::SubsecondDigits::Range { min: 3, max: 6 },
+            rounding: modifier::SubsecondRounding::Truncate,
+        }),
+        b"1234567",
+        _.subsecond == Some(123_456_000)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn case_insensitive() -> time::Result<()> {
+    let format_description = fd::parse(
+        "[weekday repr:short case_sensitive:false] [day] [month repr:short case_sensitive:false] \
+         [year]",
+    )?;
+    for input in &[
+        "MON 15 MAR 2021",
+        "mon 15 mar 2021",
+        "Mon 15 Mar 2021",
+        "mOn 15 mAr 2021",
+    ] {
+        assert_eq!(
+            Date::parse(input, &format_description)?,
+            date!("2021-03-15")
+        );
+    }
+    assert_eq!(
+        Date::parse(
+            "monday, 15 march 2021",
+            &fd::parse(
+                "[weekday case_sensitive:false], [day] [month repr:long case_sensitive:false] \
+                 [year]"
+            )?
+        )?,
+        date!("2021-03-15")
+    );
+    assert_eq!(
+        Time::parse(
+            "10:15 pm",
+            &format_description!(
+                "[hour repr:12]:[minute] [period case:upper case_sensitive:false]"
+            )
+        )?,
+        time!("22:15")
+    );
+    assert!(matches!(
+        Date::parse(
+            "15 mar 2021",
+            &format_description!("[day] [month repr:short] [year]")
+        ),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("month")
+        ))
+    ));
+
+    let mut parsed = Parsed::new();
+    let remaining = parsed.parse_component(
+        b"dEcember",
+        Component::Month(modifier::Month {
+            padding: modifier::Padding::Zero,
+            repr: modifier::MonthRepr::Long,
+            case_sensitive: false,
+        }),
+    )?;
+    assert!(remaining.is_empty());
+    assert_eq!(parsed.month, 12.try_into().ok());
+
+    Ok(())
+}
+
+#[test]
+fn unix_timestamp() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("1615803300", &fd::parse("[unix_timestamp]")?)?,
+        datetime!("2021-03-15 10:15 UTC")
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "1615803300123",
+            &format_description!("[unix_timestamp precision:millisecond]")
+        )?,
+        datetime!("2021-03-15 10:15:00.123 UTC")
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "1615803300123456",
+            &format_description!("[unix_timestamp precision:microsecond]")
+        )?,
+        datetime!("2021-03-15 10:15:00.123_456 UTC")
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "-1 +02",
+            &format_description!("[unix_timestamp precision:nanosecond] [offset_hour]")
+        )?,
+        datetime!("1970-01-01 1:59:59.999_999_999 +02:00")
+    );
+    assert!(matches!(
+        OffsetDateTime::parse(
+            "1615803300",
+            &format_description!("[unix_timestamp sign:mandatory]")
+        ),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("unix timestamp")
+        ))
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse(
+            "99999999999999999999",
+            &format_description!("[unix_timestamp]")
+        ),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::ComponentRange(_)
+        ))
+    ));
+    assert_eq!(
+        OffsetDateTime::parse(
+            "1615803300.123",
+            &format_description!("[unix_timestamp].[subsecond digits:3]")
+        )?,
+        datetime!("2021-03-15 10:15:00.123 UTC")
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "1615803300123.123",
+            &format_description!("[unix_timestamp precision:millisecond].[subsecond digits:3]")
+        )?,
+        datetime!("2021-03-15 10:15:00.123 UTC")
+    );
+    assert!(matches!(
+        OffsetDateTime::parse(
+            "1615803300123.456",
+            &format_description!("[unix_timestamp precision:millisecond].[subsecond digits:3]")
+        ),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::ComponentRange(time::error::ComponentRange {
+                name: "subsecond",
+                ..
+            })
+        ))
+    ));
+    let description = format_description!("[unix_timestamp].[subsecond digits:3]");
+    for &date_time in &[
+        datetime!("2021-03-15 10:15:00.123 UTC"),
+        datetime!("1969-12-31 23:59:58.877 UTC"),
+    ] {
+        assert_eq!(
+            OffsetDateTime::parse(&date_time.format(&description)?, &description)?,
+            date_time
+        );
+    }
+    assert_eq!(
+        Parsed::new()
+            .parse_component(
+                b"+1615803300",
+                Component::UnixTimestamp(modifier::UnixTimestamp {
+                    precision: modifier::UnixTimestampPrecision::Second,
+                    sign_is_mandatory: true,
+                })
+            )
+            .map(|_| ()),
+        Ok(())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parse_prefix() -> time::Result<()> {
+    let format_description = format_description!("[year]-[month]-[day]");
+    assert_eq!(
+        Date::parse_prefix("2021-03-15 rest", &format_description)?,
+        (date!("2021-03-15"), " rest")
+    );
+    assert_eq!(
+        Date::parse_prefix("2021-03-15", &format_description)?,
+        (date!("2021-03-15"), "")
+    );
+    assert_eq!(
+        Time::parse_prefix("12:30 rest", &format_description!("[hour]:[minute]"))?,
+        (time!("12:30"), " rest")
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse_prefix("2021-03-15T12:30:00Zé", &Rfc3339)?,
+        (datetime!("2021-03-15 12:30"), "é")
+    );
+    assert_eq!(
+        OffsetDateTime::parse_prefix("2021-03-15T12:30:00Z]", &Rfc3339)?,
+        (datetime!("2021-03-15 12:30 UTC"), "]")
+    );
+    assert_eq!(
+        UtcOffset::parse_prefix("+01:00 CET", &fd::parse("[offset_hour]:[offset_minute]")?)?,
+        (offset!("+1"), " CET")
+    );
+    assert!(matches!(
+        Date::parse_prefix("2021-03-1x", &format_description),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("day")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse_prefix("2021-13-15 rest", &format_description),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::ComponentRange(_)
+        ))
+    ));
+    assert!(matches!(
+        Time::parse_prefix("é", &format_description!("[ignore count:1]")),
+        Err(time::error::Parse::UnexpectedPartialCharacter { .. })
+    ));
+    assert!(matches!(
+        Time::parse_prefix("é", &time::format_description::FormatItem::Literal(b"\xC3")),
+        Err(time::error::Parse::UnexpectedPartialCharacter { .. })
+    ));
+
+    let mut parsed = Parsed::new();
+    assert_eq!(
+        parsed.parse_prefix(b"2021-03-15 rest", &format_description)?,
+        b" rest"
+    );
+    assert_eq!(parsed.year, Some(2021));
+    assert!(parsed.parse_prefix(b"12:", &format_description).is_err());
+    assert_eq!(parsed.year, Some(2021));
+
+    Ok(())
+}
+
+#[test]
+fn parse_from_reader() -> std::io::Result<()> {
+    let format_description = format_description!("[year]-[month]-[day] [hour]:[minute]");
+
+    let mut reader = "2021-03-15 12:30 first\n2021-03-16 08:00 and the second line\n".as_bytes();
+    let mut rest = String::new();
+    assert_eq!(
+        PrimitiveDateTime::parse_from_reader(&mut reader, &format_description)?,
+        datetime!("2021-03-15 12:30")
+    );
+    reader.read_line(&mut rest)?;
+    assert_eq!(rest, " first\n");
+    assert_eq!(
+        PrimitiveDateTime::parse_from_reader(&mut reader, &format_description)?,
+        datetime!("2021-03-16 08:00")
+    );
+    assert!(reader.starts_with(b" and the second line"));
+
+    // Values that span several of the reader's buffers are read in their entirety.
+    let mut reader = BufReader::with_capacity(3, "2021-03-15 12:30".as_bytes());
+    assert_eq!(
+        PrimitiveDateTime::parse_from_reader(&mut reader, &format_description)?,
+        datetime!("2021-03-15 12:30")
+    );
+    assert!(reader.fill_buf()?.is_empty());
+    let mut reader = BufReader::with_capacity(3, "12345".as_bytes());
+    assert_eq!(
+        OffsetDateTime::parse_from_reader(&mut reader, &format_description!("[unix_timestamp]"))?,
+        datetime!("1970-01-01 3:25:45 UTC")
+    );
+
+    let mut reader = "2021-03-15".as_bytes();
+    assert_eq!(
+        Date::parse_from_reader(&mut reader, &format_description!("[year]-[month]-[day]"))?,
+        date!("2021-03-15")
+    );
+    assert!(reader.is_empty());
+    assert_eq!(
+        Time::parse_from_reader(
+            &mut "12:30".as_bytes(),
+            &format_description!("[hour]:[minute]")
+        )?,
+        time!("12:30")
+    );
+    assert_eq!(
+        UtcOffset::parse_from_reader(&mut "-05".as_bytes(), &format_description!("[offset_hour]"))?,
+        offset!("-5")
+    );
+
+    let err = Date::parse_from_reader(
+        &mut "2021-02-30".as_bytes(),
+        &format_description!("[year]-[month]-[day]"),
+    )
+    .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(matches!(
+        err.get_ref().and_then(|err| err.downcast_ref()),
+        Some(time::error::Parse::TryFromParsed(_))
+    ));
+    let err = Date::parse_from_reader(
+        &mut "2021-02".as_bytes(),
+        &format_description!("[year]-[month]-[day]"),
+    )
+    .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(matches!(
+        err.get_ref().and_then(|err| err.downcast_ref()),
+        Some(time::error::Parse::ParseFromDescription(_))
+    ));
+
+    // A section that is cut off at the end of a buffer is still parsed.
+    let format_description = format_description!("[hour]:[minute][optional [:[second]]]");
+    let mut reader = "12:30:".as_bytes().chain("45".as_bytes());
+    assert_eq!(
+        Time::parse_from_reader(&mut reader, &format_description)?,
+        time!("12:30:45")
     );
-    parse_component!(
-        Component::Year(modifier::Year {
-            padding: modifier::Padding::Zero,
-            repr: modifier::YearRepr::LastTwo,
-            iso_week_based: true,
-            sign_is_mandatory: false,
-        }),
-        b"21",
-        _.iso_year_last_two == Some(21)
+    assert!(reader.fill_buf()?.is_empty());
+    let mut reader = BufReader::with_capacity(1, "12:30:45".as_bytes());
+    assert_eq!(
+        Time::parse_from_reader(&mut reader, &format_description)?,
+        time!("12:30:45")
     );
-    parse_component!(
-        Component::Month(modifier::Month {
-            padding: modifier::Padding::Space,
-            repr: modifier::MonthRepr::Numerical,
-        }),
-        b" 1",
-        _.month == 1.try_into().ok()
+    assert!(reader.fill_buf()?.is_empty());
+    let mut reader = "12:30:"
+        .as_bytes()
+        .chain("45 and the rest of the line".as_bytes());
+    assert_eq!(
+        Time::parse_from_reader(&mut reader, &format_description)?,
+        time!("12:30:45")
     );
-    parse_component!(
-        Component::Month(modifier::Month {
-            padding: modifier::Padding::None,
-            repr: modifier::MonthRepr::Short,
-        }),
-        b"Jan",
-        _.month == 1.try_into().ok()
+    rest.clear();
+    reader.read_line(&mut rest)?;
+    assert_eq!(rest, " and the rest of the line");
+    // Too little input follows the value to know that it has ended.
+    let err = Time::parse_from_reader(
+        &mut BufReader::with_capacity(1, "12:30 rest".as_bytes()),
+        &format_description,
+    )
+    .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    let mut parsed = Parsed::new();
+    let mut reader = "12:30:15, followed by more".as_bytes();
+    parsed.parse_from_reader(&mut reader, &format_description!("[hour]:[minute]"))?;
+    assert_eq!(parsed.hour_24, Some(12));
+    assert_eq!(parsed.minute, Some(30));
+    assert_eq!(reader, b":15, followed by more");
+
+    Ok(())
+}
+
+#[test]
+fn ignore_end() -> time::Result<()> {
+    assert_eq!(
+        Date::parse(
+            "Mon, 2021-03-15",
+            &format_description!("[ignore count:5][year]-[month]-[day][end]")
+        )?,
+        date!("2021-03-15")
     );
-    parse_component!(
-        Component::Month(modifier::Month {
-            padding: modifier::Padding::None,
-            repr: modifier::MonthRepr::Long,
-        }),
-        b"January",
-        _.month == 1.try_into().ok()
+    assert_eq!(
+        Date::parse(
+            "| 2021 | 3   | 15 |",
+            &fd::parse(
+                "[ignore count:2][year][ignore count:3][month padding:none][ignore \
+                 count:5][day][ignore count:2]"
+            )?
+        )?,
+        date!("2021-03-15")
     );
-    parse_component!(
-        Component::Ordinal(modifier::Ordinal {
-            padding: modifier::Padding::Zero,
-        }),
-        b"012",
-        _.ordinal == 12.try_into().ok()
+    assert!(matches!(
+        Date::parse("Mon", &format_description!("[ignore count:5]")),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("ignore")
+        ))
+    ));
+    assert_eq!(
+        Parsed::new().parse_items(
+            b"2021-03-15T",
+            &format_description!("[year]-[month]-[day][end]")
+        ),
+        Err(time::error::ParseFromDescription::InvalidComponent("end"))
     );
-    parse_component!(
-        Component::Weekday(modifier::Weekday {
-            repr: modifier::WeekdayRepr::Short,
-            one_indexed: false,
-        }),
-        b"Sun",
-        _.weekday == Some(Weekday::Sunday)
+    assert_eq!(
+        Parsed::new().parse_component(b"", Component::End(modifier::End)),
+        Ok(&b""[..])
     );
-    parse_component!(
-        Component::Weekday(modifier::Weekday {
-            repr: modifier::WeekdayRepr::Long,
-            one_indexed: false,
-        }),
-        b"Sunday",
-        _.weekday == Some(Weekday::Sunday)
+
+    Ok(())
+}
+
+#[test]
+fn optional() -> time::Result<()> {
+    let format_description = format_description!("[hour]:[minute][optional [:[second]]]");
+    assert_eq!(Time::parse("10:15", &format_description)?, time!("10:15"));
+    assert_eq!(
+        Time::parse("10:15:30", &format_description)?,
+        time!("10:15:30")
     );
-    parse_component!(
-        Component::Weekday(modifier::Weekday {
-            repr: modifier::WeekdayRepr::Sunday,
-            one_indexed: false,
-        }),
-        b"0",
-        _.weekday == Some(Weekday::Sunday)
+    assert!(matches!(
+        Time::parse("10:15:3", &format_description),
+        Err(time::error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+
+    let format_description = format_description!("[year][optional [-[month][optional [-[day]]]]]");
+    assert_eq!(
+        Date::parse("2021-03-15", &format_description)?,
+        date!("2021-03-15")
     );
-    parse_component!(
-        Component::Weekday(modifier::Weekday {
-            repr: modifier::WeekdayRepr::Sunday,
-            one_indexed: true,
-        }),
-        b"1",
-        _.weekday == Some(Weekday::Sunday)
+    assert!(matches!(
+        Date::parse("2021-03", &format_description),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::InsufficientInformation { .. }
+        ))
+    ));
+
+    // A section that only partially matches has no effect.
+    let format_description =
+        format_description!("[hour][optional [:[minute]h]][optional [:[second]]]");
+    assert!(matches!(
+        Time::parse("10:30", &format_description),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::InsufficientInformation { .. }
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn first() -> time::Result<()> {
+    let format_description = format_description!(
+        "[first [[year]-[month]-[day]] [[day]/[month]/[year]] [[month repr:long] [day], [year]]]"
     );
-    parse_component!(
-        Component::Weekday(modifier::Weekday {
-            repr: modifier::WeekdayRepr::Monday,
-            one_indexed: false,
-        }),
-        b"6",
-        _.weekday == Some(Weekday::Sunday)
+    assert_eq!(
+        Date::parse("2021-03-15", &format_description)?,
+        date!("2021-03-15")
     );
-    parse_component!(
-        Component::Weekday(modifier::Weekday {
-            repr: modifier::WeekdayRepr::Monday,
-            one_indexed: true,
-        }),
-        b"7",
-        _.weekday == Some(Weekday::Sunday)
+    assert_eq!(
+        Date::parse("15/03/2021", &format_description)?,
+        date!("2021-03-15")
     );
-    parse_component!(
-        Component::WeekNumber(modifier::WeekNumber {
-            padding: modifier::Padding::None,
-            repr: modifier::WeekNumberRepr::Sunday,
-        }),
-        b"2",
-        _.sunday_week_number == Some(2)
+    assert_eq!(
+        Date::parse("March 15, 2021", &format_description)?,
+        date!("2021-03-15")
     );
-    parse_component!(
-        Component::WeekNumber(modifier::WeekNumber {
-            padding: modifier::Padding::None,
-            repr: modifier::WeekNumberRepr::Monday,
-        }),
-        b"2",
-        _.monday_week_number == Some(2)
+    assert!(matches!(
+        Date::parse("15.03.2021", &format_description),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::NoMatchingAlternative { count: 3 }
+        ))
+    ));
+
+    // A failed alternative has no effect.
+    let format_description =
+        format_description!("[first [[hour]:[minute]h] [[hour]]][optional [:[second]]]");
+    assert!(matches!(
+        Time::parse("10:30", &format_description),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::InsufficientInformation { .. }
+        ))
+    ));
+
+    let mut parsed = Parsed::new();
+    assert_eq!(
+        parsed.parse_item(b"10", &time::format_description::FormatItem::First(&[]))?,
+        b"10"
     );
-    parse_component!(
-        Component::WeekNumber(modifier::WeekNumber {
-            padding: modifier::Padding::None,
-            repr: modifier::WeekNumberRepr::Iso,
-        }),
-        b"2",
-        _.iso_week_number == 2.try_into().ok()
+
+    Ok(())
+}
+
+#[test]
+fn owned() -> time::Result<()> {
+    let format_description =
+        time::format_description::parse_owned("[hour]:[minute][optional [:[second]]]")?;
+    assert_eq!(Time::parse("10:15", &format_description)?, time!("10:15"));
+    assert_eq!(
+        Time::parse("10:15:30", &format_description)?,
+        time!("10:15:30")
     );
-    parse_component!(
-        Component::Subsecond(modifier::Subsecond {
-            digits: modifier::SubsecondDigits::One
-        }),
-        b"1",
-        _.subsecond == Some(100_000_000)
+
+    let format_description =
+        time::format_description::parse("[hour]:[minute][optional [:[second]]]")?;
+    assert_eq!(Time::parse("10:15", &format_description)?, time!("10:15"));
+    assert_eq!(
+        Time::parse("10:15:30", &format_description)?,
+        time!("10:15:30")
     );
-    parse_component!(
-        Component::Subsecond(modifier::Subsecond {
-            digits: modifier::SubsecondDigits::Two
-        }),
-        b"12",
-        _.subsecond == Some(120_000_000)
+
+    let format_description = time::format_description::parse_owned(
+        "[first [[year]-[month]-[day]] [[day]/[month]/[year]]]",
+    )?;
+    assert_eq!(
+        Date::parse("2021-03-15", &format_description)?,
+        date!("2021-03-15")
     );
-    parse_component!(
-        Component::Subsecond(modifier::Subsecond {
-            digits: modifier::SubsecondDigits::Three
-        }),
-        b"123",
-        _.subsecond == Some(123_000_000)
+    assert_eq!(
+        Date::parse("15/03/2021", &format_description)?,
+        date!("2021-03-15")
     );
-    parse_component!(
-        Component::Subsecond(modifier::Subsecond {
-            digits: modifier::SubsecondDigits::Four
-        }),
-        b"1234",
-        _.subsecond == Some(123_400_000)
+
+    let format_description =
+        time::format_description::parse("[first [[year]-[month]-[day]] [[day]/[month]/[year]]]")?;
+    assert_eq!(
+        Date::parse("15/03/2021", &format_description)?,
+        date!("2021-03-15")
     );
-    parse_component!(
-        Component::Subsecond(modifier::Subsecond {
-            digits: modifier::SubsecondDigits::Five
-        }),
-        b"12345",
-        _.subsecond == Some(123_450_000)
+    assert!(matches!(
+        Date::parse("15.03.2021", &format_description),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::NoMatchingAlternative { .. }
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn locale() -> time::Result<()> {
+    #[derive(Debug)]
+    struct Japanese;
+
+    impl Locale for Japanese {
+        fn long_month_names(&self) -> &[&str; 12] {
+            &[
+                "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                "12月",
+            ]
+        }
+
+        fn short_month_names(&self) -> &[&str; 12] {
+            self.long_month_names()
+        }
+
+        fn narrow_month_names(&self) -> &[&str; 12] {
+            &[
+                "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12",
+            ]
+        }
+
+        fn long_weekday_names(&self) -> &[&str; 7] {
+            &[
+                "月曜日",
+                "火曜日",
+                "水曜日",
+                "木曜日",
+                "金曜日",
+                "土曜日",
+                "日曜日",
+            ]
+        }
+
+        fn short_weekday_names(&self) -> &[&str; 7] {
+            &["月", "火", "水", "木", "金", "土", "日"]
+        }
+
+        fn narrow_weekday_names(&self) -> &[&str; 7] {
+            self.short_weekday_names()
+        }
+
+        fn period_markers(&self, _: bool) -> &[&str; 2] {
+            &["午前", "午後"]
+        }
+
+        fn offset_abbreviations(&self) -> &[(&str, UtcOffset)] {
+            &[("JST", offset!("+9")), ("日本標準時", offset!("+9"))]
+        }
+    }
+
+    let format_description =
+        format_description!("[year]年[month repr:long][day padding:none]日([weekday repr:short])");
+    assert_eq!(
+        Date::parse_with_locale("2021年3月4日(木)", &format_description, &Japanese)?,
+        date!("2021-03-04")
     );
-    parse_component!(
-        Component::Subsecond(modifier::Subsecond {
-            digits: modifier::SubsecondDigits::Six
-        }),
-        b"123456",
-        _.subsecond == Some(123_456_000)
+    // The longest name is used, even if a shorter name is a prefix of it.
+    assert_eq!(
+        Date::parse_with_locale("2021年12月4日(土)", &format_description, &Japanese)?,
+        date!("2021-12-04")
     );
-    parse_component!(
-        Component::Subsecond(modifier::Subsecond {
-            digits: modifier::SubsecondDigits::Seven
-        }),
-        b"1234567",
-        _.subsecond == Some(123_456_700)
+    assert_eq!(
+        Time::parse_with_locale(
+            "午後3時15分",
+            &format_description!("[period][hour repr:12 padding:none]時[minute]分"),
+            &Japanese
+        )?,
+        time!("15:15")
     );
-    parse_component!(
-        Component::Subsecond(modifier::Subsecond {
-            digits: modifier::SubsecondDigits::Eight
-        }),
-        b"12345678",
-        _.subsecond == Some(123_456_780)
+    assert_eq!(
+        PrimitiveDateTime::parse_with_locale(
+            "2021年3月4日 午前10時15分",
+            &fd::parse_owned(
+                "[year]年[month repr:long][day padding:none]日 [period][hour repr:12 \
+                 padding:none]時[minute]分"
+            )?,
+            &Japanese
+        )?,
+        datetime!("2021-03-04 10:15")
     );
-    parse_component!(
-        Component::Subsecond(modifier::Subsecond {
-            digits: modifier::SubsecondDigits::Nine
-        }),
-        b"123456789",
-        _.subsecond == Some(123_456_789)
+    assert_eq!(
+        OffsetDateTime::parse_with_locale(
+            "木曜日 2021-03-04 10:00 +09:00",
+            &format_description!(
+                "[weekday] [year]-[month]-[day] [hour]:[minute] [offset_hour]:[offset_minute]"
+            ),
+            &Japanese
+        )?,
+        datetime!("2021-03-04 10:00 +09:00")
     );
-    parse_component!(
-        Component::Subsecond(modifier::Subsecond {
-            digits: modifier::SubsecondDigits::OneOrMore
-        }),
-        b"123456789",
-        _.subsecond == Some(123_456_789)
+    assert_eq!(
+        UtcOffset::parse_with_locale(
+            "日本標準時",
+            &format_description!("[offset_abbreviation]"),
+            &Japanese
+        )?,
+        offset!("+9")
     );
+    assert!(matches!(
+        UtcOffset::parse_with_locale(
+            "EST",
+            &format_description!("[offset_abbreviation]"),
+            &Japanese
+        ),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("offset abbreviation")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("2021年3月4日(木)", &format_description),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("month")
+        ))
+    ));
+
+    // Narrow names are only parsed if they are unambiguous.
+    let mut parsed = Parsed::new();
+    let weekday = Component::Weekday(modifier::Weekday {
+        repr: modifier::WeekdayRepr::Narrow,
+        one_indexed: false,
+        case_sensitive: true,
+    });
+    assert!(parsed
+        .parse_component_with_locale(b"W", weekday, &English)
+        .is_ok());
+    assert_eq!(parsed.weekday, Some(Weekday::Wednesday));
+    assert!(parsed.parse_component(b"T", weekday).is_err());
 
     Ok(())
 }
@@ -307,3 +307,49 @@ fn weekday() {
         r#"invalid value: string "NotADay", expected a day of the week"#,
     );
 }
+
+#[test]
+fn iso8601_duration() -> serde_json::Result<()> {
+    use time::serde::iso8601_duration;
+
+    let mut buf = Vec::new();
+    iso8601_duration::serialize(
+        &Duration::new(5_405, 500_000_000),
+        &mut serde_json::Serializer::new(&mut buf),
+    )?;
+    assert_eq!(buf, br#""PT1H30M5.5S""#);
+    assert_eq!(
+        iso8601_duration::deserialize(&mut serde_json::Deserializer::from_str(r#""-P3DT4H""#))?,
+        Duration::hours(-76)
+    );
+    assert_eq!(
+        iso8601_duration::deserialize(&mut serde_json::Deserializer::from_str(r#""P1Y""#))
+            .map_err(|err| err.to_string()),
+        Err(
+            r#"invalid value: string "P1Y", expected an ISO 8601 duration without years or months"#
+                .to_owned()
+        )
+    );
+
+    let mut buf = Vec::new();
+    iso8601_duration::option::serialize(
+        &Some(Duration::days(2)),
+        &mut serde_json::Serializer::new(&mut buf),
+    )?;
+    assert_eq!(buf, br#""P2D""#);
+    let mut buf = Vec::new();
+    iso8601_duration::option::serialize(&None, &mut serde_json::Serializer::new(&mut buf))?;
+    assert_eq!(buf, b"null");
+    assert_eq!(
+        iso8601_duration::option::deserialize(&mut serde_json::Deserializer::from_str("null"))?,
+        None
+    );
+    assert_eq!(
+        iso8601_duration::option::deserialize(&mut serde_json::Deserializer::from_str(
+            r#""PT1S""#
+        ))?,
+        Some(Duration::SECOND)
+    );
+
+    Ok(())
+}
@@ -75,4 +75,6 @@ fn display() {
     assert_eq!(Friday.to_string(), "Friday");
     assert_eq!(Saturday.to_string(), "Saturday");
     assert_eq!(Sunday.to_string(), "Sunday");
+    assert_eq!(format!("{:>8}", Monday), "  Monday");
+    assert_eq!(format!("{:-^10}", Friday), "--Friday--");
 }
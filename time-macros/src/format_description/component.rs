@@ -12,6 +12,12 @@ pub(crate) enum Component {
     Weekday(modifier::Weekday),
     WeekNumber(modifier::WeekNumber),
     Year(modifier::Year),
+    Quarter(modifier::Quarter),
+    WeekOfMonth(modifier::WeekOfMonth),
+    WeekOfQuarter(modifier::WeekOfQuarter),
+    WeekdayInMonth(modifier::WeekdayInMonth),
+    Century(modifier::Century),
+    Era(modifier::Era),
     Hour(modifier::Hour),
     Minute(modifier::Minute),
     Period(modifier::Period),
@@ -20,6 +26,17 @@ pub(crate) enum Component {
     OffsetHour(modifier::OffsetHour),
     OffsetMinute(modifier::OffsetMinute),
     OffsetSecond(modifier::OffsetSecond),
+    OffsetAbbreviation(modifier::OffsetAbbreviation),
+    Offset(modifier::Offset),
+    DurationDay(modifier::DurationDay),
+    DurationHour(modifier::DurationHour),
+    DurationMinute(modifier::DurationMinute),
+    DurationSecond(modifier::DurationSecond),
+    DurationSubsecond(modifier::DurationSubsecond),
+    DurationSign(modifier::DurationSign),
+    UnixTimestamp(modifier::UnixTimestamp),
+    Ignore(modifier::Ignore),
+    End(modifier::End),
 }
 
 impl ToTokens for Component {
@@ -31,6 +48,14 @@ impl ToTokens for Component {
             Self::Weekday(modifier) => ("Weekday", modifier.to_internal_token_stream()),
             Self::WeekNumber(modifier) => ("WeekNumber", modifier.to_internal_token_stream()),
             Self::Year(modifier) => ("Year", modifier.to_internal_token_stream()),
+            Self::Quarter(modifier) => ("Quarter", modifier.to_internal_token_stream()),
+            Self::WeekOfMonth(modifier) => ("WeekOfMonth", modifier.to_internal_token_stream()),
+            Self::WeekOfQuarter(modifier) => ("WeekOfQuarter", modifier.to_internal_token_stream()),
+            Self::WeekdayInMonth(modifier) => {
+                ("WeekdayInMonth", modifier.to_internal_token_stream())
+            }
+            Self::Century(modifier) => ("Century", modifier.to_internal_token_stream()),
+            Self::Era(modifier) => ("Era", modifier.to_internal_token_stream()),
             Self::Hour(modifier) => ("Hour", modifier.to_internal_token_stream()),
             Self::Minute(modifier) => ("Minute", modifier.to_internal_token_stream()),
             Self::Period(modifier) => ("Period", modifier.to_internal_token_stream()),
@@ -39,6 +64,25 @@ impl ToTokens for Component {
             Self::OffsetHour(modifier) => ("OffsetHour", modifier.to_internal_token_stream()),
             Self::OffsetMinute(modifier) => ("OffsetMinute", modifier.to_internal_token_stream()),
             Self::OffsetSecond(modifier) => ("OffsetSecond", modifier.to_internal_token_stream()),
+            Self::OffsetAbbreviation(modifier) => {
+                ("OffsetAbbreviation", modifier.to_internal_token_stream())
+            }
+            Self::Offset(modifier) => ("Offset", modifier.to_internal_token_stream()),
+            Self::DurationDay(modifier) => ("DurationDay", modifier.to_internal_token_stream()),
+            Self::DurationHour(modifier) => ("DurationHour", modifier.to_internal_token_stream()),
+            Self::DurationMinute(modifier) => {
+                ("DurationMinute", modifier.to_internal_token_stream())
+            }
+            Self::DurationSecond(modifier) => {
+                ("DurationSecond", modifier.to_internal_token_stream())
+            }
+            Self::DurationSubsecond(modifier) => {
+                ("DurationSubsecond", modifier.to_internal_token_stream())
+            }
+            Self::DurationSign(modifier) => ("DurationSign", modifier.to_internal_token_stream()),
+            Self::UnixTimestamp(modifier) => ("UnixTimestamp", modifier.to_internal_token_stream()),
+            Self::Ignore(modifier) => ("Ignore", modifier.to_internal_token_stream()),
+            Self::End(modifier) => ("End", modifier.to_internal_token_stream()),
         };
 
         tokens.extend(
@@ -71,6 +115,12 @@ pub(crate) enum NakedComponent {
     Weekday,
     WeekNumber,
     Year,
+    Quarter,
+    WeekOfMonth,
+    WeekOfQuarter,
+    WeekdayInMonth,
+    Century,
+    Era,
     Hour,
     Minute,
     Period,
@@ -79,6 +129,17 @@ pub(crate) enum NakedComponent {
     OffsetHour,
     OffsetMinute,
     OffsetSecond,
+    OffsetAbbreviation,
+    Offset,
+    DurationDay,
+    DurationHour,
+    DurationMinute,
+    DurationSecond,
+    DurationSubsecond,
+    DurationSign,
+    UnixTimestamp,
+    Ignore,
+    End,
 }
 
 impl NakedComponent {
@@ -93,6 +154,12 @@ impl NakedComponent {
             "weekday" => Ok(Self::Weekday),
             "week_number" => Ok(Self::WeekNumber),
             "year" => Ok(Self::Year),
+            "quarter" => Ok(Self::Quarter),
+            "week_of_month" => Ok(Self::WeekOfMonth),
+            "week_of_quarter" => Ok(Self::WeekOfQuarter),
+            "weekday_in_month" => Ok(Self::WeekdayInMonth),
+            "century" => Ok(Self::Century),
+            "era" => Ok(Self::Era),
             "hour" => Ok(Self::Hour),
             "minute" => Ok(Self::Minute),
             "period" => Ok(Self::Period),
@@ -101,6 +168,17 @@ impl NakedComponent {
             "offset_hour" => Ok(Self::OffsetHour),
             "offset_minute" => Ok(Self::OffsetMinute),
             "offset_second" => Ok(Self::OffsetSecond),
+            "offset_abbreviation" => Ok(Self::OffsetAbbreviation),
+            "offset" => Ok(Self::Offset),
+            "duration_day" => Ok(Self::DurationDay),
+            "duration_hour" => Ok(Self::DurationHour),
+            "duration_minute" => Ok(Self::DurationMinute),
+            "duration_second" => Ok(Self::DurationSecond),
+            "duration_subsecond" => Ok(Self::DurationSubsecond),
+            "duration_sign" => Ok(Self::DurationSign),
+            "unix_timestamp" => Ok(Self::UnixTimestamp),
+            "ignore" => Ok(Self::Ignore),
+            "end" => Ok(Self::End),
             "" => Err(InvalidFormatDescription::MissingComponentName {
                 index: component_index,
             }),
@@ -111,31 +189,66 @@ impl NakedComponent {
         }
     }
 
-    pub(crate) fn attach_modifiers(self, modifiers: Modifiers) -> Component {
-        match self {
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn attach_modifiers(
+        self,
+        modifiers: Modifiers,
+        index: usize,
+    ) -> Result<Component, InvalidFormatDescription> {
+        Ok(match self {
             Self::Day => Component::Day(modifier::Day {
                 padding: modifiers.padding.unwrap_or_default(),
+                suffix: modifiers.suffix.unwrap_or_default(),
             }),
             Self::Month => Component::Month(modifier::Month {
                 padding: modifiers.padding.unwrap_or_default(),
                 repr: modifiers.month_repr.unwrap_or_default(),
+                case_sensitive: modifiers.case_sensitive.unwrap_or(true),
             }),
             Self::Ordinal => Component::Ordinal(modifier::Ordinal {
                 padding: modifiers.padding.unwrap_or_default(),
+                suffix: modifiers.suffix.unwrap_or_default(),
             }),
             Self::Weekday => Component::Weekday(modifier::Weekday {
                 repr: modifiers.weekday_repr.unwrap_or_default(),
                 one_indexed: modifiers.weekday_is_one_indexed.unwrap_or(true),
+                case_sensitive: modifiers.case_sensitive.unwrap_or(true),
             }),
             Self::WeekNumber => Component::WeekNumber(modifier::WeekNumber {
                 padding: modifiers.padding.unwrap_or_default(),
                 repr: modifiers.week_number_repr.unwrap_or_default(),
+                suffix: modifiers.suffix.unwrap_or_default(),
             }),
             Self::Year => Component::Year(modifier::Year {
                 padding: modifiers.padding.unwrap_or_default(),
                 repr: modifiers.year_repr.unwrap_or_default(),
                 iso_week_based: modifiers.year_is_iso_week_based.unwrap_or_default(),
                 sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
+                digits: modifiers.year_digits.unwrap_or(4),
+            }),
+            Self::Quarter => Component::Quarter(modifier::Quarter {
+                padding: modifiers.padding.unwrap_or_default(),
+                suffix: modifiers.suffix.unwrap_or_default(),
+            }),
+            Self::WeekOfMonth => Component::WeekOfMonth(modifier::WeekOfMonth {
+                padding: modifiers.padding.unwrap_or_default(),
+                suffix: modifiers.suffix.unwrap_or_default(),
+            }),
+            Self::WeekOfQuarter => Component::WeekOfQuarter(modifier::WeekOfQuarter {
+                padding: modifiers.padding.unwrap_or_default(),
+                suffix: modifiers.suffix.unwrap_or_default(),
+            }),
+            Self::WeekdayInMonth => Component::WeekdayInMonth(modifier::WeekdayInMonth {
+                padding: modifiers.padding.unwrap_or_default(),
+                suffix: modifiers.suffix.unwrap_or_default(),
+            }),
+            Self::Century => Component::Century(modifier::Century {
+                padding: modifiers.padding.unwrap_or_default(),
+                suffix: modifiers.suffix.unwrap_or_default(),
+            }),
+            Self::Era => Component::Era(modifier::Era {
+                repr: modifiers.era_repr.unwrap_or_default(),
+                case_sensitive: modifiers.case_sensitive.unwrap_or(true),
             }),
             Self::Hour => Component::Hour(modifier::Hour {
                 padding: modifiers.padding.unwrap_or_default(),
@@ -146,12 +259,14 @@ impl NakedComponent {
             }),
             Self::Period => Component::Period(modifier::Period {
                 is_uppercase: modifiers.period_is_uppercase.unwrap_or(true),
+                case_sensitive: modifiers.case_sensitive.unwrap_or(true),
             }),
             Self::Second => Component::Second(modifier::Second {
                 padding: modifiers.padding.unwrap_or_default(),
             }),
             Self::Subsecond => Component::Subsecond(modifier::Subsecond {
                 digits: modifiers.subsecond_digits.unwrap_or_default(),
+                rounding: modifiers.subsecond_rounding.unwrap_or_default(),
             }),
             Self::OffsetHour => Component::OffsetHour(modifier::OffsetHour {
                 sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
@@ -163,6 +278,48 @@ impl NakedComponent {
             Self::OffsetSecond => Component::OffsetSecond(modifier::OffsetSecond {
                 padding: modifiers.padding.unwrap_or_default(),
             }),
-        }
+            Self::OffsetAbbreviation => {
+                Component::OffsetAbbreviation(modifier::OffsetAbbreviation {
+                    case_sensitive: modifiers.case_sensitive.unwrap_or(true),
+                })
+            }
+            Self::Offset => Component::Offset(modifier::Offset {
+                zulu: modifiers.offset_zulu.unwrap_or(true),
+                separator: modifiers.offset_separator.unwrap_or_default(),
+                minute_is_optional: modifiers.offset_minute_is_optional.unwrap_or_default(),
+            }),
+            Self::DurationDay => Component::DurationDay(modifier::DurationDay),
+            Self::DurationHour => Component::DurationHour(modifier::DurationHour {
+                padding: modifiers.padding.unwrap_or_default(),
+                repr: modifiers.duration_repr.unwrap_or_default(),
+            }),
+            Self::DurationMinute => Component::DurationMinute(modifier::DurationMinute {
+                padding: modifiers.padding.unwrap_or_default(),
+                repr: modifiers.duration_repr.unwrap_or_default(),
+            }),
+            Self::DurationSecond => Component::DurationSecond(modifier::DurationSecond {
+                padding: modifiers.padding.unwrap_or_default(),
+                repr: modifiers.duration_repr.unwrap_or_default(),
+            }),
+            Self::DurationSubsecond => Component::DurationSubsecond(modifier::DurationSubsecond {
+                digits: modifiers.subsecond_digits.unwrap_or_default(),
+            }),
+            Self::DurationSign => Component::DurationSign(modifier::DurationSign {
+                sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
+            }),
+            Self::UnixTimestamp => Component::UnixTimestamp(modifier::UnixTimestamp {
+                precision: modifiers.unix_timestamp_precision.unwrap_or_default(),
+                sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
+            }),
+            Self::Ignore => Component::Ignore(modifier::Ignore {
+                count: modifiers
+                    .ignore_count
+                    .ok_or(InvalidFormatDescription::Expected {
+                        what: "`count` modifier",
+                        index,
+                    })?,
+            }),
+            Self::End => Component::End(modifier::End),
+        })
     }
 }
@@ -5,6 +5,7 @@ pub(crate) enum InvalidFormatDescription {
     InvalidComponentName { name: String, index: usize },
     InvalidModifier { value: String, index: usize },
     MissingComponentName { index: usize },
+    Expected { what: &'static str, index: usize },
 }
 
 impl fmt::Display for InvalidFormatDescription {
@@ -26,6 +27,7 @@ impl fmt::Display for InvalidFormatDescription {
             MissingComponentName { index } => {
                 write!(f, "missing component name at byte index {}", index)
             }
+            Expected { what, index } => write!(f, "expected {} at byte index {}", what, index),
         }
     }
 }
@@ -3,6 +3,8 @@ pub(crate) mod error;
 pub(crate) mod modifier;
 pub(crate) mod parse;
 
+use std::iter;
+
 use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
 
 pub(crate) use self::component::Component;
@@ -23,57 +25,89 @@ mod helper {
 pub(crate) enum FormatItem<'a> {
     Literal(&'a str),
     Component(Component),
+    Compound(Vec<Self>),
+    Optional(Vec<Self>),
+    First(Vec<Vec<Self>>),
 }
 
 impl ToTokens for FormatItem<'_> {
     fn to_internal_tokens(&self, tokens: &mut TokenStream) {
-        match self {
-            FormatItem::Literal(s) => tokens.extend(
-                [
-                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
-                    TokenTree::Ident(Ident::new("time", Span::mixed_site())),
-                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
-                    TokenTree::Ident(Ident::new("format_description", Span::mixed_site())),
-                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
-                    TokenTree::Ident(Ident::new("FormatItem", Span::mixed_site())),
-                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
-                    TokenTree::Ident(Ident::new("Literal", Span::mixed_site())),
-                    TokenTree::Group(Group::new(
-                        Delimiter::Parenthesis,
-                        TokenStream::from(TokenTree::Literal(Literal::byte_string(s.as_bytes()))),
-                    )),
-                ]
-                .iter()
-                .cloned()
-                .collect::<TokenStream>(),
+        let (variant, value) = match self {
+            FormatItem::Literal(s) => (
+                "Literal",
+                TokenStream::from(TokenTree::Literal(Literal::byte_string(s.as_bytes()))),
             ),
-            FormatItem::Component(component) => tokens.extend(
-                [
-                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
-                    TokenTree::Ident(Ident::new("time", Span::mixed_site())),
-                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
-                    TokenTree::Ident(Ident::new("format_description", Span::mixed_site())),
-                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
-                    TokenTree::Ident(Ident::new("FormatItem", Span::mixed_site())),
-                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
-                    TokenTree::Ident(Ident::new("Component", Span::mixed_site())),
-                    TokenTree::Group(Group::new(
-                        Delimiter::Parenthesis,
-                        component.to_internal_token_stream(),
-                    )),
-                ]
-                .iter()
-                .cloned()
-                .collect::<TokenStream>(),
+            FormatItem::Component(component) => ("Component", component.to_internal_token_stream()),
+            FormatItem::Compound(items) => {
+                tokens.extend(compound(items));
+                return;
+            }
+            FormatItem::Optional(items) => {
+                let mut value =
+                    TokenStream::from(TokenTree::Punct(Punct::new('&', Spacing::Alone)));
+                value.extend(compound(items));
+                ("Optional", value)
+            }
+            FormatItem::First(alternatives) => (
+                "First",
+                slice(alternatives.iter().map(|items| compound(items))),
             ),
-        }
+        };
+
+        tokens.extend(format_item_path(variant));
+        tokens.extend(iter::once(TokenTree::Group(Group::new(
+            Delimiter::Parenthesis,
+            value,
+        ))));
+    }
+}
+
+/// A reference to a slice containing the provided values.
+fn slice(values: impl Iterator<Item = TokenStream>) -> TokenStream {
+    let mut contents = TokenStream::new();
+    for value in values {
+        contents.extend(value);
+        contents.extend(iter::once(TokenTree::Punct(Punct::new(
+            ',',
+            Spacing::Alone,
+        ))));
     }
+    [
+        TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+        TokenTree::Group(Group::new(Delimiter::Bracket, contents)),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+/// A `FormatItem::Compound` containing the provided items.
+fn compound(items: &[FormatItem<'_>]) -> TokenStream {
+    let mut tokens = format_item_path("Compound");
+    tokens.extend(iter::once(TokenTree::Group(Group::new(
+        Delimiter::Parenthesis,
+        slice(items.iter().map(ToTokens::to_internal_token_stream)),
+    ))));
+    tokens
+}
+
+/// The fully qualified path to the provided variant of `FormatItem`.
+fn format_item_path(variant: &str) -> TokenStream {
+    [
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("time", Span::mixed_site())),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("format_description", Span::mixed_site())),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("FormatItem", Span::mixed_site())),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new(variant, Span::mixed_site())),
+    ]
+    .iter()
+    .cloned()
+    .collect()
 }
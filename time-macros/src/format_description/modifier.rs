@@ -1,4 +1,6 @@
-use proc_macro::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+use std::iter;
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
 
 use crate::format_description::error::InvalidFormatDescription;
 use crate::format_description::helper;
@@ -68,6 +70,38 @@ macro_rules! to_tokens {
         }
     };
 
+    (
+        $(#[$struct_attr:meta])*
+        $struct_vis:vis struct $struct_name:ident;
+    ) => {
+        $(#[$struct_attr])*
+        $struct_vis struct $struct_name;
+
+        impl ToTokens for $struct_name {
+            fn to_internal_tokens(&self, tokens: &mut TokenStream) {
+                tokens.extend(
+                    [
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("time", Span::mixed_site())),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("format_description", Span::mixed_site())),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("modifier", Span::mixed_site())),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new(stringify!($struct_name), Span::mixed_site())),
+                    ]
+                    .iter()
+                    .cloned()
+                    .collect::<TokenStream>(),
+                )
+            }
+        }
+    };
+
     (
         $(#[$enum_attr:meta])*
         $enum_vis:vis enum $enum_name:ident {
@@ -122,6 +156,7 @@ macro_rules! to_tokens {
 to_tokens! {
     pub(crate) struct Day {
         pub(crate) padding: Padding,
+        pub(crate) suffix: Suffix,
     }
 }
 
@@ -130,6 +165,7 @@ to_tokens! {
         Numerical,
         Long,
         Short,
+        Narrow,
     }
 }
 
@@ -137,12 +173,14 @@ to_tokens! {
     pub(crate) struct Month {
         pub(crate) padding: Padding,
         pub(crate) repr: MonthRepr,
+        pub(crate) case_sensitive: bool,
     }
 }
 
 to_tokens! {
     pub(crate) struct Ordinal {
         pub(crate) padding: Padding,
+        pub(crate) suffix: Suffix,
     }
 }
 
@@ -150,6 +188,7 @@ to_tokens! {
     pub(crate) enum WeekdayRepr {
         Short,
         Long,
+        Narrow,
         Sunday,
         Monday,
     }
@@ -159,6 +198,7 @@ to_tokens! {
     pub(crate) struct Weekday {
         pub(crate) repr: WeekdayRepr,
         pub(crate) one_indexed: bool,
+        pub(crate) case_sensitive: bool,
     }
 }
 
@@ -174,6 +214,7 @@ to_tokens! {
     pub(crate) struct WeekNumber {
         pub(crate) padding: Padding,
         pub(crate) repr: WeekNumberRepr,
+        pub(crate) suffix: Suffix,
     }
 }
 
@@ -181,6 +222,7 @@ to_tokens! {
     pub(crate) enum YearRepr {
         Full,
         LastTwo,
+        Historical,
     }
 }
 
@@ -190,6 +232,56 @@ to_tokens! {
         pub(crate) repr: YearRepr,
         pub(crate) iso_week_based: bool,
         pub(crate) sign_is_mandatory: bool,
+        pub(crate) digits: u8,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Quarter {
+        pub(crate) padding: Padding,
+        pub(crate) suffix: Suffix,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct WeekOfMonth {
+        pub(crate) padding: Padding,
+        pub(crate) suffix: Suffix,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct WeekOfQuarter {
+        pub(crate) padding: Padding,
+        pub(crate) suffix: Suffix,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct WeekdayInMonth {
+        pub(crate) padding: Padding,
+        pub(crate) suffix: Suffix,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Century {
+        pub(crate) padding: Padding,
+        pub(crate) suffix: Suffix,
+    }
+}
+
+to_tokens! {
+    pub(crate) enum EraRepr {
+        AnnoDomini,
+        CommonEra,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Era {
+        pub(crate) repr: EraRepr,
+        pub(crate) case_sensitive: bool,
     }
 }
 
@@ -209,6 +301,7 @@ to_tokens! {
 to_tokens! {
     pub(crate) struct Period {
         pub(crate) is_uppercase: bool,
+        pub(crate) case_sensitive: bool,
     }
 }
 
@@ -218,24 +311,88 @@ to_tokens! {
     }
 }
 
+pub(crate) enum SubsecondDigits {
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    OneOrMore,
+    Range { min: u8, max: u8 },
+}
+
+impl ToTokens for SubsecondDigits {
+    fn to_internal_tokens(&self, tokens: &mut TokenStream) {
+        let variant = match self {
+            Self::One => "One",
+            Self::Two => "Two",
+            Self::Three => "Three",
+            Self::Four => "Four",
+            Self::Five => "Five",
+            Self::Six => "Six",
+            Self::Seven => "Seven",
+            Self::Eight => "Eight",
+            Self::Nine => "Nine",
+            Self::OneOrMore => "OneOrMore",
+            Self::Range { .. } => "Range",
+        };
+        tokens.extend(
+            [
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("time", Span::mixed_site())),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("format_description", Span::mixed_site())),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("modifier", Span::mixed_site())),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new("SubsecondDigits", Span::mixed_site())),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new(variant, Span::mixed_site())),
+            ]
+            .iter()
+            .cloned(),
+        );
+        if let Self::Range { min, max } = self {
+            tokens.extend(iter::once(TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                [
+                    TokenTree::Ident(Ident::new("min", Span::mixed_site())),
+                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                    TokenTree::Literal(Literal::u8_suffixed(*min)),
+                    TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+                    TokenTree::Ident(Ident::new("max", Span::mixed_site())),
+                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                    TokenTree::Literal(Literal::u8_suffixed(*max)),
+                ]
+                .iter()
+                .cloned()
+                .collect(),
+            ))));
+        }
+    }
+}
+
 to_tokens! {
-    pub(crate) enum SubsecondDigits {
-        One,
-        Two,
-        Three,
-        Four,
-        Five,
-        Six,
-        Seven,
-        Eight,
-        Nine,
-        OneOrMore,
+    pub(crate) enum SubsecondRounding {
+        Truncate,
+        HalfUp,
+        HalfEven,
     }
 }
 
 to_tokens! {
     pub(crate) struct Subsecond {
         pub(crate) digits: SubsecondDigits,
+        pub(crate) rounding: SubsecondRounding,
     }
 }
 
@@ -258,6 +415,98 @@ to_tokens! {
     }
 }
 
+to_tokens! {
+    pub(crate) struct OffsetAbbreviation {
+        pub(crate) case_sensitive: bool,
+    }
+}
+
+to_tokens! {
+    pub(crate) enum OffsetSeparator {
+        Colon,
+        None,
+        Optional,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Offset {
+        pub(crate) zulu: bool,
+        pub(crate) separator: OffsetSeparator,
+        pub(crate) minute_is_optional: bool,
+    }
+}
+
+to_tokens! {
+    pub(crate) enum DurationRepr {
+        Total,
+        Remainder,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct DurationDay;
+}
+
+to_tokens! {
+    pub(crate) struct DurationHour {
+        pub(crate) padding: Padding,
+        pub(crate) repr: DurationRepr,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct DurationMinute {
+        pub(crate) padding: Padding,
+        pub(crate) repr: DurationRepr,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct DurationSecond {
+        pub(crate) padding: Padding,
+        pub(crate) repr: DurationRepr,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct DurationSubsecond {
+        pub(crate) digits: SubsecondDigits,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct DurationSign {
+        pub(crate) sign_is_mandatory: bool,
+    }
+}
+
+to_tokens! {
+    pub(crate) enum UnixTimestampPrecision {
+        Second,
+        Millisecond,
+        Microsecond,
+        Nanosecond,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct UnixTimestamp {
+        pub(crate) precision: UnixTimestampPrecision,
+        pub(crate) sign_is_mandatory: bool,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Ignore {
+        pub(crate) count: u16,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct End;
+}
+
 to_tokens! {
     pub(crate) enum Padding {
         Space,
@@ -266,6 +515,13 @@ to_tokens! {
     }
 }
 
+to_tokens! {
+    pub(crate) enum Suffix {
+        None,
+        Ordinal,
+    }
+}
+
 macro_rules! impl_default {
     ($($type:ty => $default:expr;)*) => {$(
         impl Default for $type {
@@ -278,8 +534,14 @@ macro_rules! impl_default {
 
 impl_default! {
     Padding => Self::Zero;
+    DurationRepr => Self::Remainder;
+    EraRepr => Self::AnnoDomini;
     MonthRepr => Self::Numerical;
+    OffsetSeparator => Self::Colon;
     SubsecondDigits => Self::OneOrMore;
+    SubsecondRounding => Self::Truncate;
+    Suffix => Self::None;
+    UnixTimestampPrecision => Self::Second;
     WeekdayRepr => Self::Long;
     WeekNumberRepr => Self::Iso;
     YearRepr => Self::Full;
@@ -291,13 +553,24 @@ pub(crate) struct Modifiers {
     pub(crate) hour_is_12_hour_clock: Option<bool>,
     pub(crate) period_is_uppercase: Option<bool>,
     pub(crate) month_repr: Option<MonthRepr>,
+    pub(crate) duration_repr: Option<DurationRepr>,
     pub(crate) subsecond_digits: Option<SubsecondDigits>,
+    pub(crate) subsecond_rounding: Option<SubsecondRounding>,
+    pub(crate) unix_timestamp_precision: Option<UnixTimestampPrecision>,
     pub(crate) weekday_repr: Option<WeekdayRepr>,
     pub(crate) weekday_is_one_indexed: Option<bool>,
     pub(crate) week_number_repr: Option<WeekNumberRepr>,
     pub(crate) year_repr: Option<YearRepr>,
     pub(crate) year_is_iso_week_based: Option<bool>,
+    pub(crate) year_digits: Option<u8>,
+    pub(crate) era_repr: Option<EraRepr>,
+    pub(crate) offset_zulu: Option<bool>,
+    pub(crate) offset_separator: Option<OffsetSeparator>,
+    pub(crate) offset_minute_is_optional: Option<bool>,
     pub(crate) sign_is_mandatory: Option<bool>,
+    pub(crate) ignore_count: Option<u16>,
+    pub(crate) case_sensitive: Option<bool>,
+    pub(crate) suffix: Option<Suffix>,
 }
 
 impl Modifiers {
@@ -327,7 +600,11 @@ impl Modifiers {
             }
 
             match (component_name, modifier) {
-                ("day", "padding:space")
+                ("century", "padding:space")
+                | ("day", "padding:space")
+                | ("duration_hour", "padding:space")
+                | ("duration_minute", "padding:space")
+                | ("duration_second", "padding:space")
                 | ("hour", "padding:space")
                 | ("minute", "padding:space")
                 | ("month", "padding:space")
@@ -335,10 +612,18 @@ impl Modifiers {
                 | ("offset_minute", "padding:space")
                 | ("offset_second", "padding:space")
                 | ("ordinal", "padding:space")
+                | ("quarter", "padding:space")
                 | ("second", "padding:space")
                 | ("week_number", "padding:space")
+                | ("week_of_month", "padding:space")
+                | ("week_of_quarter", "padding:space")
+                | ("weekday_in_month", "padding:space")
                 | ("year", "padding:space") => modifiers.padding = Some(Padding::Space),
-                ("day", "padding:zero")
+                ("century", "padding:zero")
+                | ("day", "padding:zero")
+                | ("duration_hour", "padding:zero")
+                | ("duration_minute", "padding:zero")
+                | ("duration_second", "padding:zero")
                 | ("hour", "padding:zero")
                 | ("minute", "padding:zero")
                 | ("month", "padding:zero")
@@ -346,10 +631,18 @@ impl Modifiers {
                 | ("offset_minute", "padding:zero")
                 | ("offset_second", "padding:zero")
                 | ("ordinal", "padding:zero")
+                | ("quarter", "padding:zero")
                 | ("second", "padding:zero")
                 | ("week_number", "padding:zero")
+                | ("week_of_month", "padding:zero")
+                | ("week_of_quarter", "padding:zero")
+                | ("weekday_in_month", "padding:zero")
                 | ("year", "padding:zero") => modifiers.padding = Some(Padding::Zero),
-                ("day", "padding:none")
+                ("century", "padding:none")
+                | ("day", "padding:none")
+                | ("duration_hour", "padding:none")
+                | ("duration_minute", "padding:none")
+                | ("duration_second", "padding:none")
                 | ("hour", "padding:none")
                 | ("minute", "padding:none")
                 | ("month", "padding:none")
@@ -357,54 +650,164 @@ impl Modifiers {
                 | ("offset_minute", "padding:none")
                 | ("offset_second", "padding:none")
                 | ("ordinal", "padding:none")
+                | ("quarter", "padding:none")
                 | ("second", "padding:none")
                 | ("week_number", "padding:none")
+                | ("week_of_month", "padding:none")
+                | ("week_of_quarter", "padding:none")
+                | ("weekday_in_month", "padding:none")
                 | ("year", "padding:none") => modifiers.padding = Some(Padding::None),
+                ("duration_hour", "repr:total")
+                | ("duration_minute", "repr:total")
+                | ("duration_second", "repr:total") => {
+                    modifiers.duration_repr = Some(DurationRepr::Total);
+                }
+                ("duration_hour", "repr:remainder")
+                | ("duration_minute", "repr:remainder")
+                | ("duration_second", "repr:remainder") => {
+                    modifiers.duration_repr = Some(DurationRepr::Remainder);
+                }
+                ("era", "repr:ad") => modifiers.era_repr = Some(EraRepr::AnnoDomini),
+                ("era", "repr:ce") => modifiers.era_repr = Some(EraRepr::CommonEra),
                 ("hour", "repr:24") => modifiers.hour_is_12_hour_clock = Some(false),
                 ("hour", "repr:12") => modifiers.hour_is_12_hour_clock = Some(true),
+                ("ignore", modifier) if modifier.starts_with("count:") => {
+                    modifiers.ignore_count =
+                        Some(modifier["count:".len()..].parse().map_err(|_| {
+                            InvalidFormatDescription::InvalidModifier {
+                                value: modifier.to_owned(),
+                                index: *index,
+                            }
+                        })?)
+                }
+                ("era", "case_sensitive:true")
+                | ("month", "case_sensitive:true")
+                | ("period", "case_sensitive:true")
+                | ("offset_abbreviation", "case_sensitive:true")
+                | ("weekday", "case_sensitive:true") => modifiers.case_sensitive = Some(true),
+                ("era", "case_sensitive:false")
+                | ("month", "case_sensitive:false")
+                | ("period", "case_sensitive:false")
+                | ("offset_abbreviation", "case_sensitive:false")
+                | ("weekday", "case_sensitive:false") => modifiers.case_sensitive = Some(false),
+                ("century", "suffix:none")
+                | ("day", "suffix:none")
+                | ("ordinal", "suffix:none")
+                | ("quarter", "suffix:none")
+                | ("week_number", "suffix:none")
+                | ("week_of_month", "suffix:none")
+                | ("week_of_quarter", "suffix:none")
+                | ("weekday_in_month", "suffix:none") => modifiers.suffix = Some(Suffix::None),
+                ("century", "suffix:ordinal")
+                | ("day", "suffix:ordinal")
+                | ("ordinal", "suffix:ordinal")
+                | ("quarter", "suffix:ordinal")
+                | ("week_number", "suffix:ordinal")
+                | ("week_of_month", "suffix:ordinal")
+                | ("week_of_quarter", "suffix:ordinal")
+                | ("weekday_in_month", "suffix:ordinal") => {
+                    modifiers.suffix = Some(Suffix::Ordinal);
+                }
                 ("month", "repr:numerical") => modifiers.month_repr = Some(MonthRepr::Numerical),
                 ("month", "repr:long") => modifiers.month_repr = Some(MonthRepr::Long),
                 ("month", "repr:short") => modifiers.month_repr = Some(MonthRepr::Short),
-                ("offset_hour", "sign:automatic") | ("year", "sign:automatic") => {
-                    modifiers.sign_is_mandatory = Some(false)
+                ("month", "repr:narrow") => modifiers.month_repr = Some(MonthRepr::Narrow),
+                ("offset", "zulu:true") => modifiers.offset_zulu = Some(true),
+                ("offset", "zulu:false") => modifiers.offset_zulu = Some(false),
+                ("offset", "separator:colon") => {
+                    modifiers.offset_separator = Some(OffsetSeparator::Colon);
                 }
-                ("offset_hour", "sign:mandatory") | ("year", "sign:mandatory") => {
-                    modifiers.sign_is_mandatory = Some(true)
+                ("offset", "separator:none") => {
+                    modifiers.offset_separator = Some(OffsetSeparator::None);
                 }
+                ("offset", "separator:optional") => {
+                    modifiers.offset_separator = Some(OffsetSeparator::Optional);
+                }
+                ("offset", "minute:mandatory") => modifiers.offset_minute_is_optional = Some(false),
+                ("offset", "minute:optional") => modifiers.offset_minute_is_optional = Some(true),
+                ("duration_sign", "sign:automatic")
+                | ("offset_hour", "sign:automatic")
+                | ("unix_timestamp", "sign:automatic")
+                | ("year", "sign:automatic") => modifiers.sign_is_mandatory = Some(false),
+                ("duration_sign", "sign:mandatory")
+                | ("offset_hour", "sign:mandatory")
+                | ("unix_timestamp", "sign:mandatory")
+                | ("year", "sign:mandatory") => modifiers.sign_is_mandatory = Some(true),
                 ("period", "case:upper") => modifiers.period_is_uppercase = Some(true),
                 ("period", "case:lower") => modifiers.period_is_uppercase = Some(false),
-                ("subsecond", "digits:1") => {
+                ("duration_subsecond", "digits:1") | ("subsecond", "digits:1") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::One)
                 }
-                ("subsecond", "digits:2") => {
+                ("duration_subsecond", "digits:2") | ("subsecond", "digits:2") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::Two)
                 }
-                ("subsecond", "digits:3") => {
+                ("duration_subsecond", "digits:3") | ("subsecond", "digits:3") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::Three)
                 }
-                ("subsecond", "digits:4") => {
+                ("duration_subsecond", "digits:4") | ("subsecond", "digits:4") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::Four)
                 }
-                ("subsecond", "digits:5") => {
+                ("duration_subsecond", "digits:5") | ("subsecond", "digits:5") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::Five)
                 }
-                ("subsecond", "digits:6") => {
+                ("duration_subsecond", "digits:6") | ("subsecond", "digits:6") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::Six)
                 }
-                ("subsecond", "digits:7") => {
+                ("duration_subsecond", "digits:7") | ("subsecond", "digits:7") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::Seven)
                 }
-                ("subsecond", "digits:8") => {
+                ("duration_subsecond", "digits:8") | ("subsecond", "digits:8") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::Eight)
                 }
-                ("subsecond", "digits:9") => {
+                ("duration_subsecond", "digits:9") | ("subsecond", "digits:9") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::Nine)
                 }
-                ("subsecond", "digits:1+") => {
+                ("duration_subsecond", "digits:1+") | ("subsecond", "digits:1+") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::OneOrMore)
                 }
+                ("duration_subsecond", modifier) | ("subsecond", modifier)
+                    if modifier.starts_with("digits:") =>
+                {
+                    modifiers.subsecond_digits =
+                        Some(match &modifier.as_bytes()["digits:".len()..] {
+                            &[min @ b'1'..=b'9', b'-', max @ b'1'..=b'9'] if min <= max => {
+                                SubsecondDigits::Range {
+                                    min: min - b'0',
+                                    max: max - b'0',
+                                }
+                            }
+                            _ => {
+                                return Err(InvalidFormatDescription::InvalidModifier {
+                                    value: modifier.to_owned(),
+                                    index: *index,
+                                });
+                            }
+                        });
+                }
+                ("subsecond", "rounding:truncate") => {
+                    modifiers.subsecond_rounding = Some(SubsecondRounding::Truncate);
+                }
+                ("subsecond", "rounding:half_up") => {
+                    modifiers.subsecond_rounding = Some(SubsecondRounding::HalfUp);
+                }
+                ("subsecond", "rounding:half_even") => {
+                    modifiers.subsecond_rounding = Some(SubsecondRounding::HalfEven);
+                }
+                ("unix_timestamp", "precision:second") => {
+                    modifiers.unix_timestamp_precision = Some(UnixTimestampPrecision::Second)
+                }
+                ("unix_timestamp", "precision:millisecond") => {
+                    modifiers.unix_timestamp_precision = Some(UnixTimestampPrecision::Millisecond)
+                }
+                ("unix_timestamp", "precision:microsecond") => {
+                    modifiers.unix_timestamp_precision = Some(UnixTimestampPrecision::Microsecond)
+                }
+                ("unix_timestamp", "precision:nanosecond") => {
+                    modifiers.unix_timestamp_precision = Some(UnixTimestampPrecision::Nanosecond)
+                }
                 ("weekday", "repr:short") => modifiers.weekday_repr = Some(WeekdayRepr::Short),
                 ("weekday", "repr:long") => modifiers.weekday_repr = Some(WeekdayRepr::Long),
+                ("weekday", "repr:narrow") => modifiers.weekday_repr = Some(WeekdayRepr::Narrow),
                 ("weekday", "repr:sunday") => modifiers.weekday_repr = Some(WeekdayRepr::Sunday),
                 ("weekday", "repr:monday") => modifiers.weekday_repr = Some(WeekdayRepr::Monday),
                 ("weekday", "one_indexed:true") => modifiers.weekday_is_one_indexed = Some(true),
@@ -420,6 +823,10 @@ impl Modifiers {
                 }
                 ("year", "repr:full") => modifiers.year_repr = Some(YearRepr::Full),
                 ("year", "repr:last_two") => modifiers.year_repr = Some(YearRepr::LastTwo),
+                ("year", "repr:historical") => modifiers.year_repr = Some(YearRepr::Historical),
+                ("year", "digits:4") => modifiers.year_digits = Some(4),
+                ("year", "digits:5") => modifiers.year_digits = Some(5),
+                ("year", "digits:6") => modifiers.year_digits = Some(6),
                 ("year", "base:calendar") => modifiers.year_is_iso_week_based = Some(false),
                 ("year", "base:iso_week") => modifiers.year_is_iso_week_based = Some(true),
                 _ => {
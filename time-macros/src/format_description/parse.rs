@@ -3,8 +3,8 @@ use crate::format_description::error::InvalidFormatDescription;
 use crate::format_description::{helper, modifier, FormatItem};
 use crate::Error;
 
-struct ParsedItem<'a> {
-    item: FormatItem<'a>,
+struct ParsedItem<'a, T = FormatItem<'a>> {
+    item: T,
     remaining: &'a str,
 }
 
@@ -24,12 +24,20 @@ fn parse_component(mut s: &str, index: &mut usize) -> Result<Component, InvalidF
         s = "";
     }
 
-    Ok(NakedComponent::parse(component_name, component_index)?
-        .attach_modifiers(modifier::Modifiers::parse(component_name, s, index)?))
+    let component = NakedComponent::parse(component_name, component_index)?;
+    let modifiers = modifier::Modifiers::parse(component_name, s, index)?;
+    component.attach_modifiers(modifiers, *index)
 }
 
-fn parse_literal<'a>(s: &'a str, index: &mut usize) -> ParsedItem<'a> {
-    let loc = s.find('[').unwrap_or_else(|| s.len());
+fn parse_literal<'a>(
+    s: &'a str,
+    index: &mut usize,
+    is_nested: bool,
+    version: u8,
+) -> ParsedItem<'a> {
+    let loc = s
+        .find(|c| c == '[' || (is_nested && c == ']') || (version == 2 && c == '\\'))
+        .unwrap_or_else(|| s.len());
     *index += loc;
     ParsedItem {
         item: FormatItem::Literal(&s[..loc]),
@@ -37,20 +45,131 @@ fn parse_literal<'a>(s: &'a str, index: &mut usize) -> ParsedItem<'a> {
     }
 }
 
+fn parse_nested<'a>(
+    s: &'a str,
+    index: &mut usize,
+    version: u8,
+) -> Result<ParsedItem<'a, Vec<FormatItem<'a>>>, InvalidFormatDescription> {
+    let opening_index = *index;
+    let mut s = s
+        .strip_prefix('[')
+        .ok_or(InvalidFormatDescription::Expected {
+            what: "opening bracket",
+            index: *index,
+        })?;
+    *index += 1;
+
+    let mut items = Vec::new();
+    loop {
+        if s.is_empty() {
+            return Err(InvalidFormatDescription::UnclosedOpeningBracket {
+                index: opening_index,
+            });
+        }
+        if let Some(remaining) = s.strip_prefix(']') {
+            *index += 1;
+            return Ok(ParsedItem {
+                item: items,
+                remaining,
+            });
+        }
+        let ParsedItem { item, remaining } = parse_item(s, index, true, version)?;
+        s = remaining;
+        items.push(item);
+    }
+}
+
+fn parse_section<'a>(
+    s: &'a str,
+    index: &mut usize,
+    version: u8,
+) -> Result<Option<ParsedItem<'a>>, InvalidFormatDescription> {
+    let opening_index = *index;
+    let mut loc = *index + 1;
+    let s = helper::consume_whitespace(&s[1..], &mut loc);
+    let keyword = match ["optional", "first"]
+        .iter()
+        .find(|&&keyword| s.starts_with(keyword))
+    {
+        Some(&keyword) => keyword,
+        None if version == 2 && s.starts_with('[') => "",
+        None => return Ok(None),
+    };
+    let s = &s[keyword.len()..];
+    if !(s.is_empty() || s.starts_with(|c: char| c == '[' || c == ']' || c.is_whitespace())) {
+        return Ok(None);
+    }
+    loc += keyword.len();
+
+    let s = helper::consume_whitespace(s, &mut loc);
+    let ParsedItem {
+        item: items,
+        mut remaining,
+    } = parse_nested(s, &mut loc, version)?;
+    let item = if keyword.is_empty() {
+        FormatItem::Compound(items)
+    } else if keyword == "optional" {
+        FormatItem::Optional(items)
+    } else {
+        let mut alternatives = vec![items];
+        loop {
+            remaining = helper::consume_whitespace(remaining, &mut loc);
+            if !remaining.starts_with('[') {
+                break;
+            }
+            let nested = parse_nested(remaining, &mut loc, version)?;
+            alternatives.push(nested.item);
+            remaining = nested.remaining;
+        }
+        FormatItem::First(alternatives)
+    };
+
+    let s = helper::consume_whitespace(remaining, &mut loc);
+    let remaining =
+        s.strip_prefix(']')
+            .ok_or(InvalidFormatDescription::UnclosedOpeningBracket {
+                index: opening_index,
+            })?;
+    *index = loc + 1;
+
+    Ok(Some(ParsedItem { item, remaining }))
+}
+
 #[allow(clippy::manual_strip)]
 fn parse_item<'a>(
     s: &'a str,
     index: &mut usize,
+    is_nested: bool,
+    version: u8,
 ) -> Result<ParsedItem<'a>, InvalidFormatDescription> {
-    if let Some(remaining) = s.strip_prefix("[[") {
-        *index += 2;
-        return Ok(ParsedItem {
-            item: FormatItem::Literal("]"),
-            remaining,
-        });
+    if version == 1 {
+        if let Some(remaining) = s.strip_prefix("[[") {
+            *index += 2;
+            return Ok(ParsedItem {
+                item: FormatItem::Literal("["),
+                remaining,
+            });
+        }
+    } else if let Some(remaining) = s.strip_prefix('\\') {
+        return match remaining.chars().next() {
+            Some(c) if c == '\\' || c == '[' || c == ']' => {
+                *index += 2;
+                Ok(ParsedItem {
+                    item: FormatItem::Literal(&remaining[..1]),
+                    remaining: &remaining[1..],
+                })
+            }
+            _ => Err(InvalidFormatDescription::Expected {
+                what: "`\\`, `[`, or `]` after backslash",
+                index: *index + 1,
+            }),
+        };
     }
 
     if s.starts_with('[') {
+        if let Some(parsed_item) = parse_section(s, index, version)? {
+            return Ok(parsed_item);
+        }
         if let Some(bracket_index) = s.find(']') {
             *index += 1;
             let ret_val = ParsedItem {
@@ -63,16 +182,72 @@ fn parse_item<'a>(
             Err(InvalidFormatDescription::UnclosedOpeningBracket { index: *index })
         }
     } else {
-        Ok(parse_literal(s, index))
+        Ok(parse_literal(s, index, is_nested, version))
+    }
+}
+
+fn parse_version<'a>(
+    s: &'a str,
+    index: &mut usize,
+) -> Result<ParsedItem<'a, u8>, InvalidFormatDescription> {
+    let unversioned = ParsedItem {
+        item: 1,
+        remaining: s,
+    };
+
+    let mut loc = *index + 1;
+    let s = match s.strip_prefix('[') {
+        Some(s) => helper::consume_whitespace(s, &mut loc),
+        None => return Ok(unversioned),
+    };
+    let s = match s.strip_prefix("version") {
+        Some(s) if s.is_empty() || s.starts_with(|c: char| c == ']' || c.is_whitespace()) => s,
+        _ => return Ok(unversioned),
+    };
+    loc += "version".len();
+
+    let s = helper::consume_whitespace(s, &mut loc);
+    let version_index = loc;
+    let version = match s.chars().next() {
+        Some('1') => 1,
+        Some('2') => 2,
+        _ => {
+            return Err(InvalidFormatDescription::Expected {
+                what: "version 1 or 2",
+                index: version_index,
+            });
+        }
+    };
+    loc += 1;
+
+    let s = helper::consume_whitespace(&s[1..], &mut loc);
+    if let Some(remaining) = s.strip_prefix(']') {
+        *index = loc + 1;
+        Ok(ParsedItem {
+            item: version,
+            remaining,
+        })
+    } else if s.is_empty() {
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: *index })
+    } else {
+        Err(InvalidFormatDescription::Expected {
+            what: "version 1 or 2",
+            index: version_index,
+        })
     }
 }
 
-pub(crate) fn parse(mut s: &str) -> Result<Vec<FormatItem<'_>>, Error> {
+pub(crate) fn parse(s: &str) -> Result<Vec<FormatItem<'_>>, Error> {
     let mut compound = Vec::new();
     let mut loc = 0;
 
+    let ParsedItem {
+        item: version,
+        remaining: mut s,
+    } = parse_version(s, &mut loc)?;
+
     while !s.is_empty() {
-        let ParsedItem { item, remaining } = parse_item(s, &mut loc)?;
+        let ParsedItem { item, remaining } = parse_item(s, &mut loc, false, version)?;
         s = remaining;
         compound.push(item);
     }
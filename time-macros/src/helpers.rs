@@ -32,20 +32,73 @@ pub(crate) fn get_string_literal(tokens: TokenStream) -> Result<String, Error> {
     match tokens.next() {
         Some(TokenTree::Literal(literal)) => {
             let s = literal.to_string();
-            if s.starts_with('"') && s.ends_with('"') {
-                tokens
-                    .next()
-                    .map_or(Ok(s[1..s.len() - 1].to_owned()), |tree| {
-                        Err(Error::UnexpectedToken { tree })
-                    })
+            let string = if s.starts_with('"') && s.ends_with('"') && s.len() >= 2 {
+                unescape(&s[1..s.len() - 1])
+            } else if let Some(raw) = s.strip_prefix('r') {
+                let hashes = raw.len() - raw.trim_start_matches('#').len();
+                let raw = &raw[hashes..raw.len() - hashes];
+                if raw.starts_with('"') && raw.ends_with('"') && raw.len() >= 2 {
+                    raw[1..raw.len() - 1].to_owned()
+                } else {
+                    return Err(Error::ExpectedString);
+                }
             } else {
-                Err(Error::ExpectedString)
-            }
+                return Err(Error::ExpectedString);
+            };
+            tokens
+                .next()
+                .map_or(Ok(string), |tree| Err(Error::UnexpectedToken { tree }))
         }
         _ => Err(Error::ExpectedString),
     }
 }
 
+/// Resolve the escape sequences in the contents of a string literal. The literal has already been
+/// validated by the compiler, so invalid escape sequences are not handled.
+fn unescape(s: &str) -> String {
+    let mut chars = s.chars().peekable();
+    let mut unescaped = String::with_capacity(s.len());
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some('0') => unescaped.push('\0'),
+            Some('x') => {
+                let hex = chars.by_ref().take(2).collect::<String>();
+                unescaped.extend(u8::from_str_radix(&hex, 16).ok().map(char::from));
+            }
+            Some('u') => {
+                let hex = chars
+                    .by_ref()
+                    .skip(1)
+                    .take_while(|&c| c != '}')
+                    .filter(|&c| c != '_')
+                    .collect::<String>();
+                unescaped.extend(
+                    u32::from_str_radix(&hex, 16)
+                        .ok()
+                        .and_then(std::char::from_u32),
+                );
+            }
+            // A line continuation skips the newline and any leading whitespace on the next line.
+            Some('\n') => {
+                let _ = chars.peeking_take_while(|c| c.is_whitespace()).count();
+            }
+            Some(c) => unescaped.push(c),
+            None => {}
+        }
+    }
+
+    unescaped
+}
+
 pub(crate) fn consume_digits<T: FromStr>(
     component_name: &'static str,
     chars: &mut Peekable<Chars<'_>>,
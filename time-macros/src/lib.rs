@@ -47,7 +47,7 @@ mod time;
 
 use std::iter;
 
-use proc_macro::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
 
 use self::date::Date;
 use self::datetime::DateTime;
@@ -82,6 +82,18 @@ impl ToTokens for bool {
     }
 }
 
+impl ToTokens for u8 {
+    fn to_internal_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(iter::once(TokenTree::Literal(Literal::u8_suffixed(*self))))
+    }
+}
+
+impl ToTokens for u16 {
+    fn to_internal_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(iter::once(TokenTree::Literal(Literal::u16_suffixed(*self))))
+    }
+}
+
 macro_rules! impl_macros {
     ($($name:ident : $type:ty)*) => {$(
         #[proc_macro]